@@ -3,21 +3,71 @@
 */
 
 use guff_css::Css;
-use std::path::{
-	Path,
-	PathBuf,
+use std::{
+	collections::BTreeSet,
+	fmt::Write,
+	path::{
+		Path,
+		PathBuf,
+	},
 };
+use toml::{
+	Table,
+	Value,
+};
+
+
+
+/// # Animation Definitions.
+const ANIMATIONS: &str = "skel/animations.toml";
+
+/// # Scene Flags.
+///
+/// The (TOML) names of the flags that may be set on a scene, and their
+/// corresponding `Scene` constants.
+const SCENE_FLAGS: [(&str, &str); 3] = [
+	("flip-x-next", "FLIP_X_NEXT"),
+	("gravity", "GRAVITY"),
+	("ignore-edges", "IGNORE_EDGES"),
+];
+
+/// # Scene Easing Curves.
+///
+/// The (TOML) names of the named easing curves, and their corresponding
+/// `Ease` variants.
+const SCENE_EASES: [(&str, &str); 11] = [
+	("in", "In"),
+	("out", "Out"),
+	("in-out", "InOut"),
+	("cubic-in", "CubicIn"),
+	("cubic-out", "CubicOut"),
+	("cubic-in-out", "CubicInOut"),
+	("sine-in", "SineIn"),
+	("sine-out", "SineOut"),
+	("sine-in-out", "SineInOut"),
+	("bounce", "Bounce"),
+	("elastic", "Elastic"),
+];
+
+/// # Clippy's Line Limit.
+///
+/// Generated functions longer than this need to `expect` the
+/// `too_many_lines` lint.
+const TOO_MANY_LINES: usize = 100;
+
 
 
 /// # Main.
 fn main() {
 	println!("cargo:rerun-if-env-changed=CARGO_PKG_VERSION");
-	println!("cargo:rerun-if-changed=skel/playlist.txt");
+	println!("cargo:rerun-if-changed={ANIMATIONS}");
 	println!("cargo:rerun-if-changed=skel/img/poe.png");
 	println!("cargo:rerun-if-changed=skel/scss");
 	println!("cargo:rerun-if-changed=skel/sound/baa.flac");
 	println!("cargo:rerun-if-changed=skel/sound/sneeze.flac");
 	println!("cargo:rerun-if-changed=skel/sound/yawn.flac");
+	println!("cargo:rerun-if-changed=src/animation/frame.rs");
+	println!("cargo:rerun-if-changed=src/animation/sound.rs");
 
 	let css = build_css();
 	write_file(&out_path("poe.css"), css.as_bytes());
@@ -25,8 +75,10 @@ fn main() {
 	let media = build_media();
 	write_file(&out_path("media.rs"), media.as_bytes());
 
-	let def = build_default_animations();
-	write_file(&out_path("default-animations.rs"), def.as_bytes());
+	let defs = Definitions::load();
+	write_file(&out_path("animations.rs"), defs.build_animations().as_bytes());
+	write_file(&out_path("animation-scenes.rs"), defs.build_scenes().as_bytes());
+	write_file(&out_path("playlist.txt"), defs.build_playlist().as_bytes());
 }
 
 /// # Compile CSS.
@@ -59,153 +111,1328 @@ pub(crate) const IMAGE_HEIGHT: u32 = {h};
 	)
 }
 
-/// # Default Animations w/ Weightings.
-const DEFAULT_ANIMATIONS: &[(usize, &str)] = &[
-	(24, "Hop"),
-	(24, "LookDown"),
-	(24, "LookUp"),
-	(24, "Skip"),
-
-	(12, "Beg"),
-	(12, "Dance"),
-	(12, "Eat"),
-	(12, "Handstand"),
-	(12, "LayDown"),
-	(12, "LegLifts"),
-	(12, "Roll"),
-	(12, "Scratch"),
-	(12, "Spin"),
-
-	(6, "Blink"),
-	(6, "Cry"),
-	(6, "Popcorn"),
-	(6, "Really"),
-	(6, "Rest"),
-	(6, "Rotate"),
-	(6, "SleepSitting"),
-	(6, "SleepStanding"),
-
-	(3, "EatMagicFlower"),
-	(3, "PlayDead"),
-	(3, "Scoot"),
-	(3, "Scream"),
-	(3, "SideStep"),
-
-	(1, "Abduction"),
-	(1, "Bleat"),
-	(1, "Glitch"),
-	(1, "ShadowShowdown"),
-	(1, "Sneeze"),
-	(1, "Tornado"),
-	(1, "Urinate"),
-	(1, "Yawn"),
-];
 
-/// # Build Default Animation List.
-///
-/// This exports an Animation::default_choice method for choosing a default
-/// animation, which one third of the time will just be a Walk.
-///
-/// The non-walk animations are weighted to prioritize certain sequences over
-/// others. (Disruptive-ish animations, including those that make sound, are
-/// given the lowest weight.)
-///
-/// To keep things fresh, any given non-walk selection is guaranteed to be
-/// different than the previous two non-walk selections.
-fn build_default_animations() -> String {
-	// Calculate the total.
-	let mut total: usize = DEFAULT_ANIMATIONS.iter().map(|(n, _)| *n).sum();
-	let run = total / 2;
-	total += run;
-
-	// We use u16 for capped randomization; make sure this fits before we do
-	// anything else.
-	assert!(
-		total < usize::from(u16::MAX),
-		"Default special animation total is too big: {total}"
-	);
-
-	// Convert everything to match arms.
-	let mut from = 0;
-	let mut arms = Vec::new();
-
-	for (n, a) in [(run, "Run")].iter().chain(DEFAULT_ANIMATIONS.iter()) {
-		let to = from + n;
-		if from + 1 == total { arms.push(format!("\t\t\t\t\t_ => Self::{a},")); }
-		else if *n == 1 {
-			arms.push(format!("\t\t\t\t\t{from} => Self::{a},"));
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+/// # Animation Kind.
+enum Kind {
+	/// # Primary, Directly Playable.
+	Playable,
+
+	/// # Primary, Supporting.
+	Support,
+
+	/// # Child.
+	Child,
+}
+
+impl Kind {
+	/// # Primary?
+	const fn primary(self) -> bool { ! matches!(self, Self::Child) }
+}
+
+/// # Animation Definition.
+struct AnimationDef {
+	/// # Variant Name.
+	name: String,
+
+	/// # Title.
+	title: String,
+
+	/// # Kind.
+	kind: Kind,
+
+	/// # Transform Smoothing.
+	smooth: bool,
+
+	/// # Flip (X).
+	flip_x: bool,
+
+	/// # May Exit.
+	may_exit: bool,
+
+	/// # Clamp to Wall (`Direction` variant).
+	clamp_x: Option<&'static str>,
+
+	/// # Minimum Screen Size.
+	min_size: (u16, u16),
+
+	/// # CSS Class.
+	css: Option<String>,
+
+	/// # Child Animation.
+	child: Option<String>,
+
+	/// # Next Animation(s).
+	next: Option<Vec<(String, u16)>>,
+
+	/// # Next Animation(s) at Edge.
+	next_edge: Option<Vec<(String, u16)>>,
+
+	/// # Dynamic Scene Builder.
+	dynamic: Option<String>,
+
+	/// # Scales With Width and/or Height.
+	///
+	/// The screen dimensions the dynamic builder depends on, which decide
+	/// the sizes its compile-time checks sweep.
+	scales: (bool, bool),
+
+	/// # Scenes (or Dynamic Template).
+	scenes: Vec<SceneDef>,
+}
+
+impl AnimationDef {
+	/// # Scene Constant Name.
+	///
+	/// Convert the (camel case) name to screaming snake case, e.g.
+	/// `ChaseAMartian` to `CHASE_A_MARTIAN`.
+	fn const_name(&self) -> String {
+		let chars: Vec<char> = self.name.chars().collect();
+		let mut out = String::with_capacity(chars.len() * 2);
+		for (idx, c) in chars.iter().copied().enumerate() {
+			if 0 < idx && c.is_ascii_uppercase() {
+				let prev = chars[idx - 1];
+				let next = chars.get(idx + 1).copied().unwrap_or('A');
+				if ! prev.is_ascii_uppercase() || next.is_ascii_lowercase() { out.push('_'); }
+			}
+			out.push(c.to_ascii_uppercase());
 		}
-		else {
-			arms.push(format!("\t\t\t\t\t{from}..={} => Self::{a},", to - 1));
+		out
+	}
+}
+
+/// # Scene Definition.
+enum SceneDef {
+	/// # Entrance Delay.
+	EntranceDelay,
+
+	/// # Copy of Another Animation's Scene.
+	Reuse(String, usize),
+
+	/// # Regular Scene.
+	Scene {
+		/// # Milliseconds per Frame.
+		fpms: u16,
+
+		/// # Frames.
+		frames: Vec<String>,
+
+		/// # Movement.
+		move_to: Option<(i32, i32)>,
+
+		/// # Repeat.
+		repeat: Option<(u16, u8)>,
+
+		/// # Repeat Formula (Dynamic Templates Only).
+		formula: Option<(String, u8)>,
+
+		/// # Sound Cues (Name, Index, Chance, Every Loop).
+		sounds: Vec<(String, u8, u8, bool)>,
+
+		/// # Easing (`Ease` variant).
+		ease: Option<String>,
+
+		/// # Flags (`Scene` constants).
+		flags: Vec<&'static str>,
+	},
+}
+
+/// # Animation Definitions.
+///
+/// This holds the parsed and validated contents of `skel/animations.toml`.
+struct Definitions {
+	/// # Animations (in ID order).
+	animations: Vec<AnimationDef>,
+
+	/// # Default Choices and Weights (sans `Run`).
+	default_choices: Vec<(String, u16)>,
+
+	/// # Entrance Choices and Weights.
+	entrance_choices: Vec<(String, u16)>,
+}
+
+impl Definitions {
+	/// # Load.
+	///
+	/// Parse and validate the animation definitions, panicking with a (more
+	/// or less) helpful message if there are any problems.
+	fn load() -> Self {
+		let raw = std::fs::read_to_string(ANIMATIONS)
+			.unwrap_or_else(|_| panic!("Unable to read {ANIMATIONS}."));
+		let mut raw: Table = raw.parse()
+			.unwrap_or_else(|e| panic!("Unable to parse {ANIMATIONS}: {e}"));
+
+		let frames = enum_variants("src/animation/frame.rs", "Frame");
+		let sounds = enum_variants("src/animation/sound.rs", "Sound");
+
+		let default_choices = raw.remove("default-choices")
+			.map_or_else(Vec::new, |v| weighted_list("default-choices", &v));
+		let entrance_choices = raw.remove("entrance-choices")
+			.map_or_else(Vec::new, |v| weighted_list("entrance-choices", &v));
+		let animations: Vec<AnimationDef> = match raw.remove("animation") {
+			Some(Value::Array(v)) => v.iter()
+				.enumerate()
+				.map(|(idx, v)| parse_animation(idx, v, &frames, &sounds))
+				.collect(),
+			_ => fail("animation", "must be an array of tables."),
+		};
+		if let Some(k) = raw.keys().next() { fail(k, "unknown key."); }
+
+		let out = Self { animations, default_choices, entrance_choices };
+		out.validate();
+		out
+	}
+
+	/// # Find Animation.
+	fn get(&self, name: &str) -> Option<&AnimationDef> {
+		self.animations.iter().find(|a| a.name == name)
+	}
+
+	/// # Validate.
+	///
+	/// Check the animations for consistency with one another.
+	fn validate(&self) {
+		// IDs start at one, and need to fit within the exclusion bitsets.
+		let len = self.animations.len();
+		if len == 0 || 127 < len {
+			fail("animation", &format!("must have between 1 and 127 entries, not {len}."));
+		}
+
+		let mut names = BTreeSet::new();
+		for a in &self.animations {
+			if ! names.insert(a.name.as_str()) { fail(&a.name, "duplicate name."); }
+		}
+
+		for a in &self.animations {
+			let ctx = a.name.as_str();
+
+			// Children need to be children, and only primaries can have them.
+			if let Some(child) = a.child.as_deref() {
+				if ! a.kind.primary() { fail(ctx, "child animations cannot have children."); }
+				match self.get(child) {
+					Some(c) if ! c.kind.primary() => {},
+					Some(_) => fail(ctx, &format!("child {child} is not a child animation.")),
+					None => fail(ctx, &format!("unknown child {child}.")),
+				}
+			}
+
+			// Size requirements belong to the primary, and the fallbacks have
+			// to fit anywhere.
+			if a.min_size != (0, 0) {
+				if ! a.kind.primary() {
+					fail(ctx, "child animations cannot have minimum sizes; set them on the primary.");
+				}
+				if matches!(ctx, "Fall" | "Rotate" | "Walk") {
+					fail(ctx, "fallback animations cannot have minimum sizes.");
+				}
+			}
+
+			// Successors have to stay on the same side of the fence.
+			for (key, list) in [("next", &a.next), ("next-edge", &a.next_edge)] {
+				for (next, _) in list.iter().flatten() {
+					match self.get(next) {
+						Some(n) if n.kind.primary() == a.kind.primary() => {},
+						Some(_) => fail(ctx, &format!("{key} {next} must be the same kind of animation.")),
+						None => fail(ctx, &format!("unknown {key} {next}.")),
+					}
+				}
+			}
+
+			// Everything needs scenes, even if only as a (dynamic) template.
+			if a.scenes.is_empty() { fail(ctx, "missing scenes."); }
+
+			// Repeat formulas document the dynamic builders, and only them.
+			let formulas = a.scenes.iter()
+				.filter(|s| matches!(s, SceneDef::Scene { formula: Some(_), .. }))
+				.count();
+			if a.dynamic.is_some() && formulas == 0 {
+				fail(ctx, "dynamic animations need at least one repeat formula.");
+			}
+			else if a.dynamic.is_none() && formulas != 0 {
+				fail(ctx, "repeat formulas are only for dynamic animations.");
+			}
+
+			// And the dimensions they scale with must be spelled out, and
+			// match what the formulas actually use.
+			if a.dynamic.is_some() {
+				let used = a.scenes.iter()
+					.filter_map(|s| match s {
+						SceneDef::Scene { formula: Some((f, _)), .. } => Some(f.as_str()),
+						_ => None,
+					})
+					.flat_map(|f| f.split(|c: char| ! c.is_ascii_alphanumeric()))
+					.fold((false, false), |acc, t| (acc.0 || t == "w", acc.1 || t == "h"));
+				if a.scales == (false, false) {
+					fail(ctx, "dynamic animations must list the dimensions they scale-with.");
+				}
+				if a.scales != used {
+					fail(ctx, "scales-with does not match the dimensions used by the repeat formulas.");
+				}
+			}
+			else if a.scales != (false, false) {
+				fail(ctx, "scales-with is only for dynamic animations.");
+			}
+
+			// Reused scenes have to point somewhere real.
+			for (idx, s) in a.scenes.iter().enumerate() {
+				let SceneDef::Reuse(other, other_idx) = s else { continue; };
+				let ctx = format!("{ctx} scene #{idx}");
+				if *other == a.name { fail(&ctx, "cannot reuse its own scenes."); }
+				match self.get(other).and_then(|o| o.scenes.get(*other_idx)) {
+					Some(_) if self.get(other).is_some_and(|o| o.dynamic.is_some()) =>
+						fail(&ctx, "cannot reuse a dynamic template."),
+					Some(SceneDef::Scene { .. } | SceneDef::EntranceDelay) => {},
+					Some(SceneDef::Reuse(..)) => fail(&ctx, "cannot reuse a reused scene."),
+					None => fail(&ctx, &format!("unknown scene {other} #{other_idx}.")),
+				}
+			}
+		}
+
+		// The default pool.
+		let mut seen = BTreeSet::new();
+		let mut total = 0_usize;
+		for (name, weight) in &self.default_choices {
+			let ctx = "default-choices";
+			if matches!(name.as_str(), "Run" | "Walk") {
+				fail(ctx, &format!("{name} is handled separately."));
+			}
+			if ! self.get(name).is_some_and(|a| a.kind == Kind::Playable) {
+				fail(ctx, &format!("{name} is not a playable animation."));
+			}
+			if ! seen.insert(name) { fail(ctx, &format!("duplicate {name}.")); }
+			total += usize::from(*weight);
+		}
+
+		// We use u16 for capped randomization; make sure this fits once Run's
+		// share has been added.
+		total += total / 2;
+		assert!(
+			total < usize::from(u16::MAX),
+			"Default special animation total is too big: {total}"
+		);
+
+		// The entrance pool.
+		seen.clear();
+		for (name, _) in &self.entrance_choices {
+			let ctx = "entrance-choices";
+			if ! self.get(name).is_some_and(|a| a.kind.primary()) {
+				fail(ctx, &format!("{name} is not a primary animation."));
+			}
+			if ! seen.insert(name) { fail(ctx, &format!("duplicate {name}.")); }
+		}
+		if self.entrance_choices.last().is_none_or(|(name, _)| name != "Fall") {
+			fail("entrance-choices", "Fall must come last.");
 		}
-		from = to;
 	}
+}
 
-	// Make sure we got 'em all.
-	assert_eq!(from, total, "Missing a default animation match arm.");
+impl Definitions {
+	/// # Build Animations.
+	///
+	/// Generate the `Animation` type and all of its property and transition
+	/// methods, along with the default and entrance pools.
+	fn build_animations(&self) -> String {
+		let mut out = String::new();
+		self.build_type(&mut out);
+		self.build_choices(&mut out);
+		self.build_properties(&mut out);
+		self.build_transitions(&mut out);
+		out
+	}
 
-	// Build the statement!
-	format!(
-		r"impl Animation {{
-	/// # Default Choice.
-	///
-	/// Return a generic default animation for use in contexts where no
-	/// explicit choice is supplied.
-	pub(crate) fn default_choice() -> Self {{
-		if 0 == Universe::rand_mod(3) {{ Self::Walk }}
-		else {{
-			let mut last = LAST_SPECIAL.load(SeqCst).to_le_bytes();
-			loop {{
-				let next = match Universe::rand_mod({total}) {{
-{}
-				}};
-
-				// Accept and return the choice so long as it is fresh.
-				if match next {{
-					// Let Run happen every third choice.
-					Self::Run => last[0] != Self::Run as u8 && last[1] != Self::Run as u8,
-					// Only keep a low-priority selection if none of them were
-					// recently chosen.
-					Self::Abduction |
-						Self::Bleat |
-						Self::EatMagicFlower |
-						Self::Glitch |
-						Self::PlayDead |
-						Self::Scoot |
-						Self::Scream |
-						Self::ShadowShowdown |
-						Self::Sneeze |
-						Self::Tornado |
-						Self::Urinate |
-						Self::Yawn =>
-							is_fresh(Self::Abduction, last) &&
-							is_fresh(Self::Bleat, last) &&
-							is_fresh(Self::EatMagicFlower, last) &&
-							is_fresh(Self::Glitch, last) &&
-							is_fresh(Self::PlayDead, last) &&
-							is_fresh(Self::Scoot, last) &&
-							is_fresh(Self::Scream, last) &&
-							is_fresh(Self::ShadowShowdown, last) &&
-							is_fresh(Self::Sneeze, last) &&
-							is_fresh(Self::Tornado, last) &&
-							is_fresh(Self::Urinate, last) &&
-							is_fresh(Self::Yawn, last),
-					// For everything else, 1/5 for itself is fine.
-					_ => is_fresh(next, last),
-				}} {{
-					last.rotate_right(1);
-					last[0] = next as u8;
-					LAST_SPECIAL.store(u32::from_le_bytes(last), SeqCst);
-					return next;
-				}}
-			}}
-		}}
-	}}
-}}",
-		arms.join("\n")
+	/// # Build Type.
+	///
+	/// Generate the `Animation` type, its built-in constants, and basic
+	/// conversion methods.
+	fn build_type(&self, out: &mut String) {
+		let len = self.animations.len();
+		out.push_str(r#"#[derive(Clone, Copy, Eq, PartialEq)]
+/// # Animations.
+///
+/// This holds all possible sprite animations, for both primary and child
+/// mates, as well as secondary (linked) sequences.
+///
+/// Each animation is identified by a `u8`, starting with `1`. The built-in
+/// ones are exposed as associated constants; any IDs beyond those belong to
+/// runtime-loaded packs (see `Poe.loadPack`).
+pub(crate) struct Animation(u8);
+
+#[expect(clippy::missing_docs_in_private_items, reason = "Self-explanatory.")]
+#[expect(non_upper_case_globals, reason = "These stand in for enum variants.")]
+impl Animation {
+"#);
+		for (idx, a) in self.animations.iter().enumerate() {
+			writeln!(out, "\tpub(crate) const {}: Self = Self({});", a.name, idx + 1).unwrap();
+		}
+		out.push_str("}\n\n");
+
+		writeln!(
+			out,
+			"impl Animation {{
+	/// # Maximum Animation ID.
+	///
+	/// This is the highest _built-in_ ID; pack animations follow it.
+	const MAX_ANIMATION_ID: u8 = {len};
+
+	/// # All Animations.
+	///
+	/// This only includes the built-in animations.
+	pub(crate) const ALL: [Self; Self::MAX_ANIMATION_ID as usize] = [",
+		).unwrap();
+		for a in &self.animations { writeln!(out, "\t\tSelf::{},", a.name).unwrap(); }
+		out.push_str(r"	];
+
+	/// # From U8.
+	///
+	/// Return the `Animation` corresponding to the given ID, or `None` if out
+	/// of range.
+	pub(crate) fn from_u8(src: u8) -> Option<Self> {
+		if 0 != src && src <= Self::MAX_ANIMATION_ID { Some(Self(src)) }
+		else { pack::from_u8(src) }
+	}
+
+	/// # ID.
+	///
+	/// Return the animation's numeric ID. This is the inverse of
+	/// `Animation::from_u8`.
+	pub(crate) const fn id(self) -> u8 { self.0 }
+");
+
+		gen_fn(
+			out,
+			"From Name.
+
+Return the `Animation` corresponding to the given (case-sensitive)
+variant name, e.g. `LookDown`, or `None` if there is no match.",
+			"pub(crate) fn from_name(src: &str) -> Option<Self>",
+			&gen_match(
+				"src",
+				self.animations.iter().map(|a| (format!("{:?}", a.name), format!("Some(Self::{})", a.name))),
+				Some("pack::from_name(src)"),
+			),
+		);
+
+		gen_fn(
+			out,
+			"As Str.
+
+Return a human-readable \"title\" for the `Animation` as a static string
+slice.",
+			"#[must_use]\npub(crate) fn as_str(self) -> &'static str",
+			&self.gen_self_match(
+				|a| Some(format!("{:?}", a.title)),
+				Some("pack::get(self).map_or(\"\", |p| p.title)"),
+			),
+		);
+
+		gen_fn(
+			out,
+			"Name.
+
+Return the variant name, e.g. `LookDown`, as a static string
+slice. This is the inverse of `Animation::from_name`.",
+			"#[must_use]\npub(crate) fn name(self) -> &'static str",
+			&self.gen_self_match(
+				|a| Some(format!("{:?}", a.name)),
+				Some("pack::get(self).map_or(\"\", |p| p.name)"),
+			),
+		);
+
+		gen_fn(
+			out,
+			"Scenes.
+
+Return the animation's `SceneList`.
+
+Most of these are completely static, identical from run-to-run, but a
+few have randomized or environmental modifiers, tweaking them slightly.",
+			"pub(crate) fn scenes(self, width: u16, height: u16) -> SceneList",
+			&self.gen_self_match(
+				|a| Some(a.dynamic.as_ref().map_or_else(
+					|| format!("const {{ SceneList::new(SceneListKind::Fixed(scenes::{})) }}", a.const_name()),
+					|f| format!("SceneList::new(SceneListKind::Inline(InlineScenes::new(scenes::{f}(width, height))))"),
+				)),
+				Some("pack::scenes(self, width, height)"),
+			),
+		);
+
+		gen_fn(
+			out,
+			"Repeat Formulas.
+
+Return the `(index, formula, from)` repeat of each screen-scaled scene
+of a dynamic animation, as written in `skel/animations.toml`. (The
+builders in `scenes.rs` are held to these by the unit tests.)",
+			"pub(crate) const fn repeat_formulas(self) -> &'static [(usize, &'static str, u8)]",
+			&self.gen_self_match(
+				|a| {
+					let list = a.scenes.iter()
+						.enumerate()
+						.filter_map(|(idx, s)| match s {
+							SceneDef::Scene { formula: Some((f, from)), .. } =>
+								Some(format!("({idx}, {f:?}, {from})")),
+							_ => None,
+						})
+						.collect::<Vec<_>>();
+					(! list.is_empty()).then(|| format!("&[{}]", list.join(", ")))
+				},
+				Some("&[]"),
+			),
+		);
+
+		out.push_str("}\n\n");
+	}
+
+	/// # Build Choices.
+	///
+	/// This exports the pool of animations eligible for selection by
+	/// `Animation::default_choice`, along with their default weightings and
+	/// the runtime (configurable) equivalents, and the same for
+	/// `Animation::entrance_choice`.
+	///
+	/// `Run` is prepended to the default list with a weight equal to half the
+	/// total of the rest; `Walk` is handled separately.
+	///
+	/// The non-walk animations are weighted to prioritize certain sequences
+	/// over others. (Disruptive-ish animations, including those that make
+	/// sound, are given the lowest weight.)
+	fn build_choices(&self, out: &mut String) {
+		let total: u16 = self.default_choices.iter().map(|(_, w)| *w).sum();
+		let run = total / 2;
+		let defaults: Vec<(&str, u16)> = std::iter::once(("Run", run))
+			.chain(self.default_choices.iter().map(|(a, w)| (a.as_str(), *w)))
+			.collect();
+		let entrances: Vec<(&str, u16)> = self.entrance_choices.iter()
+			.map(|(a, w)| (a.as_str(), *w))
+			.collect();
+
+		let choices = |set: &[(&str, u16)]| set.iter()
+			.fold(String::new(), |mut acc, (a, _)| {
+				writeln!(acc, "\t\tSelf::{a},").unwrap();
+				acc
+			});
+		let weights = |set: &[(&str, u16)]| set.iter()
+			.map(|(_, w)| w.to_string())
+			.collect::<Vec<_>>()
+			.join(", ");
+		let atomics = |set: &[(&str, u16)]| set.iter()
+			.fold(String::new(), |mut acc, (_, w)| {
+				writeln!(acc, "\tAtomicU16::new({w}),").unwrap();
+				acc
+			});
+
+		write!(
+			out,
+			r"impl Animation {{
+	/// # Default Choices.
+	///
+	/// These are the animations eligible for selection by
+	/// `Animation::default_choice` (aside from `Walk`).
+	pub(crate) const DEFAULT_CHOICES: [Self; {dlen}] = [
+{dchoices}	];
+
+	/// # Default Weights.
+	///
+	/// The out-of-the-box weightings for each of the `DEFAULT_CHOICES`,
+	/// totalling {dtotal}.
+	pub(crate) const DEFAULT_WEIGHTS: [u16; {dlen}] = [{dweights}];
+
+	/// # Entrance Choices.
+	///
+	/// These are the animations eligible for selection by
+	/// `Animation::entrance_choice`. (`Fall` is only used for the very first
+	/// entrance.)
+	pub(crate) const ENTRANCE_CHOICES: [Self; {elen}] = [
+{echoices}	];
+
+	/// # Entrance Weights.
+	///
+	/// The out-of-the-box weightings for each of the `ENTRANCE_CHOICES`.
+	pub(crate) const ENTRANCE_WEIGHTS: [u16; {elen}] = [{eweights}];
+}}
+
+/// # Default Weights.
+///
+/// The runtime weightings used by `Animation::default_choice`, which may be
+/// overridden by the user via `Poe.configure`.
+static DEFAULT_WEIGHTS: [AtomicU16; {dlen}] = [
+{datomics}];
+
+/// # Entrance Weights.
+///
+/// The runtime weightings used by `Animation::entrance_choice`, which may be
+/// overridden by the user via `Poe.configure`.
+static ENTRANCE_WEIGHTS: [AtomicU16; {elen}] = [
+{eatomics}];
+
+",
+			dlen = defaults.len(),
+			dchoices = choices(&defaults),
+			dtotal = total + run,
+			dweights = weights(&defaults),
+			datomics = atomics(&defaults),
+			elen = entrances.len(),
+			echoices = choices(&entrances),
+			eweights = weights(&entrances),
+			eatomics = atomics(&entrances),
+		).unwrap();
+	}
+
+	/// # Build Properties.
+	///
+	/// Generate the various property lookup methods.
+	fn build_properties(&self, out: &mut String) {
+		out.push_str("impl Animation {\n");
+
+		gen_fn(
+			out,
+			"Directly Playable?
+
+Returns `true` if the animation can be cued up via the userland setter
+`Poe.play`.",
+			"pub(crate) fn playable(self) -> bool",
+			&self.gen_flag(|a| a.kind == Kind::Playable, "playable()"),
+		);
+
+		gen_fn(
+			out,
+			"Clamp to Wall.
+
+Returns the side — left or right — to clamp the animation's X position
+to, if any. (If flipped, the opposite should be applied.)",
+			"pub(crate) const fn clamp_x(self) -> Option<Direction>",
+			&self.gen_self_match(
+				|a| a.clamp_x.map(|d| format!("Some(Direction::{d})")),
+				Some("None"),
+			),
+		);
+
+		gen_fn(
+			out,
+			"CSS Class.
+
+Return the stylized animation \"number\", if any. As there can be only
+one, the value slots into a custom `data-a` attribute on the wrapper
+element.",
+			"pub(crate) const fn css_class(self) -> &'static str",
+			&self.gen_self_match(|a| a.css.as_ref().map(|c| format!("{c:?}")), Some("\"\"")),
+		);
+
+		gen_fn(
+			out,
+			"Minimum Screen Size.
+
+Return the minimum screen width and height needed for the animation —
+and its child, if any — to play properly. Choosers skip animations
+that don't fit.",
+			"pub(crate) fn min_size(self) -> (u16, u16)",
+			&self.gen_self_match(
+				|a| (a.min_size != (0, 0)).then(|| format!("{:?}", a.min_size)),
+				Some("pack::get(self).map_or((0, 0), |p| p.min_size)"),
+			),
+		);
+
+		gen_fn(
+			out,
+			"Flip (X).
+
+Returns `true` if the animation needs to flip the sprite image
+horizontally.",
+			"pub(crate) fn flip_x(self) -> bool",
+			&self.gen_flag(|a| a.flip_x, "flip_x"),
+		);
+
+		gen_fn(
+			out,
+			"Animation May Exit Screen?
+
+Returns `true` if the relative-moving animation is allowed to ever
+leave the viewport. (Actual passage is randomly assigned at runtime.)",
+			"pub(crate) fn may_exit(self) -> bool",
+			&self.gen_flag(|a| a.may_exit, "may_exit"),
+		);
+
+		gen_fn(
+			out,
+			"Is Primary Animation?
+
+Returns `true` if the animation only applies to the primary sprite.",
+			"pub(crate) fn primary(self) -> bool",
+			&self.gen_flag(|a| a.kind.primary(), "primary()"),
+		);
+
+		gen_fn(
+			out,
+			"Smooth?
+
+Some of the faster animations can benefit from a little \"transform\"
+smoothing.",
+			"pub(crate) fn smooth(self) -> bool",
+			&self.gen_flag(|a| a.smooth, "smooth"),
+		);
+
+		gen_fn(
+			out,
+			"Child Animation.
+
+Return the child animation required by this primary animation, if any.",
+			"pub(crate) fn child(self) -> Option<Self>",
+			&self.gen_self_match(
+				|a| a.child.as_ref().map(|c| format!("Some(Self::{c})")),
+				Some("pack::get(self).and_then(|p| p.child)"),
+			),
+		);
+
+		out.push_str("}\n\n");
+	}
+
+	/// # Build Transitions.
+	///
+	/// Generate the weighted transition tables behind `Animation::next` and
+	/// `Animation::next_edge`. The successors from every table are flattened
+	/// into a single list — `next` then `next-edge`, in animation order — with
+	/// each animation mapped to its slice.
+	fn build_transitions(&self, out: &mut String) {
+		let mut choices = String::new();
+		let mut weights = Vec::new();
+		let mut atomics = String::new();
+		let mut ranges: [Vec<(String, String)>; 2] = [Vec::new(), Vec::new()];
+		for (idx, arms) in ranges.iter_mut().enumerate() {
+			for a in &self.animations {
+				let list = if idx == 0 { &a.next } else { &a.next_edge };
+				let Some(list) = list else { continue; };
+				let from = weights.len();
+				for (next, w) in list {
+					writeln!(choices, "\t\tSelf::{next},").unwrap();
+					writeln!(atomics, "\tAtomicU16::new({w}),").unwrap();
+					weights.push(w.to_string());
+				}
+				arms.push((format!("Self::{}", a.name), format!("Some({from}..{})", weights.len())));
+			}
+		}
+
+		write!(
+			out,
+			r"impl Animation {{
+	/// # Transition Choices.
+	///
+	/// The successors from every built-in `next` and `next_edge` table,
+	/// flattened. See `Animation::next_table` and
+	/// `Animation::next_edge_table` for the slices.
+	pub(crate) const TRANSITION_CHOICES: [Self; {len}] = [
+{choices}	];
+
+	/// # Transition Weights.
+	///
+	/// The out-of-the-box weightings for each of the `TRANSITION_CHOICES`.
+	pub(crate) const TRANSITION_WEIGHTS: [u16; {len}] = [{weights}];
+}}
+
+/// # Transition Weights.
+///
+/// The runtime weightings used by `Animation::next` and
+/// `Animation::next_edge`, which may be overridden by the user via
+/// `Poe.configure`.
+static TRANSITION_WEIGHTS: [AtomicU16; {len}] = [
+{atomics}];
+
+impl Animation {{",
+			len = weights.len(),
+			weights = weights.join(", "),
+		).unwrap();
+
+		let [next, next_edge] = ranges;
+		gen_fn(
+			out,
+			"Next Table.
+
+The slice of `TRANSITION_CHOICES` (and weights) behind `Animation::next`,
+if any. Pack animations keep their own tables.",
+			"pub(crate) const fn next_table(self) -> Option<Range<usize>>",
+			&gen_match("self", next.into_iter(), Some("None")),
+		);
+
+		gen_fn(
+			out,
+			"Next at Edge Table.
+
+The slice of `TRANSITION_CHOICES` (and weights) behind
+`Animation::next_edge`, if any. Pack animations keep their own tables.",
+			"pub(crate) const fn next_edge_table(self) -> Option<Range<usize>>",
+			&gen_match("self", next_edge.into_iter(), Some("None")),
+		);
+
+		out.push_str("}\n");
+	}
+
+	/// # Build Playlist.
+	///
+	/// Generate the list of directly-playable animations, one per line, with
+	/// their IDs, for `Animation::PLAYLIST`.
+	fn build_playlist(&self) -> String {
+		self.animations.iter()
+			.enumerate()
+			.filter_map(|(idx, a)|
+				if matches!(a.kind, Kind::Playable) { Some(format!("#{:<4}{}", idx + 1, a.title)) }
+				else { None }
+			)
+			.collect::<Vec<_>>()
+			.join("\n")
+	}
+
+	/// # Build Scenes.
+	///
+	/// Generate the static scene constants — or for dynamic animations,
+	/// templates — one per animation, along with `const` assertions
+	/// validating every animation's scenes so mistakes fail the build. The
+	/// dynamic ones are checked at each of the sampled `CHECK_WIDTHS` and/or
+	/// `CHECK_HEIGHTS`, depending on which dimensions they scale with.
+	fn build_scenes(&self) -> String {
+		let mut out = String::new();
+		for a in &self.animations {
+			write!(
+				out,
+				"/// # For `Animation::{}`.\npub(super) const {}: &[Scene; {}] = &[\n",
+				a.name,
+				a.const_name(),
+				a.scenes.len(),
+			).unwrap();
+			for s in &a.scenes {
+				match s {
+					SceneDef::EntranceDelay => out.push_str("\tENTRANCE_DELAY,\n"),
+					SceneDef::Reuse(other, idx) => {
+						let other = self.get(other).expect("Missing animation.");
+						writeln!(out, "\t{}[{idx}],", other.const_name()).unwrap();
+					},
+					SceneDef::Scene { fpms, frames, move_to, repeat, sounds, ease, flags, .. } => {
+						let frames = frames.iter()
+							.map(|f| format!("Frame::{f}"))
+							.collect::<Vec<_>>()
+							.join(", ");
+						write!(out, "\tScene::new({fpms}, &[{frames}])").unwrap();
+						if let Some((x, y)) = move_to {
+							write!(out, "\n\t\t.with_move_to(Position::new({x}, {y}))").unwrap();
+						}
+						if let Some((n, from)) = repeat {
+							write!(out, "\n\t\t.with_repeat({n}, {from})").unwrap();
+						}
+						if ! sounds.is_empty() {
+							let sounds = sounds.iter()
+								.map(|(s, idx, chance, every)| {
+									let mut cue = format!("SoundCue::new(Sound::{s}, {idx})");
+									if *chance != 100 { write!(cue, ".with_chance({chance})").unwrap(); }
+									if *every { cue.push_str(".every_loop()"); }
+									cue
+								})
+								.collect::<Vec<_>>()
+								.join(", ");
+							write!(out, "\n\t\t.with_sounds(&[{sounds}])").unwrap();
+						}
+						if let Some(e) = ease {
+							write!(out, "\n\t\t.with_ease(Ease::{e})").unwrap();
+						}
+						if ! flags.is_empty() {
+							let flags = flags.iter()
+								.map(|f| format!("Scene::{f}"))
+								.collect::<Vec<_>>()
+								.join(" | ");
+							write!(out, "\n\t\t.with_flags({flags})").unwrap();
+						}
+						out.push_str(",\n");
+					},
+				}
+			}
+			out.push_str("];\n\n");
+		}
+
+		// The inline capacity needed for the longest dynamic list.
+		let inline = self.animations.iter()
+			.filter_map(|a| a.dynamic.as_ref().map(|_| a.scenes.len()))
+			.max()
+			.unwrap_or(0);
+		write!(
+			out,
+			"/// # Dynamic Scene Capacity.
+///
+/// The length of the longest dynamic template, i.e. the most scenes an
+/// `InlineScenes` needs to hold.
+pub(super) const DYNAMIC_SCENES: usize = {inline};\n\n",
+		).unwrap();
+
+		// Compile-time checks.
+		for a in &self.animations {
+			if let Some(f) = a.dynamic.as_deref() {
+				let (width, height) = a.scales;
+				out.push_str("const _: () = {\n\tlet mut i = 0;\n");
+				if width {
+					writeln!(
+						out,
+						"\twhile i < CHECK_WIDTHS.len() {{
+		let w = CHECK_WIDTHS[i];
+		assert_valid({name:?}, Some((w, CHECK_HEIGHT)), &{f}(w, CHECK_HEIGHT));
+		i += 1;
+	}}",
+						name=a.name,
+					).unwrap();
+				}
+				if height {
+					if width { out.push_str("\ti = 0;\n"); }
+					writeln!(
+						out,
+						"\twhile i < CHECK_HEIGHTS.len() {{
+		let h = CHECK_HEIGHTS[i];
+		assert_valid({name:?}, Some((CHECK_WIDTH, h)), &{f}(CHECK_WIDTH, h));
+		i += 1;
+	}}",
+						name=a.name,
+					).unwrap();
+				}
+				if ! width && ! height {
+					writeln!(
+						out,
+						"\tassert_valid({name:?}, Some((CHECK_WIDTH, CHECK_HEIGHT)), &{f}(CHECK_WIDTH, CHECK_HEIGHT));",
+						name=a.name,
+					).unwrap();
+				}
+				out.push_str("};\n");
+			}
+			else {
+				writeln!(
+					out,
+					"const _: () = assert_valid({:?}, None, {});",
+					a.name,
+					a.const_name(),
+				).unwrap();
+			}
+		}
+
+		out
+	}
+
+	/// # Generate Flag.
+	///
+	/// Return a `match` expression yielding `true` for the animations
+	/// satisfying `cb`, deferring to the pack `field` (or method) for
+	/// everything else.
+	fn gen_flag<F>(&self, cb: F, field: &str) -> String
+	where F: Fn(&AnimationDef) -> bool {
+		self.gen_self_match(
+			|a| cb(a).then(|| "true".to_owned()),
+			Some(&format!("pack::get(self).is_some_and(|p| p.{field})")),
+		)
+	}
+
+	/// # Generate `match self`.
+	///
+	/// Return a `match` expression over the animations, using the values
+	/// returned by `cb`, and `fallback` for any `None`s.
+	fn gen_self_match<F>(&self, cb: F, fallback: Option<&str>) -> String
+	where F: Fn(&AnimationDef) -> Option<String> {
+		gen_match(
+			"self",
+			self.animations.iter().filter_map(|a| cb(a).map(|v| (format!("Self::{}", a.name), v))),
+			fallback,
+		)
+	}
+}
+
+
+
+/// # Fail.
+///
+/// Abort the build with an error message pertaining to the animation
+/// definitions.
+fn fail(ctx: &str, msg: &str) -> ! {
+	panic!("{ANIMATIONS}: {ctx}: {msg}");
+}
+
+/// # Enum Variants.
+///
+/// Return the variant names for a simple (fieldless) enum defined in the
+/// given file.
+fn enum_variants(path: &str, name: &str) -> BTreeSet<String> {
+	let raw = std::fs::read_to_string(path)
+		.unwrap_or_else(|_| panic!("Unable to read {path}."));
+	let start = format!("enum {name} {{");
+	let raw = raw.split_once(&start)
+		.and_then(|(_, rest)| rest.split_once('}'))
+		.map(|(body, _)| body)
+		.unwrap_or_else(|| panic!("Unable to find {name} in {path}."));
+
+	raw.lines()
+		.filter_map(|line| {
+			let line = line.split_once("//").map_or(line, |(l, _)| l);
+			let line = line.split(['=', ',']).next()?.trim();
+			if line.is_empty() { None }
+			else { Some(line.to_owned()) }
+		})
+		.collect()
+}
+
+/// # Generate Function.
+///
+/// Append a method to `out`, wrapping `body` with the signature and docs,
+/// and expecting clippy's `too_many_lines` lint if it is long enough to
+/// trigger it.
+fn gen_fn(out: &mut String, docs: &str, sig: &str, body: &str) {
+	let (attrs, sig) = sig.rsplit_once('\n').unwrap_or(("", sig));
+	let body = body.lines()
+		.map(|line| format!("\t\t{line}"))
+		.collect::<Vec<_>>()
+		.join("\n");
+
+	out.push('\n');
+	if TOO_MANY_LINES < body.lines().filter(|l| ! l.trim().is_empty()).count() + 2 {
+		out.push_str("\t#[expect(clippy::too_many_lines, reason = \"There are a lot of animations.\")]\n");
+	}
+	for line in attrs.lines() { writeln!(out, "\t{line}").unwrap(); }
+	for (idx, line) in docs.lines().enumerate() {
+		if idx == 0 { writeln!(out, "\t/// # {line}").unwrap(); }
+		else if line.is_empty() { out.push_str("\t///\n"); }
+		else { writeln!(out, "\t/// {line}").unwrap(); }
+	}
+	writeln!(out, "\t{sig} {{\n{body}\n\t}}").unwrap();
+}
+
+/// # Generate Match.
+///
+/// Return a `match` expression for `arms`, grouping patterns that share the
+/// same value (in order of first appearance), with an optional catch-all.
+fn gen_match<I>(subject: &str, arms: I, fallback: Option<&str>) -> String
+where I: Iterator<Item=(String, String)> {
+	let mut groups: Vec<(Vec<String>, String)> = Vec::new();
+	for (pat, val) in arms {
+		if let Some((pats, _)) = groups.iter_mut().find(|(_, v)| *v == val) { pats.push(pat); }
+		else { groups.push((vec![pat], val)); }
+	}
+
+	let mut out = format!("match {subject} {{\n");
+	for (pats, val) in groups {
+		let indent = if pats.len() == 1 { "\n\t" } else { "\n\t\t" };
+		writeln!(out, "\t{} => {},", pats.join(" |\n\t\t"), val.replace('\n', indent)).unwrap();
+	}
+	if let Some(fallback) = fallback { writeln!(out, "\t_ => {fallback},").unwrap(); }
+	out.push('}');
+	out
+}
+
+/// # Parse Animation.
+fn parse_animation(idx: usize, raw: &Value, frames: &BTreeSet<String>, sounds: &BTreeSet<String>)
+-> AnimationDef {
+	let Value::Table(raw) = raw else { fail(&format!("animation #{idx}"), "must be a table."); };
+	let name = match raw.get("name") {
+		Some(Value::String(s)) if is_ident(s, true) => s.clone(),
+		_ => fail(&format!("animation #{idx}"), "missing or invalid name."),
+	};
+	let ctx = name.as_str();
+
+	let mut out = AnimationDef {
+		name: name.clone(),
+		title: String::new(),
+		kind: Kind::Child,
+		smooth: false,
+		flip_x: false,
+		may_exit: false,
+		clamp_x: None,
+		min_size: (0, 0),
+		css: None,
+		child: None,
+		next: None,
+		next_edge: None,
+		dynamic: None,
+		scales: (false, false),
+		scenes: Vec::new(),
+	};
+
+	let mut kind = None;
+	for (k, v) in raw {
+		match k.as_str() {
+			"name" => {},
+			"title" => { out.title = string(ctx, k, v); },
+			"kind" => {
+				kind.replace(match string(ctx, k, v).as_str() {
+					"playable" => Kind::Playable,
+					"support" => Kind::Support,
+					"child" => Kind::Child,
+					_ => fail(ctx, "kind must be playable, support, or child."),
+				});
+			},
+			"smooth" => { out.smooth = boolean(ctx, k, v); },
+			"flip-x" => { out.flip_x = boolean(ctx, k, v); },
+			"may-exit" => { out.may_exit = boolean(ctx, k, v); },
+			"clamp-x" => {
+				out.clamp_x.replace(match string(ctx, k, v).as_str() {
+					"left" => "Left",
+					"right" => "Right",
+					_ => fail(ctx, "clamp-x must be left or right."),
+				});
+			},
+			"min-width" => { out.min_size.0 = int(ctx, k, v); },
+			"min-height" => { out.min_size.1 = int(ctx, k, v); },
+			"css" => {
+				let css = string(ctx, k, v);
+				if css.is_empty() || ! css.bytes().all(|b| b.is_ascii_alphanumeric()) {
+					fail(ctx, "css must be alphanumeric.");
+				}
+				out.css.replace(css);
+			},
+			"child" => { out.child.replace(string(ctx, k, v)); },
+			"next" => { out.next.replace(transition(ctx, k, v)); },
+			"next-edge" => { out.next_edge.replace(transition(ctx, k, v)); },
+			"dynamic" => {
+				let f = string(ctx, k, v);
+				if ! is_ident(&f, false) { fail(ctx, "dynamic must be a function name."); }
+				out.dynamic.replace(f);
+			},
+			"scales-with" => {
+				let Value::Array(dims) = v else { fail(ctx, "scales-with must be an array."); };
+				for dim in dims {
+					match dim.as_str() {
+						Some("width") if ! out.scales.0 => { out.scales.0 = true; },
+						Some("height") if ! out.scales.1 => { out.scales.1 = true; },
+						_ => fail(ctx, "scales-with must list width and/or height, once each."),
+					}
+				}
+			},
+			"scene" => {
+				let Value::Array(v) = v else { fail(ctx, "scene must be an array of tables."); };
+				out.scenes = v.iter()
+					.enumerate()
+					.map(|(idx, v)| parse_scene(&format!("{ctx} scene #{idx}"), v, frames, sounds))
+					.collect();
+			},
+			_ => fail(ctx, &format!("unknown key {k}.")),
+		}
+	}
+
+	if out.title.is_empty() { fail(ctx, "missing title."); }
+	out.kind = kind.unwrap_or_else(|| fail(ctx, "missing kind."));
+	out
+}
+
+/// # Parse Scene.
+fn parse_scene(ctx: &str, raw: &Value, frames: &BTreeSet<String>, sounds: &BTreeSet<String>)
+-> SceneDef {
+	let Value::Table(raw) = raw else { fail(ctx, "must be a table."); };
+
+	// Special cases.
+	if raw.contains_key("entrance-delay") || raw.contains_key("reuse") {
+		if raw.len() != 1 { fail(ctx, "entrance-delay and reuse cannot be combined with other keys."); }
+		if let Some(v) = raw.get("entrance-delay") {
+			if ! boolean(ctx, "entrance-delay", v) { fail(ctx, "entrance-delay must be true."); }
+			return SceneDef::EntranceDelay;
+		}
+		let (name, idx) = pair(ctx, "reuse", &raw["reuse"]);
+		return SceneDef::Reuse(string(ctx, "reuse", &name), int(ctx, "reuse", &idx));
+	}
+
+	let mut fpms = None;
+	let mut out_frames = Vec::new();
+	let mut move_to = None;
+	let mut repeat = None;
+	let mut formula = None;
+	let mut cues = Vec::new();
+	let mut ease = None;
+	let mut flags = Vec::new();
+	for (k, v) in raw {
+		match k.as_str() {
+			"fpms" => { fpms.replace(int(ctx, k, v)); },
+			"frames" => {
+				for f in string(ctx, k, v).split_whitespace() {
+					if ! frames.contains(f) { fail(ctx, &format!("unknown frame {f}.")); }
+					out_frames.push(f.to_owned());
+				}
+			},
+			"move-to" => {
+				let (x, y) = pair(ctx, k, v);
+				move_to.replace((int(ctx, k, &x), int(ctx, k, &y)));
+			},
+			"repeat" => {
+				let (n, from) = pair(ctx, k, v);
+				if let Value::String(n) = n {
+					if n.trim().is_empty() { fail(ctx, "repeat formulas cannot be empty."); }
+					formula.replace((n, int(ctx, k, &from)));
+				}
+				else { repeat.replace((int(ctx, k, &n), int(ctx, k, &from))); }
+			},
+			"sound" => {
+				let (s, idx) = pair(ctx, k, v);
+				let s = string(ctx, k, &s);
+				if ! sounds.contains(&s) { fail(ctx, &format!("unknown sound {s}.")); }
+				cues.push((s, int(ctx, k, &idx), 100, false));
+			},
+			"sounds" => {
+				let Value::Array(v) = v else { fail(ctx, "sounds must be an array."); };
+				for cue in v { cues.push(parse_sound_cue(ctx, cue, sounds)); }
+			},
+			"ease" => { ease.replace(parse_ease(ctx, v)); },
+			"flags" => {
+				let Value::Array(v) = v else { fail(ctx, "flags must be an array."); };
+				for f in v {
+					let f = string(ctx, k, f);
+					let Some((_, flag)) = SCENE_FLAGS.iter().find(|(n, _)| *n == f) else {
+						fail(ctx, &format!("unknown flag {f}."));
+					};
+					if flags.contains(flag) { fail(ctx, &format!("duplicate flag {f}.")); }
+					flags.push(*flag);
+				}
+			},
+			_ => fail(ctx, &format!("unknown key {k}.")),
+		}
+	}
+
+	if out_frames.is_empty() { fail(ctx, "missing frames."); }
+	SceneDef::Scene {
+		fpms: fpms.unwrap_or_else(|| fail(ctx, "missing fpms.")),
+		frames: out_frames,
+		move_to,
+		repeat,
+		formula,
+		sounds: cues,
+		ease,
+		flags,
+	}
+}
+
+/// # Parse Sound Cue.
+///
+/// Cues are tables with a `sound` name and step `idx`, and optionally a
+/// percentage `chance` and/or `every-loop` flag. (Ranges are checked along
+/// with the rest of the scene at compile time.)
+fn parse_sound_cue(ctx: &str, raw: &Value, sounds: &BTreeSet<String>)
+-> (String, u8, u8, bool) {
+	let Value::Table(raw) = raw else { fail(ctx, "sounds must be an array of tables."); };
+	let mut sound = None;
+	let mut idx = None;
+	let mut chance = 100;
+	let mut every = false;
+	for (k, v) in raw {
+		match k.as_str() {
+			"sound" => {
+				let s = string(ctx, k, v);
+				if ! sounds.contains(&s) { fail(ctx, &format!("unknown sound {s}.")); }
+				sound.replace(s);
+			},
+			"idx" => { idx.replace(int(ctx, k, v)); },
+			"chance" => { chance = int(ctx, k, v); },
+			"every-loop" => { every = boolean(ctx, k, v); },
+			_ => fail(ctx, &format!("unknown sound key {k}.")),
+		}
+	}
+
+	(
+		sound.unwrap_or_else(|| fail(ctx, "sound cue missing sound.")),
+		idx.unwrap_or_else(|| fail(ctx, "sound cue missing idx.")),
+		chance,
+		every,
 	)
 }
 
+/// # Parse Ease.
+///
+/// Easing curves are either named, or given as `[x1, y1, x2, y2]` cubic
+/// bezier control points. (The latter are range-checked along with the rest
+/// of the scene at compile time.)
+fn parse_ease(ctx: &str, v: &Value) -> String {
+	if let Some(name) = v.as_str() {
+		let Some((_, ease)) = SCENE_EASES.iter().find(|(n, _)| *n == name) else {
+			fail(ctx, &format!("unknown ease {name}."));
+		};
+		return (*ease).to_owned();
+	}
+
+	let points = match v.as_array().map(Vec::as_slice) {
+		Some(points @ [_, _, _, _]) => points.iter()
+			.map(|p| {
+				let p = p.as_float()
+					.or_else(|| p.as_integer().map(|n| n as f64))
+					.unwrap_or_else(|| fail(ctx, "ease points must be numbers."));
+				format!("{}", (p * 1000.0).round() as i16)
+			})
+			.collect::<Vec<_>>()
+			.join(", "),
+		_ => fail(ctx, "ease must be a name or four-element array."),
+	};
+	format!("Bezier([{points}])")
+}
+
+/// # Boolean Value.
+fn boolean(ctx: &str, key: &str, v: &Value) -> bool {
+	v.as_bool().unwrap_or_else(|| fail(ctx, &format!("{key} must be a boolean.")))
+}
+
+/// # Integer Value.
+fn int<T: TryFrom<i64>>(ctx: &str, key: &str, v: &Value) -> T {
+	v.as_integer()
+		.and_then(|n| T::try_from(n).ok())
+		.unwrap_or_else(|| fail(ctx, &format!("{key} contains an invalid or out-of-range number.")))
+}
+
+/// # Is Identifier?
+///
+/// Returns `true` if the string is a reasonable (ASCII) variant name, or if
+/// `upper` is false, function name.
+fn is_ident(src: &str, upper: bool) -> bool {
+	let mut chars = src.chars();
+	chars.next().is_some_and(|c|
+		if upper { c.is_ascii_uppercase() }
+		else { c.is_ascii_lowercase() }
+	) &&
+	chars.all(|c| c.is_ascii_alphanumeric() || (! upper && c == '_'))
+}
+
+/// # Pair Value.
+fn pair(ctx: &str, key: &str, v: &Value) -> (Value, Value) {
+	match v.as_array().map(Vec::as_slice) {
+		Some([a, b]) => (a.clone(), b.clone()),
+		_ => fail(ctx, &format!("{key} must be a two-element array.")),
+	}
+}
+
+/// # String Value.
+fn string(ctx: &str, key: &str, v: &Value) -> String {
+	v.as_str()
+		.map(str::to_owned)
+		.unwrap_or_else(|| fail(ctx, &format!("{key} must be a string.")))
+}
+
+/// # Transition Value.
+///
+/// Successors may be a single name or a list of `[name, weight]` pairs.
+fn transition(ctx: &str, key: &str, v: &Value) -> Vec<(String, u16)> {
+	if let Some(name) = v.as_str() { vec![(name.to_owned(), 1)] }
+	else { weighted_list(&format!("{ctx} {key}"), v) }
+}
+
+/// # Weighted List.
+///
+/// Parse a non-empty list of `[name, weight]` pairs, with non-zero weights
+/// totalling less than `u16::MAX`.
+fn weighted_list(ctx: &str, v: &Value) -> Vec<(String, u16)> {
+	let Some(v) = v.as_array().filter(|v| ! v.is_empty()) else {
+		fail(ctx, "must be a non-empty array.");
+	};
+
+	let mut total = 0_u32;
+	let mut out = Vec::with_capacity(v.len());
+	let mut seen = BTreeSet::new();
+	for pair_raw in v {
+		let (name, weight) = pair(ctx, "entry", pair_raw);
+		let name = string(ctx, "name", &name);
+		let weight: u16 = int(ctx, "weight", &weight);
+		if weight == 0 { fail(ctx, &format!("{name} must have a non-zero weight.")); }
+		if ! seen.insert(name.clone()) { fail(ctx, &format!("duplicate {name}.")); }
+		total += u32::from(weight);
+		out.push((name, weight));
+	}
+
+	if u32::from(u16::MAX) <= total { fail(ctx, &format!("total weight is too big: {total}.")); }
+	out
+}
+
+
+
 /// # Image Dimensions.
 fn img_size() -> (u32, u32) {
 	let dim = imagesize::size("skel/img/poe.png").expect("Failed to read poe.png dimensions.");
@@ -17,12 +17,15 @@ init(wasmArray.buffer).then(() => {
 	// Make the Poe instance public.
 	window.Poe = Poe;
 
-	// Disable focus?
-	if (currentScript.hasAttribute('data-no-focus')) { Poe.focus = false; }
-
-	// Disable audio?
-	if (currentScript.hasAttribute('data-no-audio')) { Poe.audio = false; }
-
-	// Autostart?
-	if (! currentScript.hasAttribute('data-no-start')) { Poe.active = true; }
+	// Apply the script tag's options, autostarting unless told otherwise.
+	try {
+		const unknown = Poe.init(currentScript.dataset);
+		if (unknown.length) {
+			console.warn('Poe: unknown options: ' + unknown.join(', ') + '.');
+		}
+	}
+	catch (e) {
+		console.warn('Poe: invalid configuration.', e);
+		if (! currentScript.hasAttribute('data-no-start')) { Poe.active = true; }
+	}
 });
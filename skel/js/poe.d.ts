@@ -0,0 +1,316 @@
+/**
+ * @file JS Mate Poe: Type Definitions.
+ *
+ * These are the supporting types for the `Poe` class, which wasm-bindgen
+ * declares from the Rust exports themselves. This file is embedded as a
+ * custom section by `src/lib.rs`, so the two always ship together.
+ */
+
+/** A sound name. */
+type PoeSound = "Baa" | "Sneeze" | "Yawn";
+
+/** An animation, by ID or (code) name. */
+type PoeAnimationRef = number | string;
+
+/** An entry from `Poe.animations`. */
+interface PoeAnimation {
+	/** The animation ID. */
+	id: number;
+
+	/** The (code) name, as used by `Poe.configure`. */
+	name: string;
+
+	/** A human-readable title. */
+	title: string;
+
+	/** True for primary animations, false for child ones. */
+	primary: boolean;
+
+	/** The ID of the accompanying child animation, if any. */
+	child: number | null;
+
+	/** True if the animation can be cued directly. */
+	playable: boolean;
+
+	/** True if the animation is allowed to leave the screen. */
+	mayExit: boolean;
+
+	/** The length of a single pass in milliseconds. */
+	duration: number;
+
+	/** The weighted successors when the sequence finishes. */
+	next: [string, number][];
+
+	/** The weighted successors when a screen edge is reached. */
+	nextEdge: [string, number][];
+
+	/** The scenes as scene text, with any screen-scaled repeats as formulas. */
+	scenes: string;
+}
+
+/** An entry from `Poe.state`. */
+interface PoeMateState {
+	/** True for the primary mate. */
+	primary: boolean;
+
+	/** The current animation ID, if any. */
+	animation: number | null;
+
+	/** The current animation name, if any. */
+	name: string | null;
+
+	/** The current sprite frame, if any. */
+	frame: number | null;
+
+	/** The horizontal position in pixels. */
+	x: number;
+
+	/** The vertical position in pixels. */
+	y: number;
+
+	/** True if flipped horizontally. */
+	flipped: boolean;
+
+	/** True if gravity applies. */
+	gravity: boolean;
+
+	/** True if screen edges are being ignored. */
+	ignoreEdges: boolean;
+
+	/** True if being dragged. */
+	dragging: boolean;
+
+	/** The current step index within the animation, if any. */
+	step: number | null;
+
+	/** Milliseconds until the next tick. */
+	nextTick: number;
+
+	/** The queued next animation ID, if any. */
+	next: number | null;
+
+	/** The queued next animation name, if any. */
+	nextName: string | null;
+
+	/**
+	 * True while `Poe.inject` scenes are playing, in which case `animation`
+	 * and `name` refer to the stand-in `Blink`. Always `false` outside
+	 * director builds.
+	 */
+	custom: boolean;
+}
+
+/** A custom scene for `Poe.inject` or `Poe.loadPack`. */
+interface PoeScene {
+	/** Milliseconds per frame (`6..=65535`). */
+	fpms: number;
+
+	/** Frame IDs, as reported by `Poe.state`. */
+	frames: number[];
+
+	/** X/Y movement per step. */
+	moveTo?: [number, number];
+
+	/** Repeat count and the frame index to repeat from. */
+	repeat?: [number, number];
+
+	/** Sound name and the frame index to play it at. */
+	sound?: [PoeSound, number];
+
+	/** Additional sound cues. */
+	sounds?: PoeSoundCue[];
+
+	/**
+	 * Movement easing, either named or as CSS-style cubic bezier control
+	 * points (`[x1, y1, x2, y2]`).
+	 */
+	ease?: PoeEase | [number, number, number, number];
+
+	/** Scene flags. */
+	flags?: ("flipXNext" | "gravity" | "ignoreEdges")[];
+}
+
+/** A scene sound cue. */
+interface PoeSoundCue {
+	/** The sound. */
+	sound: PoeSound;
+
+	/** The frame index to play it at. */
+	idx: number;
+
+	/** The percent chance it plays (`1..=100`). Default: 100. */
+	chance?: number;
+
+	/** Play it on every repetition of the frame, not just the first. */
+	everyLoop?: boolean;
+}
+
+/** A named easing curve. */
+type PoeEase =
+	| "in" | "out" | "inOut"
+	| "cubicIn" | "cubicOut" | "cubicInOut"
+	| "sineIn" | "sineOut" | "sineInOut"
+	| "bounce" | "elastic";
+
+/** An animation successor, or weighted list of them. */
+type PoeTransition = string | [string, number][];
+
+/** A starting position for `PoePackAnimation`. */
+interface PoePackStart {
+	/** Off the left or right edge, random, or an absolute coordinate. */
+	x: "left" | "right" | "random" | number;
+
+	/** Above the top edge, on the floor, or an absolute coordinate. */
+	y: "top" | "bottom" | number;
+}
+
+/** An animation for `Poe.loadPack`. */
+interface PoePackAnimation {
+	/** A unique (code) name. */
+	name: string;
+
+	/** A human-readable title; defaults to the name. */
+	title?: string;
+
+	/** The kind of animation; defaults to `"playable"`. */
+	kind?: "playable" | "support" | "child";
+
+	/**
+	 * The scenes, or scene text: one scene per line, e.g.
+	 * `100 F002 F003 move=-2,0 repeat=(w+40)/32@0 gravity`.
+	 */
+	scenes: PoeScene[] | string;
+
+	/** The successor(s) when the sequence finishes. */
+	next?: PoeTransition;
+
+	/** The successor(s) when a screen edge is reached. */
+	nextEdge?: PoeTransition;
+
+	/** The weight within the default pool; defaults to `0`. */
+	weight?: number;
+
+	/** The accompanying child animation. (Primary only.) */
+	child?: string;
+
+	/** The starting position. */
+	start?: PoePackStart;
+
+	/** The starting position relative to the primary mate. (Child only.) */
+	offset?: [number, number];
+
+	/** Flip the sprite horizontally. */
+	flipX?: boolean;
+
+	/** Allow the animation to leave the screen. */
+	mayExit?: boolean;
+
+	/** Apply transform smoothing. */
+	smooth?: boolean;
+
+	/** The minimum screen width required for automatic selection. (Primary only.) */
+	minWidth?: number;
+
+	/** The minimum screen height required for automatic selection. (Primary only.) */
+	minHeight?: number;
+}
+
+/** Options for `Poe.configure`. */
+interface PoeConfig {
+	/** Milliseconds to wait before (re-)entering the screen (`0..=10000`). */
+	entranceDelay?: number;
+
+	/** One-in-n chance a walk/run may leave the screen (`0..=1000`). */
+	exitOdds?: number;
+
+	/** One-in-n chance the default choice will be a walk (`0..=1000`). */
+	walkOdds?: number;
+
+	/** Default animation weights, by name (`0..=1000`). */
+	weights?: Record<string, number>;
+
+	/** Entrance animation weights, by name (`0..=1000`). */
+	entranceWeights?: Record<string, number>;
+
+	/** Successor weights, by animation, then successor name (`0..=1000`). */
+	nextWeights?: Record<string, Record<string, number>>;
+
+	/** Edge successor weights, by animation, then successor name (`0..=1000`). */
+	nextEdgeWeights?: Record<string, Record<string, number>>;
+
+	/** Animation names (or presets like `"family"`) to never choose. */
+	exclude?: string[];
+}
+
+/**
+ * Options for `Poe.init`.
+ *
+ * As these usually come straight from a script tag's `dataset`, every value
+ * may also be given as a string.
+ */
+interface PoeInit {
+	/** Enable audio. */
+	audio?: boolean | string;
+
+	/** Disable audio. */
+	noAudio?: boolean | string;
+
+	/** Tunables, as an object or JSON string. */
+	config?: PoeConfig | string;
+
+	/** Animations (or presets) to never choose, as an array or list. */
+	exclude?: string[] | string;
+
+	/** Enable click-and-drag. */
+	focus?: boolean | string;
+
+	/** Disable click-and-drag. */
+	noFocus?: boolean | string;
+
+	/** Enable smooth movement between steps. */
+	interpolate?: boolean | string;
+
+	/** Disable smooth movement between steps. */
+	noInterpolate?: boolean | string;
+
+	/** Screen margins to keep clear of, CSS-style. */
+	insets?: number | number[] | string | { top?: number, right?: number, bottom?: number, left?: number };
+
+	/** A fixed PRNG seed; zero for random. */
+	seed?: number | string;
+
+	/** A custom sprite sheet. */
+	skin?: string | Blob | null;
+
+	/** Playback speed multiplier (`0.01..=10`). */
+	speed?: number | string;
+
+	/** Start Poe right away. */
+	start?: boolean | string;
+
+	/** Don't start Poe right away. */
+	noStart?: boolean | string;
+
+	/** Master volume (`0..=1`). */
+	volume?: number | string;
+}
+
+/** Options for `Poe.queue`. */
+interface PoeQueueOptions {
+	/** Repeat the list indefinitely. */
+	loop?: boolean;
+
+	/** What to do afterward. */
+	then?: "resume" | "stop";
+}
+
+/** Options for `Poe.say`. */
+interface PoeSayOptions {
+	/** Display time in milliseconds (`500..=60000`). */
+	duration?: number;
+}
+
+interface Window {
+	/** JS Mate Poe. */
+	Poe: typeof Poe;
+}
@@ -0,0 +1,147 @@
+/*!
+# RS Mate Poe: Custom Scenes
+*/
+
+use crate::SceneList;
+use js_sys::JSON;
+use std::cell::RefCell;
+use super::{
+	pack::parse_scenes,
+	scene::text::SceneListSpec,
+};
+use wasm_bindgen::prelude::*;
+
+
+
+thread_local! {
+	/// # Pending Custom Scenes.
+	///
+	/// Scenes injected via `Poe.inject`, waiting for the primary mate to pick
+	/// them up on its next tick.
+	static PENDING: RefCell<Option<SceneList>> = const { RefCell::new(None) };
+}
+
+
+
+/// # Custom Scenes.
+///
+/// This parses and validates userland scene lists for `Poe.inject`, letting
+/// animators prototype new animations from the browser console.
+///
+/// The input is an array — or JSON string encoding an array — of scene
+/// objects with the following keys, mirroring the `Scene` builder methods:
+///
+/// | Key | Type | Description | Required |
+/// | --- | ---- | ----------- | -------- |
+/// | `fpms` | `number` | Milliseconds per frame (`6..=65535`). | Yes |
+/// | `frames` | `number[]` | Frame IDs, as reported by `Poe.state`. | Yes |
+/// | `moveTo` | `[number, number]` | X/Y movement per step. | |
+/// | `repeat` | `[number, number]` | Repeat count and the frame index to repeat from. | |
+/// | `sound` | `[string, number]` | Sound name and the frame index to play it at. | |
+/// | `sounds` | `object[]` | Additional sound cues: `{ sound, idx, chance?, everyLoop? }`, with an optional percent `chance` and/or `everyLoop` to replay on repetitions. | |
+/// | `ease` | `string\|number[]` | Movement easing: `in`, `out`, `inOut`, `cubicIn`, `cubicOut`, `cubicInOut`, `sineIn`, `sineOut`, `sineInOut`, `bounce`, `elastic`, or `[x1, y1, x2, y2]` cubic bezier control points. | |
+/// | `flags` | `string[]` | Any of `flipXNext`, `gravity`, `ignoreEdges`. | |
+///
+/// Validation follows the same rules enforced on the built-in scenes.
+///
+/// Note: scenes borrow their frames for the life of the program, so the
+/// frame and sound lists of each valid injection are interned, i.e. leaked,
+/// unless identical to ones seen before. Tweaking timings or movements costs
+/// nothing, but novel frame sequences cost a few bytes apiece, which is fine
+/// for a development tool, but is why this is limited to director builds.
+pub(crate) struct CustomScenes;
+
+impl CustomScenes {
+	/// # Set.
+	///
+	/// Parse and validate the scenes, queueing them up for the primary mate.
+	///
+	/// ## Errors
+	///
+	/// An error is returned if the scenes are invalid, in which case nothing
+	/// is queued.
+	pub(crate) fn set(src: &JsValue) -> Result<(), String> {
+		let src =
+			if let Some(s) = src.as_string() {
+				JSON::parse(&s).map_err(|_| "Scenes must be valid JSON.".to_owned())?
+			}
+			else { src.clone() };
+
+		Self::set_spec(&parse_scenes(&src)?)
+	}
+
+	/// # Set (Spec).
+	///
+	/// This is the meat of `CustomScenes::set`, broken out so the (parsed)
+	/// scenes can be specified.
+	fn set_spec(spec: &SceneListSpec) -> Result<(), String> {
+		spec.validate(0, 0)?;
+		let scenes = spec.intern().build(0, 0);
+
+		PENDING.with_borrow_mut(|p| p.replace(scenes));
+		Ok(())
+	}
+
+	/// # Take.
+	///
+	/// Return (and clear) the pending custom scenes, if any.
+	pub(crate) fn take() -> Option<SceneList> { PENDING.with_borrow_mut(Option::take) }
+}
+
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		Frame,
+		Scene,
+	};
+	use super::super::scene::{
+		SceneListKind,
+		text,
+	};
+	use wasm_bindgen_test::*;
+
+	#[wasm_bindgen_test]
+	fn t_set() {
+		let spec: SceneListSpec = "100 F002 F003 move=-2,0 repeat=3@0\n250 F001 sound=Baa@0"
+			.parse()
+			.expect("Invalid scenes.");
+		assert_eq!(CustomScenes::set_spec(&spec), Ok(()));
+
+		// The scenes should come back out, but only once.
+		let scenes = CustomScenes::take().expect("Missing scenes.");
+		assert_eq!(scenes.to_string(), spec.to_string());
+		assert!(CustomScenes::take().is_none(), "Scenes should only be taken once.");
+
+		// Repeating the same injection shouldn't intern anything new.
+		let interned = text::interned();
+		assert_eq!(CustomScenes::set_spec(&spec), Ok(()));
+		assert_eq!(text::interned(), interned, "Scenes were interned again.");
+		assert!(CustomScenes::take().is_some(), "Missing scenes.");
+	}
+
+	#[wasm_bindgen_test]
+	fn t_set_invalid() {
+		static ONE: [Frame; 1] = [Frame::F001];
+
+		// Invalid lists should be rejected with the same messages as the
+		// built-in validation, without queueing or interning anything.
+		for scenes in [
+			Vec::new(),
+			vec![Scene::new(5, &ONE)],
+			vec![Scene::new(100, &ONE), Scene::new(100, &[])],
+			vec![Scene::new(100, &ONE).with_repeat(2, 1)],
+		] {
+			let expected = SceneList::new(SceneListKind::Owned(scenes.clone())).validate();
+			assert!(expected.is_err(), "Scenes should be invalid: {scenes:?}");
+
+			let interned = text::interned();
+			assert_eq!(CustomScenes::set_spec(&SceneListSpec::from(scenes)), expected);
+			assert_eq!(text::interned(), interned, "Scenes leaked: {expected:?}");
+			assert!(CustomScenes::take().is_none(), "Invalid scenes were queued.");
+		}
+	}
+}
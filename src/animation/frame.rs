@@ -175,6 +175,20 @@ impl Frame {
 	/// # Tile Size (Signed).
 	pub(crate) const SIZE_I: i32 = 40;
 
+	#[expect(unsafe_code, reason = "Needed for transmute.")]
+	/// # From U8.
+	///
+	/// Return the frame corresponding to the given ID, or `None` if out of
+	/// range.
+	pub(crate) const fn from_u8(src: u8) -> Option<Self> {
+		if src <= Self::None as u8 {
+			// Safety: frames start at zero and end with None, so src is in
+			// range.
+			Some(unsafe { std::mem::transmute::<u8, Self>(src) })
+		}
+		else { None }
+	}
+
 	/// # CSS Class.
 	///
 	/// Return the stylized frame "class", if any. As there can be only one,
@@ -2,23 +2,44 @@
 # RS Mate Poe: Animations
 */
 
+#[cfg(feature = "director")] pub(super) mod custom;
 pub(super) mod frame;
+pub(super) mod pack;
 pub(super) mod scene;
 mod scenes;
 pub(super) mod sound;
 
 use crate::{
 	Direction,
+	dom,
 	SceneList,
 	Universe,
+	universe::rand_mod_with,
 };
-use scene::SceneListKind;
-use std::sync::atomic::{
-	AtomicU32,
-	Ordering::SeqCst,
+use wasm_bindgen::JsValue;
+use js_sys::Object;
+use scene::{
+	InlineScenes,
+	SceneListKind,
 };
+use std::{
+	fmt,
+	ops::Range,
+	sync::atomic::{
+		AtomicU16,
+		AtomicU32,
+		AtomicU64,
+		Ordering::SeqCst,
+	},
+};
+
 
 
+/// # Excluded Animations.
+///
+/// A bitset of animation IDs that should never be chosen automatically. This
+/// is empty by default, but may be populated by the user via `Poe.configure`.
+static EXCLUDED: [AtomicU64; 2] = [AtomicU64::new(0), AtomicU64::new(0)];
 
 /// # Special Default.
 ///
@@ -33,226 +54,150 @@ static LAST_ENTRANCE: AtomicU32 = AtomicU32::new(0);
 
 
 
-/// # Helper: Animations.
-macro_rules! animation {
-	(@count $odd:tt) => ( 1 );
-	(@count $odd:tt $($a:tt $b:tt)+) => ( (animation!(@count $($a)+) * 2) + 1 );
-	(@count $($a:tt $b:tt)+) =>         (  animation!(@count $($a)+) * 2      );
-
-	($( $k:ident $( = $v:literal )? $vstr:literal $( @ $dynamic:ident:: )? $scene:ident, )+) => (
-		#[repr(u8)]
-		#[derive(Debug, Clone, Copy, Eq, PartialEq)]
-		/// # Animations.
-		///
-		/// This enum holds all possible sprite animations, for both primary and child
-		/// mates, as well as secondary (linked) sequences.
-		///
-		/// Each variant is equivalent to a `u8`, starting with `1`.
-		pub(crate) enum Animation {
-			$( $k $( = $v)?, )+
-		}
+// The Animation type, its property and transition tables, and the default
+// and entrance choices are generated by build.rs from skel/animations.toml.
+include!(concat!(env!("OUT_DIR"), "/animations.rs"));
 
-		impl Animation {
-			#[cfg(any(test, feature = "director"))]
-			/// # Maximum Animation ID.
-			const MAX_ANIMATION_ID: u8 = animation!(@count $($k)+);
-
-			#[cfg(any(test, feature = "director"))]
-			/// # All Animations.
-			pub(crate) const ALL: [Self; Self::MAX_ANIMATION_ID as usize] = [ $( Self::$k, )+ ];
-
-			#[cfg(feature = "director")]
-			/// # From U8.
-			///
-			/// Return the `Animation` corresponding to the given ID, or `None` if out
-			/// of range.
-			pub(crate) const fn from_u8(src: u8) -> Option<Self> {
-				// Discriminants start at one instead of zero, so we need to
-				// knock off one to align it to the indices in ALL.
-				if let Some(src) = src.checked_sub(1) && src < Self::MAX_ANIMATION_ID {
-					Some(Self::ALL[src as usize])
-				}
-				else { None }
-			}
+impl fmt::Debug for Animation {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(self.name())
+	}
+}
 
-			#[cfg(any(test, feature = "director"))]
-			#[must_use]
-			/// # As Str.
-			///
-			/// Return a human-readable "title" for the `Animation` as a static string
-			/// slice.
-			pub(crate) const fn as_str(self) -> &'static str {
-				match self {
-					$( Self::$k => $vstr, )+
-				}
-			}
+impl Animation {
+	/// # Maximum Selection Attempts.
+	///
+	/// The default and entrance choosers re-roll selections that aren't
+	/// "fresh", but if the (configurable) pool is too small for that to ever
+	/// happen, they need to give up eventually.
+	const MAX_TRIES: u8 = 32;
+}
 
-			/// # Scenes.
-			///
-			/// Return the animation's `SceneList`.
-			///
-			/// Most of these are completely static, identical from run-to-run, but a
-			/// few have randomized or environmental modifiers, tweaking them slightly.
-			pub(crate) const fn scenes(self, width: u16) -> SceneList {
-				macro_rules! scene {
-					($s:ident) => ( const { SceneList::new(SceneListKind::Fixed(scenes::$s)) } );
-					($s:path) =>  ( $s(width) );
-				}
+impl Animation {
+	/// # Default Choice.
+	///
+	/// Return a generic default animation for use in contexts where no
+	/// explicit choice is supplied. Excluded animations — and those that
+	/// don't fit the screen — are never chosen.
+	///
+	/// Out of the box, one third of the time this will just be a `Walk`; the
+	/// rest of the time, a weighted selection is made from the
+	/// `DEFAULT_CHOICES`.
+	///
+	/// Pack animations with a weight are tacked onto the end of the pool.
+	///
+	/// To keep things fresh, any given non-walk selection is guaranteed to be
+	/// different than the previous few non-walk selections (so long as the
+	/// configured pool is big enough to allow it).
+	pub(crate) fn default_choice() -> Self {
+		Self::default_choice_for(Universe::size(), Self::excluded_set())
+	}
 
-				match self {
-					$( Self::$k => scene!($($dynamic::)?$scene), )+
-				}
+	/// # Default Choice (for Size).
+	///
+	/// This is the meat of `Animation::default_choice`, broken out so the
+	/// screen size can be specified.
+	fn default_choice_for(size: (u16, u16), excluded: [u64; 2]) -> Self {
+		let walk = Universe::walk_odds();
+		if walk != 0 && 0 == Universe::rand_mod(walk) { return Self::Walk; }
+
+		let mut weights = DEFAULT_WEIGHTS.each_ref().map(|w| w.load(SeqCst));
+		zero_unavailable(&Self::DEFAULT_CHOICES, &mut weights, size, excluded);
+		let total: u16 = weights.iter().sum();
+		let packs = pack::default_pool(size, excluded);
+		let pool = packs.iter().fold(total, |acc, (_, w)| acc.saturating_add(*w));
+		if pool == 0 { return Self::Walk; }
+
+		let mut last = LAST_SPECIAL.load(SeqCst).to_le_bytes();
+		let mut tries = 0;
+		loop {
+			let n = Universe::rand_mod(pool);
+			let next =
+				if n < total { weighted_choice(&Self::DEFAULT_CHOICES, &weights, n) }
+				else { pack::weighted_choice(&packs, n - total).unwrap_or(Self::Walk) };
+			tries += 1;
+
+			// Accept and return the choice so long as it is fresh.
+			if Self::MAX_TRIES <= tries || match next {
+				// Let Run happen every third choice.
+				Self::Run => last[0] != Self::Run.id() && last[1] != Self::Run.id(),
+				// Only keep a low-priority selection if none of them were
+				// recently chosen.
+				Self::Abduction |
+					Self::Bleat |
+					Self::EatMagicFlower |
+					Self::Glitch |
+					Self::PlayDead |
+					Self::Scoot |
+					Self::Scream |
+					Self::ShadowShowdown |
+					Self::Sneeze |
+					Self::Tornado |
+					Self::Urinate |
+					Self::Yawn =>
+						is_fresh(Self::Abduction, last) &&
+						is_fresh(Self::Bleat, last) &&
+						is_fresh(Self::EatMagicFlower, last) &&
+						is_fresh(Self::Glitch, last) &&
+						is_fresh(Self::PlayDead, last) &&
+						is_fresh(Self::Scoot, last) &&
+						is_fresh(Self::Scream, last) &&
+						is_fresh(Self::ShadowShowdown, last) &&
+						is_fresh(Self::Sneeze, last) &&
+						is_fresh(Self::Tornado, last) &&
+						is_fresh(Self::Urinate, last) &&
+						is_fresh(Self::Yawn, last),
+				// For everything else, 1/5 for itself is fine.
+				_ => is_fresh(next, last),
+			} {
+				last.rotate_right(1);
+				last[0] = next.id();
+				LAST_SPECIAL.store(u32::from_le_bytes(last), SeqCst);
+				return next;
 			}
 		}
-	);
-}
-
-animation! {
-	// Animations for the primary mate.
-	Abduction = 1_u8             "Abduction"                          ABDUCTION,
-	BathDive                     "Bath Dive"                          BATH_DIVE,
-	BeamIn                       "Beam In"                            BEAM_IN,
-	Beg                          "Beg"                                BEG,
-	BigFish                      "Big Fish"                           BIG_FISH,
-	BlackSheepCatch              "Black Sheep Catch"                  BLACK_SHEEP_CATCH,
-	BlackSheepCatchFail          "Black Sheep (Almost) Catch"         BLACK_SHEEP_CATCH_FAIL,
-	BlackSheepChase              "Black Sheep Chase"                  @scenes::black_sheep_chase,
-	BlackSheepRomance            "Black Sheep Romance"                @scenes::black_sheep_romance,
-	Bleat                        "Bleat"                              BLEAT,
-	Blink                        "Blink"                              BLINK,
-	ChaseAMartian                "Chase a Martian"                    @scenes::chase_a_martian,
-	ClimbIn                      "Climb In"                           CLIMB_IN,
-	Cry                          "Cry"                                CRY,
-	Dance                        "Dance"                              DANCE,
-	Eat                          "Eat"                                EAT,
-	EatMagicFlower               "Eat (Magic Flower)"                 EAT_MAGIC_FLOWER,
-	FloatIn                      "Float In"                           FLOAT_IN,
-	Glitch                       "Glitch"                             GLITCH,
-	Gopher                       "Gopher"                             GOPHER,
-	Handstand                    "Handstand"                          HANDSTAND,
-	Hop                          "Hop"                                HOP,
-	Jump                         "Jump"                               JUMP,
-	JumpIn                       "Jump In"                            JUMP_IN,
-	LayDown                      "Lay Down"                           LAY_DOWN,
-	LegLifts                     "Leg Lifts"                          LEG_LIFTS,
-	LookDown                     "Look Down"                          LOOK_DOWN,
-	LookUp                       "Look Up"                            LOOK_UP,
-	Nah                          "Nah…"                               NAH,
-	PlayDead                     "Play Dead"                          PLAY_DEAD,
-	Popcorn                      "Popcorn"                            POPCORN,
-	Really                       "Really?!"                           REALLY,
-	Rest                         "Rest"                               REST,
-	Roll                         "Roll"                               ROLL,
-	Rotate                       "Rotate"                             ROTATE,
-	Run                          "Run"                                RUN,
-	Scoot                        "Scoot"                              SCOOT,
-	Scratch                      "Scratch"                            SCRATCH,
-	Scream                       "Scream"                             SCREAM,
-	ShadowShowdown               "Shadow Showdown"                    SHADOW_SHOWDOWN,
-	Shake                        "Shake"                              SHAKE,
-	SideStep                     "Side Step"                          SIDE_STEP,
-	Skip                         "Skip"                               SKIP,
-	Sleep                        "Sleep"                              SLEEP,
-	SleepSitting                 "Sleep (Sitting)"                    SLEEP_SITTING,
-	SleepStanding                "Sleep (Standing)"                   SLEEP_STANDING,
-	Slide                        "Slide"                              SLIDE,
-	SlideIn                      "Slide In"                           SLIDE_IN,
-	Sneeze                       "Sneeze"                             SNEEZE,
-	Spin                         "Spin"                               SPIN,
-	Stargaze                     "Stargaze"                           STARGAZE,
-	Tornado                      "Tornado"                            TORNADO,
-	Urinate                      "Urinate"                            URINATE,
-	Walk                         "Walk"                               WALK,
-	Yawn                         "Yawn"                               YAWN,
-	Yoyo                         "Yo-Yo"                              YOYO,
-
-	// Primary animations of a special/supporting nature.
-	BathCoolDown                 "Bath Cool Down"                     BATH_COOL_DOWN,
-	Boing                        "Boing!"                             BOING,
-	Bounce                       "Bounce"                             BOUNCE,
-	ClimbDown                    "Climb Down"                         CLIMB_DOWN,
-	ClimbUp                      "Climb Up"                           CLIMB_UP,
-	DangleFall                   "Dangle (Maybe) Fall"                DANGLE_FALL,
-	DangleRecover                "Dangle Fall Recovery"               DANGLE_RECOVER,
-	DigestMagicFlower1           "Digesting (Magic Flower)"           DIGEST_MAGIC_FLOWER1,
-	DigestMagicFlower2           "Digesting (Magic Flower)"           DIGEST_MAGIC_FLOWER2,
-	Drag                         "Drag"                               DRAG,
-	EatingMagicFlower            "Eating (Magic Flower)"              EATING_MAGIC_FLOWER,
-	EndRun                       "End Run"                            END_RUN,
-	Fall                         "Fall"                               FALL,
-	GraspingFall                 "Grasping Fall"                      GRASPING_FALL,
-	Hydroplane                   "Hydroplane"                         HYDROPLANE,
-	JumpInLanding                "Jump In (Landing)"                  JUMP_IN_LANDING,
-	ReachCeiling                 "Reach Ceiling"                      REACH_CEILING,
-	ReachFloor                   "Reach Floor"                        REACH_FLOOR,
-	ReachSide1                   "Reach Side (From Floor)"            REACH_SIDE1,
-	ReachSide2                   "Reach Side (From Ceiling)"          REACH_SIDE2,
-	RunDown                      "Run Down"                           RUN_DOWN,
-	RunUpsideDown                "Run Upside Down"                    RUN_UPSIDE_DOWN,
-	SlideDown                    "Slide Down"                         SLIDE_DOWN,
-	Splat                        "Splat"                              SPLAT,
-	TornadoExit                  "Tornado (Exit)"                     @scenes::tornado_exit,
-	WalkUpsideDown               "Walk Upside Down"                   WALK_UPSIDE_DOWN,
-	WallSlide                    "Wall Slide"                         WALL_SLIDE,
-
-	// Animations for the child mates.
-	AbductionChild               "Abduction (Child)"                  ABDUCTION_CHILD,
-	BathDiveChild                "Bathtub (Child)"                    BATH_DIVE_CHILD,
-	BigFishChild                 "Big Fish (Child)"                   BIG_FISH_CHILD,
-	BlackSheepCatchChild         "Black Sheep Catch (Child)"          BLACK_SHEEP_CATCH_CHILD,
-	BlackSheepCatchExitChild     "Black Sheep Catch (Child)"          BLACK_SHEEP_CATCH_EXIT_CHILD,
-	BlackSheepCatchFailChild     "Black Sheep (Almost) Catch (Child)" BLACK_SHEEP_CATCH_FAIL_CHILD,
-	BlackSheepCatchFailExitChild "Black Sheep (Almost) Catch (Child)" @scenes::black_sheep_catch_fail_exit_child,
-	BlackSheepChaseChild         "Black Sheep Chase (Child)"          @scenes::black_sheep_chase_child,
-	BlackSheepRomanceChild       "Black Sheep Romance (Child)"        @scenes::black_sheep_romance_child,
-	ChaseAMartianChild           "Chase a Martian (Child)"            @scenes::chase_a_martian_child,
-	Flower                       "Flower (Child)"                     FLOWER,
-	MagicFlower1                 "Magic Flower (Child)"               MAGIC_FLOWER1,
-	MagicFlower2                 "Magic Flower (Child)"               MAGIC_FLOWER2,
-	ShadowShowdownChild1         "Shadow Showdown (Child)"            SHADOW_SHOWDOWN_CHILD1,
-	ShadowShowdownChild2         "Shadow Showdown (Child)"            SHADOW_SHOWDOWN_CHILD2,
-	SneezeShadow                 "Sneeze Shadow (Child)"              SNEEZE_SHADOW,
-	SplatGhost                   "Splat (Ghost)"                      SPLAT_GHOST,
-	StargazeChild                "Stargaze (Child)"                   STARGAZE_CHILD,
-}
-
-// The Animation::default_choice method is generated by build.rs.
-include!(concat!(env!("OUT_DIR"), "/default-animations.rs"));
+	}
 
-impl Animation {
 	/// # Entrance Choice.
 	///
 	/// Return a default entrance animation for use when the primary mate is
-	/// off-screen or newly-activated.
+	/// off-screen or newly-activated. Excluded animations — and those that
+	/// don't fit the screen — are never chosen; if nothing else is left, this
+	/// will fall back to `Fall`.
 	pub(crate) fn entrance_choice(first: bool) -> Self {
+		Self::entrance_choice_for(first, Universe::size(), Self::excluded_set())
+	}
+
+	/// # Entrance Choice (for Size).
+	///
+	/// This is the meat of `Animation::entrance_choice`, broken out so the
+	/// screen size can be specified.
+	fn entrance_choice_for(first: bool, size: (u16, u16), excluded: [u64; 2]) -> Self {
+		let mut weights = ENTRANCE_WEIGHTS.each_ref().map(|w| w.load(SeqCst));
+		if ! first { weights[Self::ENTRANCE_CHOICES.len() - 1] = 0; }
+		zero_unavailable(&Self::ENTRANCE_CHOICES, &mut weights, size, excluded);
+		let total: u16 = weights.iter().sum();
+		if total == 0 { return Self::Fall; }
+
 		let mut last = LAST_ENTRANCE.load(SeqCst).to_le_bytes();
+		let mut tries = 0;
 		loop {
-			let next = match Universe::rand_mod(if first { 19 } else { 13 }) {
-				0 => Self::BathDive,
-				1 => Self::BigFish,
-				2 => Self::BlackSheepCatch,
-				3 => Self::BlackSheepCatchFail,
-				4 => Self::BlackSheepChase,
-				5 => Self::BlackSheepRomance,
-				6 => Self::ClimbIn,
-				7 => Self::FloatIn,
-				8 => Self::Gopher,
-				9 => Self::JumpIn,
-				10 => Self::SlideIn,
-				11 => Self::Stargaze,
-				12 => Self::Yoyo,
-				_ => Self::Fall,
-			};
+			let next = weighted_choice(
+				&Self::ENTRANCE_CHOICES,
+				&weights,
+				Universe::rand_mod(total),
+			);
+			tries += 1;
 
 			// Accept and return the choice so long as it is fresh, and if
 			// we've selected Gopher or Yoyo — which re-exit — make sure
 			// neither have been seen recently.
-			if match next {
+			if Self::MAX_TRIES <= tries || match next {
 				Self::Gopher | Self::Yoyo => is_fresh(Self::Gopher, last) && is_fresh(Self::Yoyo, last),
 				_ => is_fresh(next, last),
 			} {
 				last.rotate_right(1);
-				last[0] = next as u8;
+				last[0] = next.id();
 				LAST_ENTRANCE.store(u32::from_le_bytes(last), SeqCst);
 				return next;
 			}
@@ -260,306 +205,162 @@ impl Animation {
 	}
 }
 
-#[cfg(any(test, feature = "director"))]
 impl Animation {
-	/// # Directly Playable?
-	///
-	/// Returns `true` if the animation can be cued up via the userland setter
-	/// `Poe.play`.
-	pub(crate) const fn playable(self) -> bool {
-		matches!(
-			self,
-			Self::Abduction |
-			Self::BathDive |
-			Self::BeamIn |
-			Self::Beg |
-			Self::BigFish |
-			Self::BlackSheepCatch |
-			Self::BlackSheepCatchFail |
-			Self::BlackSheepChase |
-			Self::BlackSheepRomance |
-			Self::Bleat |
-			Self::Blink |
-			Self::ChaseAMartian |
-			Self::ClimbIn |
-			Self::Cry |
-			Self::Dance |
-			Self::Eat |
-			Self::EatMagicFlower |
-			Self::FloatIn |
-			Self::Glitch |
-			Self::Gopher |
-			Self::Handstand |
-			Self::Hop |
-			Self::Jump |
-			Self::JumpIn |
-			Self::LayDown |
-			Self::LegLifts |
-			Self::LookDown |
-			Self::LookUp |
-			Self::Nah |
-			Self::PlayDead |
-			Self::Popcorn |
-			Self::Really |
-			Self::Rest |
-			Self::Roll |
-			Self::Rotate |
-			Self::Run |
-			Self::Scoot |
-			Self::Scratch |
-			Self::Scream |
-			Self::ShadowShowdown |
-			Self::Shake |
-			Self::SideStep |
-			Self::Skip |
-			Self::Sleep |
-			Self::SleepSitting |
-			Self::SleepStanding |
-			Self::Slide |
-			Self::SlideIn |
-			Self::Sneeze |
-			Self::Spin |
-			Self::Stargaze |
-			Self::Tornado |
-			Self::Urinate |
-			Self::Walk |
-			Self::Yawn |
-			Self::Yoyo
-		)
+	/// # Set Default Weights.
+	///
+	/// Update the runtime weightings used by `Animation::default_choice`.
+	pub(crate) fn set_default_weights(weights: &[u16; Self::DEFAULT_CHOICES.len()]) {
+		for (a, b) in DEFAULT_WEIGHTS.iter().zip(weights) { a.store(*b, SeqCst); }
+	}
+
+	/// # Set Entrance Weights.
+	///
+	/// Update the runtime weightings used by `Animation::entrance_choice`.
+	pub(crate) fn set_entrance_weights(weights: &[u16; Self::ENTRANCE_CHOICES.len()]) {
+		for (a, b) in ENTRANCE_WEIGHTS.iter().zip(weights) { a.store(*b, SeqCst); }
+	}
+
+	/// # Set Transition Weights.
+	///
+	/// Update the runtime weightings used by `Animation::next` and
+	/// `Animation::next_edge`.
+	pub(crate) fn set_transition_weights(weights: &[u16; Self::TRANSITION_CHOICES.len()]) {
+		for (a, b) in TRANSITION_WEIGHTS.iter().zip(weights) { a.store(*b, SeqCst); }
+	}
+
+	/// # Set Excluded.
+	///
+	/// Replace the set of excluded animations. See `Animation::exclusion_bit`
+	/// for the layout.
+	pub(crate) fn set_excluded(set: [u64; 2]) {
+		for (a, b) in EXCLUDED.iter().zip(set) { a.store(b, SeqCst); }
 	}
 }
 
 impl Animation {
-	/// # Clamp to Wall.
-	///
-	/// Returns the side — left or right — to clamp the animation's X position
-	/// to, if any. (If flipped, the opposite should be applied.)
-	pub(crate) const fn clamp_x(self) -> Option<Direction> {
-		match self {
-			Self::ClimbDown | Self::RunDown | Self::SlideDown => Some(Direction::Right),
-			Self::ClimbIn | Self::ClimbUp | Self::WallSlide => Some(Direction::Left),
-			_ => None,
-		}
+	/// # Family-Friendly Exclusions.
+	///
+	/// The animations excluded by the `"family"` preset.
+	pub(crate) const FAMILY: [Self; 4] = [
+		Self::Abduction,
+		Self::Glitch,
+		Self::ShadowShowdown,
+		Self::Urinate,
+	];
+
+	/// # Excludable?
+	///
+	/// Returns `true` if the animation may be excluded by the user. This
+	/// covers all of the directly-playable animations except `Rotate` and
+	/// `Walk`, which are needed as fallbacks.
+	pub(crate) fn excludable(self) -> bool {
+		self.playable() && ! matches!(self, Self::Rotate | Self::Walk)
 	}
 
-	/// # CSS Class.
-	///
-	/// Return the stylized animation "number", if any. As there can be only
-	/// one, the value slots into a custom `data-a` attribute on the wrapper
-	/// element.
-	pub(crate) const fn css_class(self) -> &'static str {
-		match self {
-			Self::Drag => "1",
-			Self::BlackSheepCatchChild | Self::SneezeShadow => "2",
-			Self::Abduction => "3",
-			Self::BigFishChild => "4",
-			Self::SplatGhost => "5",
-			Self::EatingMagicFlower => "6",
-			Self::MagicFlower1 | Self::MagicFlower2 => "7",
-			Self::DigestMagicFlower1 => "8",
-			Self::ShadowShowdownChild1 => "9",
-			Self::ShadowShowdownChild2 => "a",
-			Self::DangleRecover => "b",
-			Self::Yoyo => "c",
-			Self::BeamIn => "d",
-			Self::Glitch => "e",
-			Self::BlackSheepCatchExitChild => "f",
-			Self::BathDiveChild => "g",
-			_ => "",
-		}
+	/// # Excluded?
+	///
+	/// Returns `true` if the animation has been excluded by the user.
+	///
+	/// Child animations can't be excluded directly; they only ever play
+	/// alongside their primary, so are excluded along with it. (Since they
+	/// can't be excluded, their own successors are always available too, but
+	/// `Animation::next` ends the sequence rather than falling back to a
+	/// primary if not.)
+	pub(crate) fn excluded(self) -> bool { self.excluded_in(Self::excluded_set()) }
+
+	/// # Excluded (In Set)?
+	///
+	/// This is the meat of `Animation::excluded`, broken out so the set can
+	/// be specified.
+	const fn excluded_in(self, set: [u64; 2]) -> bool {
+		let (idx, bit) = self.exclusion_bit();
+		0 != set[idx] & bit
 	}
 
-	/// # Flip (X).
+	/// # Excluded Set.
 	///
-	/// Returns `true` if the animation needs to flip the sprite image
-	/// horizontally.
-	pub(crate) const fn flip_x(self) -> bool {
-		matches!(
-			self,
-			Self::BlackSheepCatch |
-			Self::BlackSheepCatchFail |
-			Self::BlackSheepChaseChild |
-			Self::ReachSide2
-		)
+	/// Return the current set of excluded animations. See
+	/// `Animation::exclusion_bit` for the layout.
+	fn excluded_set() -> [u64; 2] { EXCLUDED.each_ref().map(|e| e.load(SeqCst)) }
+
+	/// # Exclusion Bit.
+	///
+	/// Return the index and bit corresponding to this animation in the
+	/// exclusion set: IDs `0..64` live in the first `u64`, the rest in the
+	/// second.
+	pub(crate) const fn exclusion_bit(self) -> (usize, u64) {
+		let id = self.id();
+		if id < 64 { (0, 1 << id) }
+		else { (1, 1 << (id - 64)) }
 	}
 
-	/// # Animation May Exit Screen?
+	/// # Fits?
 	///
-	/// Returns `true` if the relative-moving animation is allowed to ever
-	/// leave the viewport. (Actual passage is randomly assigned at runtime.)
-	pub(crate) const fn may_exit(self) -> bool {
-		matches!(
-			self,
-			Self::BlackSheepChase |
-			Self::ChaseAMartian |
-			Self::Run |
-			Self::SneezeShadow |
-			Self::Tornado |
-			Self::Walk
-		)
+	/// Returns `true` if the animation can play properly on a screen of the
+	/// given size. (See `Animation::min_size`.)
+	pub(crate) fn fits(self, (width, height): (u16, u16)) -> bool {
+		let (min_w, min_h) = self.min_size();
+		min_w <= width && min_h <= height
 	}
 
-	/// # Is Primary Animation?
-	///
-	/// Returns `true` if the animation only applies to the primary sprite.
-	///
-	/// Note, this is equivalent to `! self.for_child()`.
-	pub(crate) const fn primary(self) -> bool {
-		matches!(
-			self,
-			Self::Abduction |
-			Self::BathCoolDown |
-			Self::BathDive |
-			Self::BeamIn |
-			Self::Beg |
-			Self::BigFish |
-			Self::BlackSheepCatch |
-			Self::BlackSheepCatchFail |
-			Self::BlackSheepChase |
-			Self::BlackSheepRomance |
-			Self::Bleat |
-			Self::Blink |
-			Self::Boing |
-			Self::Bounce |
-			Self::ChaseAMartian |
-			Self::ClimbDown |
-			Self::ClimbIn |
-			Self::ClimbUp |
-			Self::Cry |
-			Self::Dance |
-			Self::DangleFall |
-			Self::DangleRecover |
-			Self::DigestMagicFlower1 |
-			Self::DigestMagicFlower2 |
-			Self::Drag |
-			Self::Eat |
-			Self::EatingMagicFlower |
-			Self::EatMagicFlower |
-			Self::EndRun |
-			Self::Fall |
-			Self::FloatIn |
-			Self::Glitch |
-			Self::Gopher |
-			Self::GraspingFall |
-			Self::Handstand |
-			Self::Hop |
-			Self::Hydroplane |
-			Self::Jump |
-			Self::JumpIn |
-			Self::JumpInLanding |
-			Self::LayDown |
-			Self::LegLifts |
-			Self::LookDown |
-			Self::LookUp |
-			Self::Nah |
-			Self::PlayDead |
-			Self::Popcorn |
-			Self::ReachCeiling |
-			Self::ReachFloor |
-			Self::ReachSide1 |
-			Self::ReachSide2 |
-			Self::Really |
-			Self::Rest |
-			Self::Roll |
-			Self::Rotate |
-			Self::Run |
-			Self::RunDown |
-			Self::RunUpsideDown |
-			Self::Scoot |
-			Self::Scratch |
-			Self::Scream |
-			Self::ShadowShowdown |
-			Self::Shake |
-			Self::SideStep |
-			Self::Skip |
-			Self::Sleep |
-			Self::SleepSitting |
-			Self::SleepStanding |
-			Self::Slide |
-			Self::SlideDown |
-			Self::SlideIn |
-			Self::Sneeze |
-			Self::Spin |
-			Self::Splat |
-			Self::Stargaze |
-			Self::Tornado |
-			Self::TornadoExit |
-			Self::Urinate |
-			Self::Walk |
-			Self::WalkUpsideDown |
-			Self::WallSlide |
-			Self::Yawn |
-			Self::Yoyo
-		)
+	/// # Available?
+	///
+	/// Returns `true` if the animation may be chosen automatically, i.e. it
+	/// isn't in the `excluded` set and fits the screen.
+	fn available(self, size: (u16, u16), excluded: [u64; 2]) -> bool {
+		! self.excluded_in(excluded) && self.fits(size)
 	}
 
-	/// # Smooth?
-	///
-	/// Some of the faster animations can benefit from a little "transform"
-	/// smoothing.
-	pub(crate) const fn smooth(self) -> bool {
-		matches!(
-			self,
-			Self::AbductionChild |
-			Self::BathDive |
-			Self::BigFish |
-			Self::BigFishChild |
-			Self::BlackSheepCatchFail |
-			Self::BlackSheepCatchFailChild |
-			Self::BlackSheepCatchFailExitChild |
-			Self::BlackSheepChase |
-			Self::BlackSheepChaseChild |
-			Self::BlackSheepRomance |
-			Self::BlackSheepRomanceChild |
-			Self::ChaseAMartian |
-			Self::ChaseAMartianChild |
-			Self::ClimbDown |
-			Self::ClimbUp |
-			Self::EndRun |
-			Self::Fall |
-			Self::GraspingFall |
-			Self::Hop |
-			Self::Jump |
-			Self::JumpIn |
-			Self::Run |
-			Self::RunDown |
-			Self::RunUpsideDown |
-			Self::Skip |
-			Self::Slide |
-			Self::SlideDown |
-			Self::Stargaze |
-			Self::Walk |
-			Self::WalkUpsideDown |
-			Self::WallSlide
-		)
+	/// # Abduction Descent.
+	///
+	/// Return the distance the `AbductionChild` UFO descends before beaming
+	/// the mate up, scaled to the screen height.
+	pub(crate) const fn abduction_descent(height: u16) -> i32 {
+		scenes::abduction_passes(height) as i32 * 16
 	}
 }
 
 impl Animation {
-	/// # Child Animation.
-	///
-	/// Return the child animation required by this primary animation, if any.
-	pub(crate) const fn child(self) -> Option<Self> {
-		match self {
-			Self::Abduction => Some(Self::AbductionChild),
-			Self::BathDive => Some(Self::BathDiveChild),
-			Self::BigFish => Some(Self::BigFishChild),
-			Self::BlackSheepCatch => Some(Self::BlackSheepCatchChild),
-			Self::BlackSheepCatchFail => Some(Self::BlackSheepCatchFailChild),
-			Self::BlackSheepChase => Some(Self::BlackSheepChaseChild),
-			Self::BlackSheepRomance => Some(Self::BlackSheepRomanceChild),
-			Self::ChaseAMartian => Some(Self::ChaseAMartianChild),
-			Self::Eat => Some(Self::Flower),
-			Self::EatMagicFlower => Some(Self::MagicFlower1),
-			Self::ShadowShowdown => Some(Self::ShadowShowdownChild1),
-			Self::Sneeze => Some(Self::SneezeShadow),
-			Self::Splat => Some(Self::SplatGhost),
-			Self::Stargaze => Some(Self::StargazeChild),
-			_ => None,
-		}
+	/// # All Animations (Including Packs).
+	///
+	/// Return an iterator over the built-in animations, followed by any that
+	/// have been loaded from packs.
+	pub(crate) fn all() -> impl Iterator<Item=Self> {
+		Self::ALL.into_iter().chain(pack::all())
+	}
+
+	/// # Catalog Entry.
+	///
+	/// Return the public details for the animation as a plain Javascript
+	/// object, for `Poe.animations`. The duration is for a single pass
+	/// through the scenes, which for some animations depends on the screen
+	/// size.
+	pub(crate) fn catalog_entry(self, width: u16, height: u16) -> Object {
+		dom::object([
+			("id", JsValue::from(self.id())),
+			("name", JsValue::from_str(self.name())),
+			("title", JsValue::from_str(self.as_str())),
+			("primary", JsValue::from_bool(self.primary())),
+			("child", self.child().map_or(JsValue::NULL, |c| JsValue::from(c.id()))),
+			("playable", JsValue::from_bool(self.playable())),
+			("mayExit", JsValue::from_bool(self.may_exit())),
+			("duration", JsValue::from(self.scenes(width, height).duration())),
+			("next", transitions_js(&self.transitions(false))),
+			("nextEdge", transitions_js(&self.transitions(true))),
+			("scenes", JsValue::from_str(&self.scene_spec().to_string())),
+		])
 	}
 
-	#[expect(clippy::too_many_lines, reason = "There are a lot of animations.")]
+	#[cfg(any(test, feature = "director"))]
+	/// # Playlist.
+	///
+	/// A formatted list of the directly-playable animations, one per line,
+	/// with their IDs. This is generated by build.rs from the same source as
+	/// everything else, and printed to the console by director builds.
+	pub(crate) const PLAYLIST: &str = include_str!(concat!(env!("OUT_DIR"), "/playlist.txt"));
+}
+
+impl Animation {
 	/// # Next Animation.
 	///
 	/// Switch to this animation when the sequence finishes. Some of these
@@ -569,178 +370,179 @@ impl Animation {
 	/// Primary animations with no explicit entry will simply move to a random
 	/// default choice. Unlisted child animations, on the other hand, will
 	/// terminate instead.
+	///
+	/// The successors are drawn from weighted tables; see
+	/// `Animation::transitions`.
+	///
+	/// If the successor has been excluded or doesn't fit the screen, a
+	/// default choice is returned in its place for primaries; child
+	/// sequences simply end.
 	pub(crate) fn next(self) -> Option<Self> {
-		match self {
-			Self::Abduction => Some(
-				if 0 == Universe::rand() & 1 { Self::ChaseAMartian }
-				else { Self::BeamIn }
-			),
-			Self::BathCoolDown |
-				Self::Beg |
-				Self::Bleat |
-				Self::Blink |
-				Self::Bounce |
-				Self::DigestMagicFlower2 |
-				Self::EndRun |
-				Self::FloatIn |
-				Self::Hydroplane |
-				Self::LayDown |
-				Self::LookDown |
-				Self::LookUp |
-				Self::Nah |
-				Self::PlayDead |
-				Self::Popcorn |
-				Self::ReachFloor |
-				Self::Really |
-				Self::Rest |
-				Self::Roll |
-				Self::Rotate |
-				Self::Shake |
-				Self::Skip |
-				Self::Sleep |
-				Self::SleepSitting |
-				Self::Slide |
-				Self::SlideIn |
-				Self::Splat |
-				Self::Urinate => Some(Self::Walk),
-			Self::BathDive => Some(Self::BathCoolDown),
-			Self::BeamIn |
-				Self::Glitch => Some(Self::Shake),
-			Self::BigFish => Some(
-				if 0 == Universe::rand_mod(3) { Self::Sneeze }
-				else { Self::Walk }
-			),
-			Self::BlackSheepCatch => Some(Self::Skip),
-			Self::BlackSheepCatchChild => Some(Self::BlackSheepCatchExitChild),
-			Self::BlackSheepCatchFail => Some(Self::LayDown),
-			Self::BlackSheepCatchFailChild => Some(Self::BlackSheepCatchFailExitChild),
-			Self::BlackSheepChase |
-				Self::JumpInLanding |
-				Self::LegLifts |
-				Self::Scream => Some(Self::Run),
-			Self::Boing => Some(match Universe::rand_mod(10) {
-				0..=7 => Self::Rotate,
-				8 => Self::Shake,
-				_ => Self::Run,
-			}),
-			Self::ChaseAMartian => Some(Self::Bleat),
-			Self::ClimbDown => Some(Self::ClimbDown),
-			Self::ClimbIn => Some(Self::Rotate),
-			Self::ClimbUp |
-				Self::ReachSide1 => Some(Self::ClimbUp),
-			Self::DangleFall => Some(
-				if 0 == Universe::rand_mod(4) { Self::GraspingFall }
-				else { Self::DangleRecover }
-			),
-			Self::DangleRecover => Some(
-				if 0 == Universe::rand_mod(5) { Self::RunUpsideDown }
-				else { Self::WalkUpsideDown }
-			),
-			Self::DigestMagicFlower1 => Some(Self::DigestMagicFlower2),
-			Self::Drag => Some(Self::Drag),
-			Self::Eat |
-				Self::SleepStanding => Some(
-					if 0 == Universe::rand_mod(3) { Self::Rest }
-					else { Self::Walk }
-				),
-			Self::EatMagicFlower => Some(Self::EatingMagicFlower),
-			Self::EatingMagicFlower => Some(Self::DigestMagicFlower1),
-			Self::Fall |
-				Self::GraspingFall => Some(Self::GraspingFall),
-			Self::Jump => Some(match Universe::rand_mod(5) {
-				0..=1 => Self::Run,
-				2..=3 => Self::Slide,
-				_ => Self::Jump,
-			}),
-			Self::JumpIn => Some(Self::JumpIn),
-			Self::MagicFlower1 => Some(Self::MagicFlower2),
-			Self::ReachCeiling => Some(Self::WalkUpsideDown),
-			Self::ReachSide2 => Some(match Universe::rand_mod(5) {
-				0 => Self::ClimbDown,
-				1 => Self::SlideDown,
-				_ => Self::RunDown,
-			}),
-			Self::Run => Some(match Universe::rand_mod(25) {
-				0..=7 => Self::EndRun,
-				8..=15 => Self::Jump,
-				16..=23 => Self::Run,
-				_ => Self::Hydroplane,
-			}),
-			Self::RunDown => Some(
-				if 0 == Universe::rand_mod(3) { Self::SlideDown }
-				else { Self::RunDown }
-			),
-			Self::RunUpsideDown => Some(Self::RunUpsideDown),
-			Self::Scoot => Some(match Universe::rand_mod(7) {
-				0..=3 => Self::Scoot,
-				4..=5 => Self::Rotate,
-				_ => Self::Walk,
-			}),
-			Self::ShadowShowdown => Some(Self::Scratch),
-			Self::ShadowShowdownChild1 => Some(Self::ShadowShowdownChild2),
-			Self::SlideDown => Some(Self::SlideDown),
-			Self::Spin => Some(Self::PlayDead),
-			Self::Stargaze => Some(
-				if 0 == Universe::rand() & 1 { Self::Nah }
-				else { Self::Scream }
-			),
-			Self::Tornado => Some(Self::TornadoExit),
-			Self::WalkUpsideDown => Some(match Universe::rand_mod(15) {
-				0 => Self::DangleFall,
-				_ => Self::WalkUpsideDown,
-			}),
-			Self::WallSlide => Some(Self::WallSlide),
-			Self::Yawn => Some(Self::Sleep),
-			_ => None,
-		}
+		self.next_for(Universe::size(), Self::excluded_set())
+	}
+
+	/// # Next (for Size).
+	///
+	/// This is the meat of `Animation::next`, broken out so the screen size
+	/// and exclusions can be specified.
+	fn next_for(self, size: (u16, u16), excluded: [u64; 2]) -> Option<Self> {
+		let next = self.next_unfiltered()?;
+		if next.available(size, excluded) { Some(next) }
+		else if self.primary() { Some(Self::default_choice_for(size, excluded)) }
+		else { None }
 	}
 
 	/// # Next at Edge.
 	///
 	/// This is just like `Animation::next`, but used in cases where a screen
 	/// edge has been reached.
+	///
+	/// If the successor has been excluded or doesn't fit the screen,
+	/// `Rotate` is returned in its place for primaries; child sequences
+	/// simply end.
 	pub(crate) fn next_edge(self) -> Option<Self> {
-		match self {
-			Self::BathDive => Some(Self::BathCoolDown),
-			Self::EndRun |
-				Self::Hydroplane |
-				Self::JumpInLanding |
-				Self::Run => Some(Self::Boing),
-			Self::ClimbDown |
-				Self::RunDown |
-				Self::SlideDown => Some(Self::ReachFloor),
-			Self::ClimbUp => Some(Self::ReachCeiling),
-			Self::DangleRecover |
-				Self::RunUpsideDown |
-				Self::WalkUpsideDown => Some(Self::ReachSide2),
-			Self::Fall => Some(Self::Bounce),
-			Self::GraspingFall => Some(match Universe::rand_mod(5) {
-				0 => Self::Bounce,
-				1 => Self::PlayDead,
-				_ => Self::Splat,
-			}),
-			Self::Hop |
-				Self::Jump |
-				Self::Skip => Some(Self::WallSlide),
-			Self::JumpIn => Some(Self::JumpInLanding),
-			Self::Tornado | Self::WallSlide => Some(Self::Rotate),
-			Self::Walk => Some(match Universe::rand_mod(8) {
-				0..=4 => Self::Rotate,
-				5..=6 => Self::Scoot,
-				_ => Self::ReachSide1,
-			}),
-			_ => None,
-		}
+		self.next_edge_for(Universe::size(), Self::excluded_set())
+	}
+
+	/// # Next at Edge (for Size).
+	///
+	/// This is the meat of `Animation::next_edge`, broken out so the screen
+	/// size and exclusions can be specified.
+	fn next_edge_for(self, size: (u16, u16), excluded: [u64; 2]) -> Option<Self> {
+		let next = self.next_edge_unfiltered()?;
+		if next.available(size, excluded) { Some(next) }
+		else if self.primary() { Some(Self::Rotate) }
+		else { None }
+	}
+
+	/// # Next Animation (Unfiltered).
+	///
+	/// The raw (random) transition behind `Animation::next`.
+	fn next_unfiltered(self) -> Option<Self> {
+		self.next_table().map_or_else(
+			|| pack::next(self),
+			|range| Some(transition_choice(range)),
+		)
+	}
+
+	/// # Next at Edge (Unfiltered).
+	///
+	/// The raw (random) transition behind `Animation::next_edge`.
+	fn next_edge_unfiltered(self) -> Option<Self> {
+		self.next_edge_table().map_or_else(
+			|| pack::next_edge(self),
+			|range| Some(transition_choice(range)),
+		)
+	}
+
+	/// # Transitions.
+	///
+	/// Return the successors — and their current weights — behind
+	/// `Animation::next` or, if `edge`, `Animation::next_edge`. The list is
+	/// empty if the animation has no explicit transition.
+	pub(crate) fn transitions(self, edge: bool) -> Vec<(Self, u16)> {
+		let range = if edge { self.next_edge_table() } else { self.next_table() };
+		range.map_or_else(
+			|| pack::transitions(self, edge),
+			|range| Self::TRANSITION_CHOICES[range.clone()].iter()
+				.copied()
+				.zip(TRANSITION_WEIGHTS[range].iter().map(|w| w.load(SeqCst)))
+				.collect(),
+		)
 	}
 }
 
 
 
+/// # Weighted Choice.
+///
+/// Return the entry from `set` corresponding to `n`, a (random) number less
+/// than the sum of `weights`.
+///
+/// The weights are evaluated cumulatively, in order, so for example given
+/// weights of `[2, 1]`, `0..=1` would return the first entry, `2` the second.
+fn weighted_choice<const N: usize>(set: &[Animation; N], weights: &[u16; N], mut n: u16)
+-> Animation {
+	for (a, w) in set.iter().zip(weights) {
+		if n < *w { return *a; }
+		n -= w;
+	}
+
+	// This shouldn't be reachable so long as n is in range.
+	set[N - 1]
+}
+
+/// # Transition Choice.
+///
+/// Pick one of the `TRANSITION_CHOICES` within `range` at random, in
+/// proportion to its runtime weight.
+fn transition_choice(range: Range<usize>) -> Animation {
+	pick_transition(
+		&Animation::TRANSITION_CHOICES[range.clone()],
+		TRANSITION_WEIGHTS[range].iter().map(|w| w.load(SeqCst)),
+		Universe::rand,
+	)
+}
+
+/// # Pick Transition.
+///
+/// This is the meat of `transition_choice`, broken out so the weights and
+/// source of randomness can be specified.
+///
+/// Lone successors are returned without consuming any randomness, and even
+/// two-way splits are a simple coin flip; anything else is a weighted
+/// `rand_mod` range.
+fn pick_transition<W, R>(set: &[Animation], weights: W, mut rand: R) -> Animation
+where W: Iterator<Item=u16> + Clone, R: FnMut() -> u64 {
+	match set {
+		[a] => *a,
+		[a, b] if { let mut w = weights.clone(); w.next() == w.next() } =>
+			if 0 == rand() & 1 { *a }
+			else { *b },
+		_ => {
+			let total = weights.clone().fold(0_u16, u16::saturating_add);
+			let mut n = rand_mod_with(total, rand);
+			for (a, w) in set.iter().zip(weights) {
+				if n < w { return *a; }
+				n -= w;
+			}
+
+			// This shouldn't be reachable so long as the weights add up.
+			set[set.len() - 1]
+		},
+	}
+}
+
+/// # Transitions (JS).
+///
+/// Convert a transition list into an array of `[name, weight]` pairs.
+fn transitions_js(set: &[(Animation, u16)]) -> JsValue {
+	set.iter()
+		.map(|(a, w)| js_sys::Array::of2(&JsValue::from_str(a.name()), &JsValue::from(*w)))
+		.collect::<js_sys::Array>()
+		.into()
+}
+
+/// # Zero Unavailable Weights.
+///
+/// Zero out the weights for any members of `set` that are excluded or don't
+/// fit a screen of the given `size`.
+fn zero_unavailable<const N: usize>(
+	set: &[Animation; N],
+	weights: &mut [u16; N],
+	size: (u16, u16),
+	excluded: [u64; 2],
+) {
+	for (a, w) in set.iter().zip(weights) {
+		if ! a.available(size, excluded) { *w = 0; }
+	}
+}
+
 /// # Animation Choice is Fresh.
 ///
 /// Returns true if not present among four choices.
 const fn is_fresh(animation: Animation, set: [u8; 4]) -> bool {
-	let animation = animation as u8;
+	let animation = animation.id();
 	set[0] != animation &&
 	set[1] != animation &&
 	set[2] != animation &&
@@ -752,6 +554,7 @@ const fn is_fresh(animation: Animation, set: [u8; 4]) -> bool {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::Frame;
 	use std::collections::HashSet;
 	use wasm_bindgen_test::*;
 
@@ -760,7 +563,7 @@ mod tests {
 		const TOTAL: usize = 36;
 
 		let set = (0..5_000_u16)
-			.map(|_| Animation::default_choice() as u8)
+			.map(|_| Animation::default_choice_for((1920, 1080), [0, 0]).id())
 			.collect::<HashSet::<u8>>();
 
 		assert_eq!(
@@ -770,6 +573,293 @@ mod tests {
 		);
 	}
 
+	#[wasm_bindgen_test]
+	fn t_default_weights() {
+		// The weightings are evaluated cumulatively, in order, so the first
+		// and last values of each range should pick the same thing.
+		let mut from = 0;
+		for (a, w) in Animation::DEFAULT_CHOICES.iter().zip(Animation::DEFAULT_WEIGHTS) {
+			for n in [from, from + w - 1] {
+				assert_eq!(
+					weighted_choice(&Animation::DEFAULT_CHOICES, &Animation::DEFAULT_WEIGHTS, n),
+					*a,
+					"Weighted choice mismatch at {n}.",
+				);
+			}
+			from += w;
+		}
+
+		// Run should be worth half the rest.
+		assert_eq!(
+			Animation::DEFAULT_WEIGHTS[0],
+			Animation::DEFAULT_WEIGHTS[1..].iter().sum::<u16>() / 2,
+			"Run weight is wrong.",
+		);
+
+		// Entrances too, but only for the first go.
+		for (n, a) in (0..13).zip(Animation::ENTRANCE_CHOICES) {
+			assert_eq!(
+				weighted_choice(&Animation::ENTRANCE_CHOICES, &Animation::ENTRANCE_WEIGHTS, n),
+				a,
+				"Weighted entrance mismatch at {n}.",
+			);
+		}
+		for n in 13..19 {
+			assert_eq!(
+				weighted_choice(&Animation::ENTRANCE_CHOICES, &Animation::ENTRANCE_WEIGHTS, n),
+				Animation::Fall,
+				"Weighted entrance mismatch at {n}.",
+			);
+		}
+	}
+
+	#[wasm_bindgen_test]
+	fn t_transitions() {
+		/// # Hydroplane Count.
+		///
+		/// Count the Hydroplanes in 25K of Run's successors.
+		fn hydroplanes(set: &[Animation], weights: &[u16]) -> usize {
+			let mut rand = crate::universe::seeded_rand(0x1234_5678);
+			(0..25_000_u16)
+				.filter(|_| pick_transition(set, weights.iter().copied(), &mut rand) == Animation::Hydroplane)
+				.count()
+		}
+
+		assert_eq!(
+			Animation::Run.transitions(false),
+			[
+				(Animation::EndRun, 8),
+				(Animation::Jump, 8),
+				(Animation::Run, 8),
+				(Animation::Hydroplane, 1),
+			],
+		);
+		assert_eq!(Animation::Run.transitions(true), [(Animation::Boing, 1)]);
+
+		// Hydroplane should come up about one time in twenty-five.
+		let range = Animation::Run.next_table().expect("Run has no transitions.");
+		let set = &Animation::TRANSITION_CHOICES[range.clone()];
+		let mut weights = Animation::TRANSITION_WEIGHTS[range].to_vec();
+		let count = hydroplanes(set, &weights);
+		assert!(
+			(800..=1200).contains(&count),
+			"Run -> Hydroplane happened {count} times out of 25K; expected ~1000.",
+		);
+
+		// Unless it is weighted out.
+		if let Some(last) = weights.last_mut() { *last = 0; }
+		assert_eq!(hydroplanes(set, &weights), 0, "Run -> Hydroplane should be disabled.");
+
+		// Every table should have at least one successor, and every default
+		// weight should be non-zero.
+		for a in Animation::ALL {
+			for range in [a.next_table(), a.next_edge_table()].into_iter().flatten() {
+				assert!(! range.is_empty(), "Empty transition table: {}", a.as_str());
+			}
+		}
+		assert!(Animation::TRANSITION_WEIGHTS.iter().all(|w| *w != 0));
+	}
+
+	#[wasm_bindgen_test]
+	fn t_transitions_seeded() {
+		use crate::universe::seeded_rand;
+
+		// The first sixteen picks from each random table, as made by the
+		// original hand-written `next`/`next_edge` matches.
+		for (a, edge, seed, expected) in [
+			(Animation::Abduction, false, 1, "BeamIn ChaseAMartian ChaseAMartian BeamIn BeamIn ChaseAMartian ChaseAMartian BeamIn BeamIn ChaseAMartian BeamIn ChaseAMartian BeamIn BeamIn BeamIn BeamIn"),
+			(Animation::Abduction, false, 0xdead_beef, "BeamIn ChaseAMartian BeamIn BeamIn ChaseAMartian BeamIn BeamIn ChaseAMartian BeamIn ChaseAMartian ChaseAMartian BeamIn BeamIn ChaseAMartian ChaseAMartian BeamIn"),
+			(Animation::BigFish, false, 1, "Sneeze Sneeze Sneeze Walk Sneeze Walk Sneeze Walk Sneeze Walk Sneeze Walk Walk Walk Walk Walk"),
+			(Animation::BigFish, false, 0xdead_beef, "Walk Walk Walk Walk Walk Sneeze Walk Sneeze Walk Sneeze Sneeze Sneeze Walk Walk Walk Walk"),
+			(Animation::Eat, false, 1, "Rest Rest Rest Walk Rest Walk Rest Walk Rest Walk Rest Walk Walk Walk Walk Walk"),
+			(Animation::Eat, false, 0xdead_beef, "Walk Walk Walk Walk Walk Rest Walk Rest Walk Rest Rest Rest Walk Walk Walk Walk"),
+			(Animation::Jump, false, 1, "Run Run Run Slide Run Slide Run Jump Run Slide Run Jump Slide Run Slide Slide"),
+			(Animation::Jump, false, 0xdead_beef, "Slide Slide Jump Slide Run Run Slide Run Slide Run Run Run Slide Jump Jump Slide"),
+			(Animation::Run, false, 1, "EndRun EndRun EndRun Jump EndRun Run EndRun Run EndRun Run EndRun Run Run Jump Jump Jump"),
+			(Animation::Run, false, 0xdead_beef, "Jump Jump Hydroplane Jump Jump EndRun Run Jump Run EndRun EndRun EndRun Run Run Run Jump"),
+			(Animation::Scoot, false, 1, "Scoot Scoot Scoot Rotate Scoot Rotate Scoot Walk Scoot Rotate Scoot Walk Rotate Scoot Scoot Rotate"),
+			(Animation::Scoot, false, 0xdead_beef, "Scoot Scoot Walk Scoot Scoot Scoot Rotate Scoot Rotate Scoot Scoot Scoot Rotate Walk Rotate Scoot"),
+			(Animation::SleepStanding, false, 1, "Rest Rest Rest Walk Rest Walk Rest Walk Rest Walk Rest Walk Walk Walk Walk Walk"),
+			(Animation::SleepStanding, false, 0xdead_beef, "Walk Walk Walk Walk Walk Rest Walk Rest Walk Rest Rest Rest Walk Walk Walk Walk"),
+			(Animation::Stargaze, false, 1, "Scream Nah Nah Scream Scream Nah Nah Scream Scream Nah Scream Nah Scream Scream Scream Scream"),
+			(Animation::Stargaze, false, 0xdead_beef, "Scream Nah Scream Scream Nah Scream Scream Nah Scream Nah Nah Scream Scream Nah Nah Scream"),
+			(Animation::Walk, true, 1, "Rotate Rotate Rotate Scoot Rotate Scoot Rotate ReachSide1 Rotate Scoot Rotate ReachSide1 Scoot Rotate Rotate Rotate"),
+			(Animation::Walk, true, 0xdead_beef, "Rotate Rotate ReachSide1 Rotate Rotate Rotate Scoot Rotate Scoot Rotate Rotate Rotate Scoot ReachSide1 Scoot Rotate"),
+			(Animation::Boing, false, 1, "Rotate Rotate Rotate Rotate Rotate Rotate Rotate Run Rotate Rotate Rotate Run Rotate Rotate Rotate Rotate"),
+			(Animation::Boing, false, 0xdead_beef, "Rotate Rotate Run Rotate Rotate Rotate Rotate Rotate Rotate Rotate Rotate Rotate Rotate Shake Shake Rotate"),
+			(Animation::DangleFall, false, 1, "GraspingFall DangleRecover DangleRecover DangleRecover GraspingFall DangleRecover GraspingFall DangleRecover DangleRecover DangleRecover GraspingFall DangleRecover DangleRecover DangleRecover DangleRecover DangleRecover"),
+			(Animation::DangleFall, false, 0xdead_beef, "DangleRecover DangleRecover DangleRecover DangleRecover DangleRecover DangleRecover DangleRecover DangleRecover DangleRecover GraspingFall GraspingFall GraspingFall DangleRecover DangleRecover DangleRecover DangleRecover"),
+			(Animation::DangleRecover, false, 1, "RunUpsideDown WalkUpsideDown WalkUpsideDown WalkUpsideDown WalkUpsideDown WalkUpsideDown RunUpsideDown WalkUpsideDown WalkUpsideDown WalkUpsideDown RunUpsideDown WalkUpsideDown WalkUpsideDown WalkUpsideDown WalkUpsideDown WalkUpsideDown"),
+			(Animation::DangleRecover, false, 0xdead_beef, "WalkUpsideDown WalkUpsideDown WalkUpsideDown WalkUpsideDown WalkUpsideDown WalkUpsideDown WalkUpsideDown WalkUpsideDown WalkUpsideDown RunUpsideDown RunUpsideDown RunUpsideDown WalkUpsideDown WalkUpsideDown WalkUpsideDown WalkUpsideDown"),
+			(Animation::GraspingFall, true, 1, "Bounce PlayDead PlayDead Splat PlayDead Splat Bounce Splat PlayDead Splat Bounce Splat Splat PlayDead Splat Splat"),
+			(Animation::GraspingFall, true, 0xdead_beef, "Splat Splat Splat Splat PlayDead PlayDead Splat PlayDead Splat Bounce Bounce Bounce Splat Splat Splat Splat"),
+			(Animation::ReachSide2, false, 1, "ClimbDown SlideDown SlideDown RunDown SlideDown RunDown ClimbDown RunDown SlideDown RunDown ClimbDown RunDown RunDown SlideDown RunDown RunDown"),
+			(Animation::ReachSide2, false, 0xdead_beef, "RunDown RunDown RunDown RunDown SlideDown SlideDown RunDown SlideDown RunDown ClimbDown ClimbDown ClimbDown RunDown RunDown RunDown RunDown"),
+			(Animation::RunDown, false, 1, "SlideDown SlideDown SlideDown RunDown SlideDown RunDown SlideDown RunDown SlideDown RunDown SlideDown RunDown RunDown RunDown RunDown RunDown"),
+			(Animation::RunDown, false, 0xdead_beef, "RunDown RunDown RunDown RunDown RunDown SlideDown RunDown SlideDown RunDown SlideDown SlideDown SlideDown RunDown RunDown RunDown RunDown"),
+			(Animation::WalkUpsideDown, false, 1, "DangleFall WalkUpsideDown WalkUpsideDown WalkUpsideDown WalkUpsideDown WalkUpsideDown DangleFall WalkUpsideDown WalkUpsideDown WalkUpsideDown WalkUpsideDown WalkUpsideDown WalkUpsideDown WalkUpsideDown WalkUpsideDown WalkUpsideDown"),
+			(Animation::WalkUpsideDown, false, 0xdead_beef, "WalkUpsideDown WalkUpsideDown WalkUpsideDown WalkUpsideDown WalkUpsideDown WalkUpsideDown WalkUpsideDown WalkUpsideDown WalkUpsideDown WalkUpsideDown DangleFall WalkUpsideDown WalkUpsideDown WalkUpsideDown WalkUpsideDown WalkUpsideDown"),
+		] {
+			let range = if edge { a.next_edge_table() } else { a.next_table() };
+			let range = range.expect("Missing transition table.");
+			let set = &Animation::TRANSITION_CHOICES[range.clone()];
+			let weights = &Animation::TRANSITION_WEIGHTS[range];
+			let mut rand = seeded_rand(seed);
+			let found = (0..16)
+				.map(|_| pick_transition(set, weights.iter().copied(), &mut rand).name())
+				.collect::<Vec<_>>()
+				.join(" ");
+			assert_eq!(found, expected, "Transition drift: {} (edge: {edge}, seed: {seed}).", a.as_str());
+		}
+	}
+
+	#[wasm_bindgen_test]
+	fn t_excludable() {
+		// The presets must all be excludable.
+		for a in Animation::FAMILY {
+			assert!(a.excludable(), "Family animation is not excludable: {}", a.as_str());
+		}
+
+		// The fallbacks must not be.
+		for a in [Animation::Fall, Animation::Rotate, Animation::Walk] {
+			assert!(! a.excludable(), "Fallback animation is excludable: {}", a.as_str());
+		}
+
+		// Every animation should have its own bit.
+		let mut set = [0_u64; 2];
+		for a in Animation::ALL {
+			let (idx, bit) = a.exclusion_bit();
+			assert_eq!(set[idx] & bit, 0, "Duplicate exclusion bit: {}", a.as_str());
+			set[idx] |= bit;
+		}
+	}
+
+	#[wasm_bindgen_test]
+	fn t_excluded_children() {
+		const SIZE: (u16, u16) = (1920, 1080);
+
+		for a in Animation::ALL {
+			if ! a.excludable() { continue; }
+			let Some(child) = a.child() else { continue; };
+			let (idx, bit) = a.exclusion_bit();
+			let mut set = [0_u64; 2];
+			set[idx] = bit;
+
+			// Children are only ever requested by the primary being played,
+			// so if the excluded one never comes up, neither will its child.
+			let check = |b: Animation, from: &str| {
+				assert!(
+					! b.excluded_in(set),
+					"{} (with child {}) chosen {from} with it excluded.",
+					b.as_str(), child.as_str(),
+				);
+			};
+
+			for _ in 0..250 {
+				check(Animation::default_choice_for(SIZE, set), "by default");
+				check(Animation::entrance_choice_for(true, SIZE, set), "as first entrance");
+				check(Animation::entrance_choice_for(false, SIZE, set), "as entrance");
+				for b in Animation::ALL {
+					if ! b.primary() { continue; }
+					for c in [b.next_for(SIZE, set), b.next_edge_for(SIZE, set)].into_iter().flatten() {
+						check(c, &format!("after {}", b.as_str()));
+					}
+				}
+			}
+		}
+
+		// Children can't be excluded, but if a child's successor is ever
+		// unavailable, the child sequence should end rather than switch to a
+		// primary.
+		for a in Animation::ALL {
+			if a.primary() { continue; }
+			for b in [a.next_unfiltered(), a.next_edge_unfiltered()].into_iter().flatten() {
+				let (idx, bit) = b.exclusion_bit();
+				let mut set = [0_u64; 2];
+				set[idx] = bit;
+				for _ in 0..50 {
+					for c in [a.next_for(SIZE, set), a.next_edge_for(SIZE, set)].into_iter().flatten() {
+						assert!(! c.excluded_in(set), "Excluded {} chosen after {}.", c.as_str(), a.as_str());
+						assert!(! c.primary(), "Primary {} chosen after child {}.", c.as_str(), a.as_str());
+					}
+				}
+			}
+		}
+	}
+
+	#[wasm_bindgen_test]
+	fn t_fits() {
+		// Everything fits on a big enough screen.
+		for a in Animation::ALL {
+			assert!(a.fits((1920, 1080)), "{} should fit at 1920x1080.", a.as_str());
+		}
+
+		// Unavailable animations shouldn't be chosen.
+		let mut weights = [1_u16; Animation::ENTRANCE_CHOICES.len()];
+		zero_unavailable(&Animation::ENTRANCE_CHOICES, &mut weights, (1920, 1080), [0, 0]);
+		assert!(weights.iter().all(|w| *w == 1), "Entrances should all be available at 1920x1080.");
+		zero_unavailable(&Animation::ENTRANCE_CHOICES, &mut weights, (1920, 400), [0, 0]);
+		for (a, w) in Animation::ENTRANCE_CHOICES.iter().zip(weights) {
+			assert_eq!(w == 0, ! a.fits((1920, 400)), "Wrong weight for {}.", a.as_str());
+		}
+		assert!(! Animation::BathDive.fits((1920, 400)), "BathDive should not fit at 1920x400.");
+		assert!(! Animation::BathDive.fits((400, 1080)), "BathDive should not fit at 400x1080.");
+
+		// In shorter windows, the UFO should start just off the top of the
+		// screen rather than way above it.
+		for h in 81..=560 {
+			let mate = i32::from(h) - Frame::SIZE_I;
+			let ufo = mate - Frame::SIZE_I * 2 - Animation::abduction_descent(h);
+			assert!(
+				(-Frame::SIZE_I - 32..=-Frame::SIZE_I).contains(&ufo),
+				"UFO starts at {ufo} for height {h}.",
+			);
+		}
+		for h in 529..=4320 {
+			assert_eq!(Animation::abduction_descent(h), 480, "Descent should be full at {h}.");
+		}
+	}
+
+	#[wasm_bindgen_test]
+	fn t_fits_tiny() {
+		// Nothing should be chosen automatically that can't fit the screen.
+		for w in (0..=800_u16).step_by(20) {
+			for h in (0..=800_u16).step_by(20) {
+				let size = (w, h);
+				for _ in 0..50 {
+					for a in [
+						Animation::default_choice_for(size, [0, 0]),
+						Animation::entrance_choice_for(true, size, [0, 0]),
+						Animation::entrance_choice_for(false, size, [0, 0]),
+					] {
+						assert!(a.fits(size), "{} chosen at {w}x{h}.", a.as_str());
+					}
+				}
+
+				for a in Animation::ALL {
+					for b in [a.next_for(size, [0, 0]), a.next_edge_for(size, [0, 0])].into_iter().flatten() {
+						assert!(
+							b.fits(size),
+							"{} chosen after {} at {w}x{h}.", b.as_str(), a.as_str(),
+						);
+					}
+				}
+			}
+		}
+	}
+
+	#[wasm_bindgen_test]
+	fn t_names() {
+		for a in Animation::ALL {
+			assert_eq!(
+				Animation::from_name(&format!("{a:?}")),
+				Some(a),
+				"Animation name mismatch: {}", a.as_str(),
+			);
+		}
+		assert!(Animation::from_name("Baa").is_none(), "Baa should not be an animation.");
+	}
+
 	#[wasm_bindgen_test]
 	fn t_playable() {
 		for a in Animation::ALL {
@@ -798,31 +888,52 @@ mod tests {
 		}
 	}
 
-	#[cfg(feature = "director")]
 	#[wasm_bindgen_test]
-	fn dbg_list() {
+	fn t_playlist() {
 		assert_eq!(
-			Animation::StargazeChild as u8,
+			Animation::StargazeChild.id(),
 			Animation::MAX_ANIMATION_ID,
 			"MAX_ANIMATION_ID is wrong!"
 		);
 
-		// Manually generate the playlist outputted by Poe::list() using the
-		// current animation details as reference.
-		let new = Animation::ALL.into_iter().filter_map(|a|
-			if a.playable() { Some(format!("#{:<4}{}", a as u8, a.as_str())) }
-			else { None }
-		)
+		// The generated playlist should agree with the runtime properties.
+		let expected = Animation::ALL.into_iter()
+			.filter_map(|a|
+				if a.playable() { Some(format!("#{:<4}{}", a.id(), a.as_str())) }
+				else { None }
+			)
 			.collect::<Vec<String>>()
 			.join("\n");
+		assert_eq!(Animation::PLAYLIST, expected);
+	}
 
-		// Make sure the fresh version matches our pre-computed original,
-		// otherwise we'll need to update it.
-		let old = include_str!("../../skel/playlist.txt");
-		assert_eq!(
-			old.trim(),
-			new.trim(),
-			"Playlist has changed:\n\n{new}\n",
-		);
+	#[wasm_bindgen_test]
+	fn t_duration() {
+		// The duration should match the sum of the step times.
+		for a in Animation::ALL {
+			for (w, h) in [(0, 0), (800, 600), (1920, 1080), (3840, 2160)] {
+				let scenes = a.scenes(w, h);
+				let duration = scenes.duration();
+				let expected = scenes.fold(0_u32, |acc, s| acc + u32::from(s.next_tick()));
+				assert_eq!(duration, expected, "Duration mismatch {} ({w}x{h}).", a.as_str());
+			}
+		}
+	}
+
+	#[wasm_bindgen_test]
+	fn t_catalog() {
+		// The catalog details should be internally consistent.
+		for a in Animation::ALL {
+			assert_eq!(Animation::from_u8(a.id()), Some(a), "ID mismatch {}.", a.as_str());
+			assert_eq!(Animation::from_name(a.name()), Some(a), "Name mismatch {}.", a.as_str());
+			assert!(! a.as_str().is_empty(), "Missing title {}.", a.name());
+			if a.playable() { assert!(a.primary(), "Playable child {}.", a.as_str()); }
+			if let Some(c) = a.child() {
+				assert!(a.primary() && ! c.primary(), "Invalid child {}.", a.as_str());
+			}
+			for (w, h) in [(0, 0), (1920, 1080)] {
+				assert_ne!(a.scenes(w, h).duration(), 0, "Zero duration {} ({w}x{h}).", a.as_str());
+			}
+		}
 	}
 }
@@ -0,0 +1,1033 @@
+/*!
+# RS Mate Poe: Animation Packs
+*/
+
+use crate::{
+	dom,
+	Frame,
+	Position,
+	Scene,
+	SceneList,
+	Sound,
+	Universe,
+};
+use js_sys::{
+	Array,
+	JSON,
+};
+use std::{
+	cell::RefCell,
+	rc::Rc,
+};
+use super::{
+	Animation,
+	scene::{
+		Ease,
+		SceneListKind,
+		SoundCue,
+		text::{
+			SceneListSpec,
+			SceneSpec,
+			SceneTemplate,
+		},
+	},
+};
+use wasm_bindgen::{
+	JsCast,
+	prelude::*,
+};
+
+
+
+thread_local! {
+	/// # Registered Pack Animations.
+	///
+	/// Animations loaded via `Poe.loadPack`, in ID order, starting just after
+	/// the built-in ones. Entries are permanent once registered.
+	static PACKS: RefCell<Vec<Rc<PackAnimation>>> = const { RefCell::new(Vec::new()) };
+}
+
+/// # Maximum Pack ID.
+///
+/// The highest ID a pack animation can have. This is bound by the size of the
+/// exclusion bitset.
+const MAX_PACK_ID: u8 = 127;
+
+/// # Validation Sizes.
+///
+/// Scenes with screen-dependent formulas are validated at each of these
+/// screen sizes.
+const SIZES: [(u16, u16); 7] = [
+	(320, 240),
+	(640, 480),
+	(1280, 720),
+	(1920, 1080),
+	(3840, 2160),
+	(7680, 4320),
+	(1920, 200),
+];
+
+
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+/// # Pack Animation Kind.
+///
+/// This mirrors the `kind` key in `skel/animations.toml`.
+pub(crate) enum PackKind {
+	/// # Primary, Directly Playable.
+	Playable,
+
+	/// # Primary, Supporting.
+	Support,
+
+	/// # Child.
+	Child,
+}
+
+impl PackKind {
+	/// # Primary?
+	const fn primary(self) -> bool { ! matches!(self, Self::Child) }
+}
+
+
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+/// # Starting Position.
+///
+/// Built-in animations have their starting positions hard-coded in `Mate`;
+/// pack animations declare theirs with one of these.
+pub(crate) enum PackStart {
+	/// # Absolute (Screen) Position.
+	Screen(StartX, StartY),
+
+	/// # Relative to the Primary Mate.
+	///
+	/// This is only valid for child animations. The X offset is mirrored when
+	/// the primary mate is flipped.
+	Offset(Position),
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+/// # Starting X Position.
+pub(crate) enum StartX {
+	/// # Just Off the Left Edge.
+	Left,
+
+	/// # Just Off the Right Edge.
+	Right,
+
+	/// # Random (On-Screen).
+	Random,
+
+	/// # Absolute.
+	At(i32),
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+/// # Starting Y Position.
+pub(crate) enum StartY {
+	/// # Just Above the Top Edge.
+	Top,
+
+	/// # On the Floor.
+	Bottom,
+
+	/// # Absolute.
+	At(i32),
+}
+
+
+
+#[derive(Debug, Clone)]
+/// # Pack Animation Definition.
+///
+/// An unvalidated pack entry, as parsed from userland. Animations referenced
+/// by name — `child`, `next`, `next_edge` — may be built-in, previously
+/// loaded, or part of the same pack.
+pub(crate) struct PackDef {
+	/// # Name.
+	name: String,
+
+	/// # Title.
+	title: Option<String>,
+
+	/// # Kind.
+	kind: PackKind,
+
+	/// # Scenes.
+	scenes: SceneListSpec,
+
+	/// # Next Animation(s).
+	next: Vec<(String, u16)>,
+
+	/// # Next at Edge Animation(s).
+	next_edge: Vec<(String, u16)>,
+
+	/// # Default Pool Weight.
+	weight: u16,
+
+	/// # Child Animation.
+	child: Option<String>,
+
+	/// # Starting Position.
+	start: Option<PackStart>,
+
+	/// # Flip (X).
+	flip_x: bool,
+
+	/// # May Exit Screen.
+	may_exit: bool,
+
+	/// # Smooth.
+	smooth: bool,
+
+	/// # Minimum Screen Size.
+	min_size: (u16, u16),
+}
+
+impl PackDef {
+	/// # New.
+	const fn new(name: String, kind: PackKind, scenes: SceneListSpec) -> Self {
+		Self {
+			name,
+			title: None,
+			kind,
+			scenes,
+			next: Vec::new(),
+			next_edge: Vec::new(),
+			weight: 0,
+			child: None,
+			start: None,
+			flip_x: false,
+			may_exit: false,
+			smooth: false,
+			min_size: (0, 0),
+		}
+	}
+
+	/// # Validate Scenes.
+	///
+	/// Check the scenes at each of the test `SIZES`, or just once if they
+	/// aren't screen-dependent.
+	fn validate_scenes(&self) -> Result<(), String> {
+		let ctx = &self.name;
+		if self.scenes.is_dynamic() {
+			for (w, h) in SIZES {
+				self.scenes.validate(w, h).map_err(|e| format!("{ctx} ({w}x{h}): {e}"))?;
+			}
+			Ok(())
+		}
+		else { self.scenes.validate(0, 0).map_err(|e| format!("{ctx}: {e}")) }
+	}
+}
+
+
+
+#[derive(Debug)]
+/// # Pack Animation.
+///
+/// A validated, registered pack entry. The `Animation` property methods defer
+/// to these for any IDs beyond the built-in range.
+pub(crate) struct PackAnimation {
+	/// # Name.
+	pub(super) name: &'static str,
+
+	/// # Title.
+	pub(super) title: &'static str,
+
+	/// # Kind.
+	kind: PackKind,
+
+	/// # Flip (X).
+	pub(super) flip_x: bool,
+
+	/// # May Exit Screen.
+	pub(super) may_exit: bool,
+
+	/// # Smooth.
+	pub(super) smooth: bool,
+
+	/// # Minimum Screen Size.
+	pub(super) min_size: (u16, u16),
+
+	/// # Child Animation.
+	pub(super) child: Option<Animation>,
+
+	/// # Scenes.
+	scenes: SceneTemplate,
+
+	/// # Next Animation(s).
+	next: Vec<(Animation, u16)>,
+
+	/// # Next at Edge Animation(s).
+	next_edge: Vec<(Animation, u16)>,
+
+	/// # Default Pool Weight.
+	weight: u16,
+
+	/// # Starting Position.
+	start: Option<PackStart>,
+}
+
+
+impl PackAnimation {
+	/// # Directly Playable?
+	pub(super) const fn playable(&self) -> bool { matches!(self.kind, PackKind::Playable) }
+
+	/// # Primary?
+	pub(super) const fn primary(&self) -> bool { self.kind.primary() }
+}
+
+
+
+/// # Animation Packs.
+///
+/// This parses, validates, and registers userland animation packs for
+/// `Poe.loadPack`, letting new behaviors ship without a rebuild.
+///
+/// The input is an array — or JSON string encoding an array — of animation
+/// objects with the following keys:
+///
+/// | Key | Type | Description | Required |
+/// | --- | ---- | ----------- | -------- |
+/// | `name` | `string` | A unique (code) name, e.g. `MoonWalk`. | Yes |
+/// | `title` | `string` | A human-readable title. Defaults to the name. | |
+/// | `kind` | `string` | `"playable"`, `"support"`, or `"child"`. Defaults to `"playable"`. | |
+/// | `scenes` | `object[]\|string` | The scenes, in the same format as `Poe.inject`, or as scene text. | Yes |
+/// | `next` | `string\|[string, number][]` | The successor(s) when the sequence finishes. | |
+/// | `nextEdge` | `string\|[string, number][]` | The successor(s) when a screen edge is reached. | |
+/// | `weight` | `number` | The weight within the default pool. Defaults to `0` (never chosen). | |
+/// | `child` | `string` | The accompanying child animation. (Primary only.) | |
+/// | `start` | `object` | The starting `x` (`"left"`, `"right"`, `"random"`, or a number) and `y` (`"top"`, `"bottom"`, or a number). | |
+/// | `offset` | `[number, number]` | The starting position relative to the primary mate. (Child only.) | |
+/// | `flipX` | `bool` | Flip the sprite horizontally. | |
+/// | `mayExit` | `bool` | Allow the animation to leave the screen. | |
+/// | `smooth` | `bool` | Apply transform smoothing. | |
+/// | `minWidth` | `number` | The minimum screen width required for automatic selection. (Primary only.) | |
+/// | `minHeight` | `number` | The minimum screen height required for automatic selection. (Primary only.) | |
+///
+/// Scene text — see the `scene::text` module — may use formulas of the screen
+/// size for repeat counts, in which case the scenes are validated across a
+/// range of sizes.
+///
+/// Successors follow the same rules as the built-in ones: primary animations
+/// can only lead to other primary animations, children to children, and
+/// multiple choices are picked randomly in proportion to their weight.
+///
+/// Packs are all-or-nothing: if anything is invalid, none of the animations
+/// are registered. Registered animations are permanent, and receive IDs
+/// following the built-in ones, in order, up to a maximum of `127`.
+pub(crate) struct Pack;
+
+impl Pack {
+	/// # Load.
+	///
+	/// Parse, validate, and register the pack.
+	///
+	/// ## Errors
+	///
+	/// An error is returned if the pack is invalid, in which case nothing is
+	/// registered.
+	pub(crate) fn load(src: &JsValue) -> Result<(), String> {
+		let src =
+			if let Some(s) = src.as_string() {
+				JSON::parse(&s).map_err(|_| "Packs must be valid JSON.".to_owned())?
+			}
+			else { src.clone() };
+
+		if ! Array::is_array(&src) { return Err("Packs must be an array.".to_owned()); }
+
+		let mut defs = Vec::new();
+		for (idx, v) in src.unchecked_ref::<Array>().iter().enumerate() {
+			defs.push(parse_def(&v).map_err(|e| format!("Animation #{idx}: {e}"))?);
+		}
+
+		register(defs)
+	}
+}
+
+
+
+impl Animation {
+	/// # Pack Starting Position.
+	///
+	/// Return the declared starting position for a pack animation, if any.
+	pub(crate) fn pack_start(self) -> Option<PackStart> { get(self)?.start }
+}
+
+
+
+/// # Get Pack Animation.
+///
+/// Return the registered pack entry for the animation, if any.
+pub(super) fn get(animation: Animation) -> Option<Rc<PackAnimation>> {
+	let idx = animation.id().checked_sub(Animation::MAX_ANIMATION_ID + 1)?;
+	PACKS.with_borrow(|p| p.get(usize::from(idx)).map(Rc::clone))
+}
+
+/// # All Pack Animations.
+pub(super) fn all() -> impl Iterator<Item=Animation> {
+	(Animation::MAX_ANIMATION_ID + 1..=MAX_PACK_ID)
+		.take(PACKS.with_borrow(Vec::len))
+		.map(Animation)
+}
+
+/// # From U8.
+///
+/// Return the pack animation corresponding to the given ID, if registered.
+pub(super) fn from_u8(src: u8) -> Option<Animation> {
+	let out = Animation(src);
+	get(out).map(|_| out)
+}
+
+/// # From Name.
+///
+/// Return the pack animation with the given (case-sensitive) name, if
+/// registered.
+pub(super) fn from_name(src: &str) -> Option<Animation> {
+	PACKS.with_borrow(|p| p.iter().position(|a| a.name == src))
+		.and_then(|idx| u8::try_from(idx).ok())
+		.map(|idx| Animation(Animation::MAX_ANIMATION_ID + 1 + idx))
+}
+
+/// # Scenes.
+///
+/// Return the pack animation's scenes for the given screen size. (This will
+/// be empty for non-pack animations.)
+pub(super) fn scenes(animation: Animation, width: u16, height: u16) -> SceneList {
+	get(animation).map_or_else(
+		|| SceneList::new(SceneListKind::Owned(Vec::new())),
+		|p| p.scenes.build(width, height),
+	)
+}
+
+/// # Scene Spec.
+///
+/// Return the pack animation's scenes as written, formulas and all, or
+/// `None` for non-pack animations.
+pub(super) fn scene_spec(animation: Animation) -> Option<SceneListSpec> {
+	get(animation).map(|p| SceneListSpec::from(&p.scenes))
+}
+
+/// # Next Animation.
+///
+/// The raw transition for a pack animation, if any.
+pub(super) fn next(animation: Animation) -> Option<Animation> {
+	get(animation).and_then(|p| weighted_random(&p.next))
+}
+
+/// # Next at Edge.
+///
+/// The raw edge transition for a pack animation, if any.
+pub(super) fn next_edge(animation: Animation) -> Option<Animation> {
+	get(animation).and_then(|p| weighted_random(&p.next_edge))
+}
+
+/// # Transitions.
+///
+/// The weighted successors for a pack animation, if any.
+pub(super) fn transitions(animation: Animation, edge: bool) -> Vec<(Animation, u16)> {
+	get(animation).map_or_else(Vec::new, |p|
+		if edge { p.next_edge.clone() }
+		else { p.next.clone() }
+	)
+}
+
+/// # Default Pool.
+///
+/// Return the (available) pack animations participating in the default
+/// pool, along with their weights.
+pub(super) fn default_pool(size: (u16, u16), excluded: [u64; 2]) -> Vec<(Animation, u16)> {
+	all()
+		.filter_map(|a| {
+			let weight = get(a)?.weight;
+			if weight == 0 || ! a.available(size, excluded) { None }
+			else { Some((a, weight)) }
+		})
+		.collect()
+}
+
+/// # Weighted Choice.
+///
+/// Return the entry from `set` corresponding to `n`, a (random) number less
+/// than the sum of the weights. See `super::weighted_choice`.
+pub(super) fn weighted_choice(set: &[(Animation, u16)], mut n: u16) -> Option<Animation> {
+	for (a, w) in set {
+		if n < *w { return Some(*a); }
+		n -= w;
+	}
+
+	set.last().map(|(a, _)| *a)
+}
+
+/// # Weighted Random Choice.
+///
+/// Pick an entry from `set` at random, in proportion to the weights. Lone
+/// entries are returned as-is, without consuming any randomness.
+fn weighted_random(set: &[(Animation, u16)]) -> Option<Animation> {
+	match set {
+		[] => None,
+		[(a, _)] => Some(*a),
+		_ => {
+			let total = set.iter().fold(0_u16, |acc, (_, w)| acc.saturating_add(*w));
+			weighted_choice(set, Universe::rand_mod(total))
+		},
+	}
+}
+
+
+
+/// # Register.
+///
+/// Validate and resolve the definitions, then add them to the registry, all
+/// or nothing.
+fn register(defs: Vec<PackDef>) -> Result<(), String> {
+	if defs.is_empty() {
+		return Err("Packs must include at least one animation.".to_owned());
+	}
+
+	// Make sure there's room.
+	let start = PACKS.with_borrow(Vec::len);
+	let available = usize::from(MAX_PACK_ID - Animation::MAX_ANIMATION_ID) - start;
+	if available < defs.len() {
+		return Err(format!("Too many animations; only {available} more can be loaded."));
+	}
+	let start = Animation::MAX_ANIMATION_ID + 1 + u8::try_from(start).map_err(|_| "Too many animations.")?;
+
+	// Look up a name among the new and existing animations, returning the
+	// animation and whether or not it is primary.
+	let resolve = |name: &str| -> Option<(Animation, bool)> {
+		if let Some(idx) = defs.iter().position(|d| d.name == name) {
+			let idx = u8::try_from(idx).ok()?;
+			Some((Animation(start + idx), defs[usize::from(idx)].kind.primary()))
+		}
+		else { Animation::from_name(name).map(|a| (a, a.primary())) }
+	};
+
+	// Resolve a transition list, enforcing kind consistency.
+	let transitions = |key: &str, list: &[(String, u16)], primary: bool| {
+		let mut total = 0_u16;
+		let mut out = Vec::with_capacity(list.len());
+		for (name, weight) in list {
+			let (a, a_primary) = resolve(name)
+				.ok_or_else(|| format!("{key} references unknown animation {name}."))?;
+			if a_primary != primary {
+				return Err(format!(
+					"{key} references {name}, but primary and child animations cannot mix.",
+				));
+			}
+			if *weight == 0 { return Err(format!("{key} weights must be at least one.")); }
+			total = total.checked_add(*weight)
+				.ok_or_else(|| format!("{key} weights must total less than 65536."))?;
+			out.push((a, *weight));
+		}
+		Ok::<_, String>(out)
+	};
+
+	let mut out = Vec::with_capacity(defs.len());
+	for (idx, d) in defs.iter().enumerate() {
+		let ctx = &d.name;
+		let primary = d.kind.primary();
+
+		// Names must be unique.
+		if ! is_name(&d.name) {
+			return Err(format!(
+				"Animation #{idx}: names must start with a letter and contain only ASCII letters and numbers.",
+			));
+		}
+		if Animation::from_name(&d.name).is_some() || defs[..idx].iter().any(|d2| d2.name == d.name) {
+			return Err(format!("{ctx}: an animation with this name already exists."));
+		}
+
+		let title = d.title.as_deref().unwrap_or(&d.name);
+		if title.trim().is_empty() { return Err(format!("{ctx}: titles cannot be empty.")); }
+
+		d.validate_scenes()?;
+
+		// Check the relationships.
+		let child = match &d.child {
+			Some(name) => {
+				if ! primary {
+					return Err(format!("{ctx}: only primary animations can have children."));
+				}
+				match resolve(name) {
+					Some((a, false)) => Some(a),
+					Some(_) => return Err(format!("{ctx}: child {name} is not a child animation.")),
+					None => return Err(format!("{ctx}: child references unknown animation {name}.")),
+				}
+			},
+			None => None,
+		};
+		let next = transitions("next", &d.next, primary).map_err(|e| format!("{ctx}: {e}"))?;
+		let next_edge = transitions("nextEdge", &d.next_edge, primary).map_err(|e| format!("{ctx}: {e}"))?;
+
+		if d.weight != 0 && ! primary {
+			return Err(format!("{ctx}: child animations cannot join the default pool."));
+		}
+		if primary && matches!(d.start, Some(PackStart::Offset(_))) {
+			return Err(format!("{ctx}: only child animations can have offsets."));
+		}
+		if d.min_size != (0, 0) && ! primary {
+			return Err(format!("{ctx}: only primary animations can have minimum sizes."));
+		}
+
+		out.push(PackAnimation {
+			name: "",
+			title: "",
+			kind: d.kind,
+			flip_x: d.flip_x,
+			may_exit: d.may_exit,
+			smooth: d.smooth,
+			min_size: d.min_size,
+			child,
+			scenes: SceneTemplate::default(),
+			next,
+			next_edge,
+			weight: d.weight,
+			start: d.start,
+		});
+	}
+
+	// Everything checks out! Leak the strings and intern the scenes — they
+	// need to be static — and register the lot.
+	for (a, d) in out.iter_mut().zip(defs) {
+		a.scenes = d.scenes.intern();
+		a.title = Box::leak(d.title.unwrap_or_else(|| d.name.clone()).into_boxed_str());
+		a.name = Box::leak(d.name.into_boxed_str());
+	}
+	PACKS.with_borrow_mut(|p| p.extend(out.into_iter().map(Rc::new)));
+	Ok(())
+}
+
+/// # Valid Name?
+///
+/// Names must start with an ASCII letter, and contain only ASCII letters and
+/// numbers.
+fn is_name(src: &str) -> bool {
+	src.as_bytes().first().is_some_and(u8::is_ascii_alphabetic) &&
+	src.bytes().all(|b| b.is_ascii_alphanumeric())
+}
+
+
+
+/// # Parse Definition.
+fn parse_def(src: &JsValue) -> Result<PackDef, String> {
+	let entries = dom::entries(src).ok_or("Animations must be objects.")?;
+
+	let mut name = None;
+	let mut scenes = None;
+	for (k, v) in &entries {
+		match k.as_str() {
+			"name" => { name.replace(v.as_string().ok_or("name must be a string.")?); },
+			"scenes" => {
+				let v =
+					if let Some(s) = v.as_string() { s.parse::<SceneListSpec>()? }
+					else { parse_scenes(v)? };
+				scenes.replace(v);
+			},
+			_ => {},
+		}
+	}
+
+	let name = name.ok_or("name is required.")?;
+	let scenes = scenes.ok_or("scenes are required.")?;
+	let mut out = PackDef::new(name, PackKind::Playable, scenes);
+	let mut offset = None;
+	for (k, v) in entries {
+		match k.as_str() {
+			"name" | "scenes" => {},
+			"title" => { out.title.replace(v.as_string().ok_or("title must be a string.")?); },
+			"kind" => {
+				out.kind = match v.as_string().as_deref() {
+					Some("playable") => PackKind::Playable,
+					Some("support") => PackKind::Support,
+					Some("child") => PackKind::Child,
+					_ => return Err("kind must be playable, support, or child.".to_owned()),
+				};
+			},
+			"next" => { out.next = parse_transition(&k, &v)?; },
+			"nextEdge" => { out.next_edge = parse_transition(&k, &v)?; },
+			"weight" => { out.weight = int(&k, &v)?; },
+			"child" => { out.child.replace(v.as_string().ok_or("child must be a string.")?); },
+			"start" => { out.start.replace(parse_start(&v)?); },
+			"offset" => {
+				let (x, y) = pair(&k, &v)?;
+				offset.replace(Position::new(int(&k, &x)?, int(&k, &y)?));
+			},
+			"flipX" => { out.flip_x = boolean(&k, &v)?; },
+			"mayExit" => { out.may_exit = boolean(&k, &v)?; },
+			"smooth" => { out.smooth = boolean(&k, &v)?; },
+			"minWidth" => { out.min_size.0 = int(&k, &v)?; },
+			"minHeight" => { out.min_size.1 = int(&k, &v)?; },
+			_ => return Err(format!("Unknown key: {k}.")),
+		}
+	}
+
+	// Only one starting position is allowed.
+	if let Some(offset) = offset {
+		if out.start.is_some() {
+			return Err("start and offset cannot both be set.".to_owned());
+		}
+		out.start.replace(PackStart::Offset(offset));
+	}
+
+	Ok(out)
+}
+
+/// # Parse Starting Position.
+fn parse_start(src: &JsValue) -> Result<PackStart, String> {
+	let mut x = None;
+	let mut y = None;
+	for (k, v) in dom::entries(src).ok_or("start must be an object.")? {
+		match (k.as_str(), v.as_string().as_deref()) {
+			("x", Some("left")) => { x.replace(StartX::Left); },
+			("x", Some("right")) => { x.replace(StartX::Right); },
+			("x", Some("random")) => { x.replace(StartX::Random); },
+			("x", None) => { x.replace(StartX::At(int(&k, &v)?)); },
+			("y", Some("top")) => { y.replace(StartY::Top); },
+			("y", Some("bottom")) => { y.replace(StartY::Bottom); },
+			("y", None) => { y.replace(StartY::At(int(&k, &v)?)); },
+			("x" | "y", Some(_)) => return Err(format!("Invalid start {k}.")),
+			_ => return Err(format!("Unknown start key: {k}.")),
+		}
+	}
+
+	Ok(PackStart::Screen(
+		x.ok_or("start.x is required.")?,
+		y.ok_or("start.y is required.")?,
+	))
+}
+
+/// # Parse Transition.
+///
+/// Successors can be a single name or an array of `[name, weight]` pairs.
+fn parse_transition(key: &str, src: &JsValue) -> Result<Vec<(String, u16)>, String> {
+	if let Some(name) = src.as_string() { return Ok(vec![(name, 1)]); }
+	if ! Array::is_array(src) {
+		return Err(format!("{key} must be a name or an array of [name, weight] pairs."));
+	}
+
+	let mut out = Vec::new();
+	for v in src.unchecked_ref::<Array>().iter() {
+		let (name, weight) = pair(key, &v)?;
+		let name = name.as_string().ok_or_else(|| format!("{key} names must be strings."))?;
+		out.push((name, int(key, &weight)?));
+	}
+
+	if out.is_empty() { Err(format!("{key} must not be empty.")) }
+	else { Ok(out) }
+}
+
+/// # Parse Scenes.
+///
+/// Parse and validate an array of scene objects. This is shared with
+/// `Poe.inject`.
+pub(super) fn parse_scenes(src: &JsValue) -> Result<SceneListSpec, String> {
+	if ! Array::is_array(src) { return Err("Scenes must be an array.".to_owned()); }
+
+	let mut scenes = Vec::new();
+	for (idx, v) in src.unchecked_ref::<Array>().iter().enumerate() {
+		scenes.push(parse_scene(&v).map_err(|e| format!("Scene #{idx}: {e}"))?);
+	}
+
+	Ok(SceneListSpec::from(scenes))
+}
+
+/// # Boolean.
+fn boolean(key: &str, src: &JsValue) -> Result<bool, String> {
+	src.as_bool().ok_or_else(|| format!("{key} must be a boolean."))
+}
+
+#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+/// # Integer.
+///
+/// Parse a whole number that fits in `T`, without any rounding or clamping.
+fn int<T: TryFrom<i64>>(key: &str, src: &JsValue) -> Result<T, String> {
+	/// # Maximum Safe Integer.
+	const MAX_SAFE: f64 = 9_007_199_254_740_991.0;
+
+	src.as_f64()
+		.filter(|n| n.fract() == 0.0 && n.abs() <= MAX_SAFE)
+		.and_then(|n| T::try_from(n as i64).ok())
+		.ok_or_else(|| format!("{key} contains an invalid or out-of-range number."))
+}
+
+/// # Pair.
+///
+/// Parse a two-element array.
+fn pair(key: &str, src: &JsValue) -> Result<(JsValue, JsValue), String> {
+	if Array::is_array(src) {
+		let arr = src.unchecked_ref::<Array>();
+		if arr.length() == 2 { return Ok((arr.get(0), arr.get(1))); }
+	}
+	Err(format!("{key} must be a two-element array."))
+}
+
+/// # Parse Scene.
+fn parse_scene(src: &JsValue) -> Result<SceneSpec, String> {
+	let mut fpms = None;
+	let mut frames = None;
+	let mut move_to = None;
+	let mut repeat = None;
+	let mut sounds = Vec::new();
+	let mut ease = None;
+	let mut flags = 0;
+
+	for (k, v) in dom::entries(src).ok_or("Scenes must be objects.")? {
+		match k.as_str() {
+			"fpms" => { fpms.replace(int::<u16>(&k, &v)?); },
+			"frames" => {
+				if ! Array::is_array(&v) { return Err("frames must be an array.".to_owned()); }
+				let mut out = Vec::new();
+				for f in v.unchecked_ref::<Array>().iter() {
+					out.push(Frame::from_u8(int(&k, &f)?).ok_or("frames contains an invalid ID.")?);
+				}
+				frames.replace(out);
+			},
+			"moveTo" => {
+				let (x, y) = pair(&k, &v)?;
+				move_to.replace(Position::new(int(&k, &x)?, int(&k, &y)?));
+			},
+			"repeat" => {
+				let (n, from) = pair(&k, &v)?;
+				repeat.replace((int::<u16>(&k, &n)?, int::<u8>(&k, &from)?));
+			},
+			"sound" => {
+				let (name, idx) = pair(&k, &v)?;
+				sounds.push(SoundCue::new(parse_sound(&name)?, int::<u8>(&k, &idx)?));
+			},
+			"sounds" => {
+				if ! Array::is_array(&v) { return Err("sounds must be an array.".to_owned()); }
+				for cue in v.unchecked_ref::<Array>().iter() {
+					sounds.push(parse_sound_cue(&cue)?);
+				}
+			},
+			"ease" => { ease.replace(parse_ease(&v)?); },
+			"flags" => { flags = parse_flags(&v)?; },
+			_ => return Err(format!("Unknown key: {k}.")),
+		}
+	}
+
+	let fpms = fpms.ok_or("fpms is required.")?;
+	let frames = frames.ok_or("frames is required.")?;
+	let mut scene = Scene::new(fpms, &[]).with_flags(flags);
+	if let Some(pos) = move_to { scene = scene.with_move_to(pos); }
+	if let Some((n, from)) = repeat { scene = scene.with_repeat(n, from); }
+	if let Some(e) = ease { scene = scene.with_ease(e); }
+
+	let scene = SceneSpec::new(scene, frames, sounds);
+	scene.validate(0, 0)?;
+	Ok(scene)
+}
+
+/// # Parse Sound.
+fn parse_sound(src: &JsValue) -> Result<Sound, String> {
+	src.as_string()
+		.and_then(|n| Sound::from_name(&n))
+		.ok_or_else(|| "sound must be Baa, Sneeze, or Yawn.".to_owned())
+}
+
+/// # Parse Sound Cue.
+///
+/// Cues are objects with a `sound` name and frame `idx`, and optionally a
+/// percentage `chance` and/or `everyLoop` flag.
+fn parse_sound_cue(src: &JsValue) -> Result<SoundCue, String> {
+	let mut sound = None;
+	let mut idx = None;
+	let mut chance = 100;
+	let mut every = false;
+	for (k, v) in dom::entries(src).ok_or("Sound cues must be objects.")? {
+		match k.as_str() {
+			"sound" => { sound.replace(parse_sound(&v)?); },
+			"idx" => { idx.replace(int::<u8>(&k, &v)?); },
+			"chance" => { chance = int::<u8>(&k, &v)?; },
+			"everyLoop" => { every = boolean(&k, &v)?; },
+			_ => return Err(format!("Unknown sound key: {k}.")),
+		}
+	}
+
+	let cue = SoundCue::new(
+		sound.ok_or("Sound cues require a sound.")?,
+		idx.ok_or("Sound cues require an idx.")?,
+	).with_chance(chance);
+	Ok(if every { cue.every_loop() } else { cue })
+}
+
+/// # Parse Ease.
+///
+/// Easing curves are either named or given as `[x1, y1, x2, y2]` cubic
+/// bezier control points.
+fn parse_ease(src: &JsValue) -> Result<Ease, String> {
+	if let Some(name) = src.as_string() {
+		return Ease::from_js_name(&name).ok_or_else(|| format!("Invalid ease: {name}."));
+	}
+
+	if Array::is_array(src) {
+		let arr = src.unchecked_ref::<Array>();
+		if arr.length() == 4 {
+			let mut points = [0.0; 4];
+			for (p, v) in points.iter_mut().zip(arr.iter()) {
+				*p = v.as_f64().ok_or("ease contains an invalid number.")?;
+			}
+			return Ease::bezier(points).ok_or_else(|| "ease contains an out-of-range point.".to_owned());
+		}
+	}
+
+	Err("ease must be a name or four-element array.".to_owned())
+}
+
+/// # Parse Flags.
+fn parse_flags(src: &JsValue) -> Result<u8, String> {
+	if ! Array::is_array(src) { return Err("flags must be an array.".to_owned()); }
+
+	let mut out = 0;
+	for v in src.unchecked_ref::<Array>().iter() {
+		out |= match v.as_string().as_deref() {
+			Some("flipXNext") => Scene::FLIP_X_NEXT,
+			Some("gravity") => Scene::GRAVITY,
+			Some("ignoreEdges") => Scene::IGNORE_EDGES,
+			_ => return Err(format!("Invalid flag: {v:?}.")),
+		};
+	}
+
+	Ok(out)
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use super::super::scene::text;
+	use wasm_bindgen_test::*;
+
+	/// # Test Definition.
+	fn def(name: &str, kind: PackKind) -> PackDef {
+		PackDef::new(name.to_owned(), kind, "100 F001 F002".parse().expect("Invalid scene."))
+	}
+
+	#[wasm_bindgen_test]
+	fn t_register() {
+		let mut a = def("MoonWalk", PackKind::Playable);
+		a.title.replace("Moon Walk".to_owned());
+		a.next.push(("MoonBounce".to_owned(), 1));
+		a.next_edge.push(("Rotate".to_owned(), 1));
+		a.child.replace("MoonDust".to_owned());
+		a.weight = 12;
+		a.min_size = (300, 0);
+		let mut b = def("MoonBounce", PackKind::Support);
+		b.start.replace(PackStart::Screen(StartX::Random, StartY::Top));
+		let mut c = def("MoonDust", PackKind::Child);
+		c.start.replace(PackStart::Offset(Position::new(-38, 0)));
+
+		assert_eq!(register(vec![a, b, c]), Ok(()));
+
+		// IDs should follow the built-ins.
+		let walk = Animation::from_name("MoonWalk").expect("Missing MoonWalk.");
+		let bounce = Animation::from_name("MoonBounce").expect("Missing MoonBounce.");
+		let dust = Animation::from_name("MoonDust").expect("Missing MoonDust.");
+		assert_eq!(walk.id(), Animation::MAX_ANIMATION_ID + 1);
+		assert_eq!(bounce.id(), Animation::MAX_ANIMATION_ID + 2);
+		assert_eq!(dust.id(), Animation::MAX_ANIMATION_ID + 3);
+		assert_eq!(Animation::from_u8(dust.id()), Some(dust));
+		assert!(Animation::from_u8(dust.id() + 1).is_none(), "Unregistered ID resolved.");
+		assert_eq!(Animation::all().count(), Animation::ALL.len() + 3);
+
+		// Properties.
+		assert_eq!(walk.as_str(), "Moon Walk");
+		assert_eq!(bounce.as_str(), "MoonBounce");
+		assert!(walk.playable() && walk.primary() && walk.excludable(), "MoonWalk is wrong.");
+		assert!(! bounce.playable() && bounce.primary(), "MoonBounce is wrong.");
+		assert!(! dust.playable() && ! dust.primary(), "MoonDust is wrong.");
+		assert_eq!(walk.child(), Some(dust));
+		assert_eq!(walk.scenes(3840, 2160).count(), 2);
+		assert_eq!(walk.next(), Some(bounce));
+		assert_eq!(walk.next_edge(), Some(Animation::Rotate));
+		assert_eq!(bounce.pack_start(), Some(PackStart::Screen(StartX::Random, StartY::Top)));
+		assert_eq!(walk.min_size(), (300, 0));
+		assert_eq!(default_pool((1920, 1080), [0, 0]), vec![(walk, 12)]);
+		assert!(default_pool((200, 1080), [0, 0]).is_empty(), "MoonWalk shouldn't fit at 200x1080.");
+
+		// Later packs can build on earlier ones.
+		let mut d = def("MoonLanding", PackKind::Support);
+		d.next.push(("MoonWalk".to_owned(), 2));
+		d.next.push(("Walk".to_owned(), 1));
+		assert_eq!(register(vec![d]), Ok(()));
+		assert_eq!(Animation::from_name("MoonLanding").map(Animation::id), Some(dust.id() + 1));
+
+		// But names are first come, first served.
+		assert!(register(vec![def("MoonWalk", PackKind::Playable)]).is_err(), "Duplicate name.");
+	}
+
+	#[wasm_bindgen_test]
+	fn t_register_invalid() {
+		/// # Expect Failure.
+		fn fails(defs: Vec<PackDef>, expected: &str) {
+			let before = PACKS.with_borrow(Vec::len);
+			let interned = text::interned();
+			assert_eq!(register(defs), Err(expected.to_owned()));
+			assert_eq!(PACKS.with_borrow(Vec::len), before, "Registry changed: {expected}");
+			assert_eq!(text::interned(), interned, "Scenes leaked: {expected}");
+		}
+
+		fails(Vec::new(), "Packs must include at least one animation.");
+		fails(
+			vec![def("Walk", PackKind::Playable)],
+			"Walk: an animation with this name already exists.",
+		);
+		fails(
+			vec![def("Moon", PackKind::Playable), def("Moon", PackKind::Child)],
+			"Moon: an animation with this name already exists.",
+		);
+		fails(
+			vec![def("Moon Walk", PackKind::Playable)],
+			"Animation #0: names must start with a letter and contain only ASCII letters and numbers.",
+		);
+
+		// Scenes must be valid.
+		let mut a = def("Moon", PackKind::Playable);
+		a.scenes = SceneListSpec::default();
+		fails(vec![a], "Moon: Scene lists must have at least one scene.");
+		let mut a = def("Moon", PackKind::Playable);
+		a.scenes = "100 F001\n5 F001".parse().expect("Invalid scene.");
+		fails(vec![a], "Moon: Scene #1: Scene fpms must be greater than 5.");
+		let mut a = def("Moon", PackKind::Playable);
+		a.scenes = "100 F001*9 repeat=w@0".parse().expect("Invalid scene.");
+		fails(vec![a], "Moon (7680x4320): Step overflow (69129).");
+
+		// Primaries and children can't mix.
+		let mut a = def("Moon", PackKind::Playable);
+		a.child.replace("Walk".to_owned());
+		fails(vec![a], "Moon: child Walk is not a child animation.");
+		let mut a = def("Moon", PackKind::Child);
+		a.child.replace("SplatGhost".to_owned());
+		fails(vec![a], "Moon: only primary animations can have children.");
+		let mut a = def("Moon", PackKind::Child);
+		a.next.push(("Walk".to_owned(), 1));
+		fails(vec![a], "Moon: next references Walk, but primary and child animations cannot mix.");
+		let mut a = def("Moon", PackKind::Child);
+		a.weight = 1;
+		fails(vec![a], "Moon: child animations cannot join the default pool.");
+		let mut a = def("Moon", PackKind::Playable);
+		a.start.replace(PackStart::Offset(Position::new(0, 0)));
+		fails(vec![a], "Moon: only child animations can have offsets.");
+		let mut a = def("Moon", PackKind::Child);
+		a.min_size = (0, 200);
+		fails(vec![a], "Moon: only primary animations can have minimum sizes.");
+
+		// Everything is all or nothing.
+		let a = def("Moon", PackKind::Playable);
+		let mut b = def("Star", PackKind::Playable);
+		b.next_edge.push(("Nope".to_owned(), 1));
+		fails(vec![a, b], "Star: nextEdge references unknown animation Nope.");
+		assert!(Animation::from_name("Moon").is_none(), "Moon should not be registered.");
+
+		// There's only so much room. (Other tests may have registered packs
+		// already, so work out what's left.)
+		let room = usize::from(MAX_PACK_ID - Animation::MAX_ANIMATION_ID) - PACKS.with_borrow(Vec::len);
+		let defs = (0..=room)
+			.map(|n| def(&format!("Moon{n}"), PackKind::Playable))
+			.collect();
+		fails(defs, &format!("Too many animations; only {room} more can be loaded."));
+	}
+}
@@ -2,13 +2,20 @@
 # RS Mate Poe: Animation Scenes
 */
 
+mod ease;
+pub(super) mod text;
+
 use crate::{
 	Direction,
 	Frame,
 	Position,
 	Sound,
+	Universe,
 };
 use std::num::NonZeroU16;
+use super::scenes::DYNAMIC_SCENES;
+
+pub(crate) use ease::Ease;
 
 
 
@@ -32,8 +39,11 @@ pub(crate) struct Scene {
 	/// # Repeat?
 	repeat: Option<(NonZeroU16, u8)>,
 
-	/// # Play Sound?
-	sound: Option<(Sound, u8)>,
+	/// # Sound Cues.
+	sounds: &'static [SoundCue],
+
+	/// # Easing.
+	ease: Option<Ease>,
 
 	/// # Flags.
 	flags: u8,
@@ -47,7 +57,8 @@ impl Scene {
 			fpms,
 			frames,
 			repeat: None,
-			sound: None,
+			sounds: &[],
+			ease: None,
 			flags: 0,
 		}
 	}
@@ -65,9 +76,19 @@ impl Scene {
 		else { self }
 	}
 
-	/// # With Sound.
-	pub(crate) const fn with_sound(self, sound: Sound, idx: u8) -> Self {
-		Self { sound: Some((sound, idx)), ..self }
+	/// # With Frames.
+	const fn with_frames(self, frames: &'static [Frame]) -> Self {
+		Self { frames, ..self }
+	}
+
+	/// # With Sounds.
+	pub(crate) const fn with_sounds(self, sounds: &'static [SoundCue]) -> Self {
+		Self { sounds, ..self }
+	}
+
+	/// # With Easing.
+	pub(crate) const fn with_ease(self, ease: Ease) -> Self {
+		Self { ease: Some(ease), ..self }
 	}
 
 	/// # With Flags.
@@ -81,49 +102,74 @@ impl Scene {
 	///
 	/// Return the total number of steps for the `Scene`, ultimately determined
 	/// by the frame count (including repeats).
-	pub(crate) const fn steps(&self) -> usize {
-		let len = self.frames.len();
+	pub(crate) const fn steps(&self) -> usize { self.steps_with(self.frames.len()) }
+
+	/// # Number of Steps (With Length).
+	///
+	/// This is the meat of `Scene::steps`, broken out so the frame count can
+	/// be specified, e.g. for scene specs that keep their frames separately.
+	pub(super) const fn steps_with(&self, len: usize) -> usize {
 		if let Some((repeat, from)) = self.repeat {
 			len + (len - from as usize) * repeat.get() as usize
 		}
 		else { len }
 	}
 
-	/// # Frame at Step Number.
+	/// # Frame Index at Step Number.
+	///
+	/// Return the index of the frame played at a given step, accounting for
+	/// repeats.
 	///
 	/// Note: Steps start from zero for the purposes of this method.
-	pub(crate) const fn frame_at_step(&self, mut idx: usize) -> Frame {
+	const fn frame_idx(&self, idx: usize) -> usize {
 		let len = self.frames.len();
-
-		if len <= idx {
-			idx = match self.repeat {
+		if idx < len { idx }
+		else {
+			match self.repeat {
 				None | Some((_, 0)) => idx % len,
 				Some((_, from)) => {
 					let from = from as usize;
 					from + (idx - from) % (len - from)
 				},
-			};
+			}
 		}
+	}
+
+	/// # Frame at Step Number.
+	///
+	/// Note: Steps start from zero for the purposes of this method.
+	pub(crate) const fn frame_at_step(&self, idx: usize) -> Frame {
+		let idx = self.frame_idx(idx);
 
 		// This won't fail, but the condition lets the compiler omit a panic
 		// handler, saving a lot of space in the compiled binary.
-		if idx < len { self.frames[idx] }
+		if idx < self.frames.len() { self.frames[idx] }
 		else { Frame::None }
 	}
 
-	/// # Sound at Step Number.
+	/// # Sounds at Step Number.
+	///
+	/// Return the set of sounds (see `Sound::bit`) cued for the step,
+	/// rolling the dice for any that only play some of the time.
 	///
 	/// Note: Steps start from zero for the purposes of this method.
-	pub(crate) const fn sound_at_step(&self, idx: usize) -> Option<Sound> {
-		if let Some((out, idx2)) = self.sound && idx2 as usize == idx { Some(out) }
-		else { None }
+	pub(crate) fn sounds_at_step(&self, idx: usize) -> u8 {
+		let frame_idx = self.frame_idx(idx);
+		self.sounds.iter()
+			.filter(|c| c.matches(idx, frame_idx) && c.roll())
+			.fold(0, |acc, c| acc | c.sound.bit())
 	}
 
-	#[cfg(not(feature = "director"))]
-	/// # Next Tick.
-	pub(crate) const fn next_tick(&self) -> u16 { self.fpms }
+	/// # Frame Rate.
+	///
+	/// Return the number of milliseconds each frame should be displayed for.
+	/// This is fixed for most scenes, but entrance delays are configurable at
+	/// runtime.
+	fn fpms(&self) -> u16 {
+		if Self::ENTRANCE == self.flags & Self::ENTRANCE { Universe::entrance_delay() }
+		else { self.fpms }
+	}
 
-	#[cfg(feature = "director")]
 	#[expect(
 		clippy::cast_possible_truncation,
 		clippy::cast_sign_loss,
@@ -131,9 +177,10 @@ impl Scene {
 	)]
 	/// # Next Tick.
 	pub(crate) fn next_tick(&self) -> u16 {
-		crate::Universe::speed().map_or(
-			self.fpms,
-			|speed| (f32::from(self.fpms) / speed) as u16
+		let fpms = self.fpms();
+		Universe::speed().map_or(
+			fpms,
+			|speed| (f32::from(fpms) / speed) as u16
 		)
 	}
 }
@@ -151,11 +198,8 @@ impl Scene {
 	/// # Flag: Ignore Edges.
 	pub(crate) const IGNORE_EDGES: u8 =  0b0000_1000;
 
-	/// # Flag: Ease In.
-	pub(super) const EASE_IN: u8 =       0b0001_0000;
-
-	/// # Flag: Ease Out.
-	pub(super) const EASE_OUT: u8 =      0b0010_0000;
+	/// # Flag: Entrance Delay (Configurable Duration).
+	pub(super) const ENTRANCE: u8 =      0b0100_0000;
 
 	/// # Flag: Mate Mask.
 	pub(crate) const MATE_MASK: u8 =
@@ -163,15 +207,289 @@ impl Scene {
 }
 
 impl Scene {
-	/// # Ease?
-	const fn ease(&self) -> bool {
-		0 != self.flags & (Self::EASE_IN | Self::EASE_OUT)
+	/// # Validate.
+	///
+	/// Make sure the scene is sane: it has frames, a reasonable frame rate,
+	/// its repeat and sound indices (if any) are in range, and its easing
+	/// curve (if any) makes sense.
+	///
+	/// ## Errors
+	///
+	/// Returns a description of the first problem found, if any.
+	pub(crate) const fn validate(&self) -> Result<(), &'static str> {
+		self.validate_with(self.frames, self.sounds)
+	}
+
+	/// # Validate (With Frames and Sounds).
+	///
+	/// This is the meat of `Scene::validate`, broken out so the frames and
+	/// sounds can be specified, e.g. for scene specs that keep their own.
+	pub(super) const fn validate_with(&self, frames: &[Frame], sounds: &[SoundCue])
+	-> Result<(), &'static str> {
+		let len = frames.len();
+		if len == 0 { Err("Scenes must have at least one frame.") }
+		else if self.fpms <= 5 { Err("Scene fpms must be greater than 5.") }
+		else if let Some((_, r)) = self.repeat && len <= r as usize {
+			Err("Scene repeat index out of range.")
+		}
+		else if let Err(e) = validate_sounds(len, sounds) { Err(e) }
+		else if let Some(ease) = self.ease { ease.validate() }
+		else { Ok(()) }
+	}
+}
+
+/// # Validate Sounds.
+///
+/// Make sure the sound cues are in range of the `len` frames and have sane
+/// odds.
+const fn validate_sounds(len: usize, sounds: &[SoundCue]) -> Result<(), &'static str> {
+	let mut idx = 0;
+	while idx < sounds.len() {
+		let cue = sounds[idx];
+		if len <= cue.idx as usize {
+			return Err("Scene sound index out of range.");
+		}
+		if cue.chance == 0 || 100 < cue.chance {
+			return Err("Scene sound chance must be between 1 and 100.");
+		}
+		idx += 1;
+	}
+	Ok(())
+}
+
+
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+/// # Sound Cue.
+///
+/// A sound to play at a given frame index within a `Scene`, optionally only
+/// some of the time, and optionally on every pass through a repeated run of
+/// frames rather than just the first.
+pub(crate) struct SoundCue {
+	/// # Sound.
+	sound: Sound,
+
+	/// # Frame Index.
+	idx: u8,
+
+	/// # Chance (Percent).
+	chance: u8,
+
+	/// # Every Loop?
+	every: bool,
+}
+
+impl SoundCue {
+	/// # New.
+	///
+	/// Play the sound at the frame index, the first time through.
+	pub(crate) const fn new(sound: Sound, idx: u8) -> Self {
+		Self { sound, idx, chance: 100, every: false }
+	}
+
+	/// # With Chance.
+	///
+	/// Only play the sound `chance` percent of the time.
+	pub(crate) const fn with_chance(self, chance: u8) -> Self {
+		Self { chance, ..self }
+	}
+
+	/// # Every Loop.
+	///
+	/// Play the sound on every pass through the frame, including repeats.
+	pub(crate) const fn every_loop(self) -> Self {
+		Self { every: true, ..self }
+	}
+
+	/// # Sound.
+	pub(crate) const fn sound(self) -> Sound { self.sound }
+
+	/// # Frame Index.
+	pub(crate) const fn idx(self) -> u8 { self.idx }
+
+	/// # Chance (Percent).
+	pub(crate) const fn chance(self) -> u8 { self.chance }
+
+	/// # Every Loop?
+	pub(crate) const fn every(self) -> bool { self.every }
+
+	/// # Matches Step?
+	///
+	/// Returns `true` if the cue applies to the step `idx`, which plays the
+	/// frame at `frame_idx`.
+	const fn matches(self, idx: usize, frame_idx: usize) -> bool {
+		let cue = self.idx as usize;
+		cue == idx || (self.every && cue == frame_idx)
+	}
+
+	/// # Roll the Dice.
+	///
+	/// Returns `true` if the sound should play this time. Sure things don't
+	/// touch the random number generator.
+	fn roll(self) -> bool {
+		100 <= self.chance || Universe::rand_mod(100) < u16::from(self.chance)
+	}
+}
+
+
+
+/// # Scene Error.
+///
+/// A fixed-capacity error message, since `format!` can't be used in `const`
+/// contexts. (Anything past the capacity is silently dropped.)
+pub(super) struct SceneError {
+	/// # Buffer.
+	buf: [u8; 96],
+
+	/// # Length.
+	len: usize,
+}
+
+impl SceneError {
+	/// # New.
+	pub(super) const fn new() -> Self {
+		Self { buf: [0; 96], len: 0 }
+	}
+
+	/// # Push String.
+	pub(super) const fn push_str(mut self, src: &str) -> Self {
+		let src = src.as_bytes();
+		let mut idx = 0;
+		while idx < src.len() && self.len < self.buf.len() {
+			self.buf[self.len] = src[idx];
+			self.len += 1;
+			idx += 1;
+		}
+		self
+	}
+
+	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+	/// # Push Number.
+	pub(super) const fn push_num(self, mut num: usize) -> Self {
+		let mut tmp = [0_u8; 20];
+		let mut start = tmp.len();
+		loop {
+			start -= 1;
+			tmp[start] = b'0' + (num % 10) as u8;
+			num /= 10;
+			if num == 0 { break; }
+		}
+
+		match str::from_utf8(tmp.split_at(start).1) {
+			Ok(s) => self.push_str(s),
+			Err(_) => self,
+		}
+	}
+
+	/// # As Str.
+	pub(super) const fn as_str(&self) -> &str {
+		match str::from_utf8(self.buf.split_at(self.len).0) {
+			Ok(s) => s,
+			Err(_) => "",
+		}
+	}
+}
+
+
+
+/// # Check Scenes.
+///
+/// Validate each scene, and make sure the total number of steps fits in a
+/// `u16`. This is `const` so the built-in scenes can be checked at compile
+/// time.
+///
+/// ## Errors
+///
+/// Returns a description of the first problem found, if any.
+pub(super) const fn check_scenes(scenes: &[Scene]) -> Result<(), SceneError> {
+	let mut total = 0;
+	let mut idx = 0;
+	while idx < scenes.len() {
+		if let Err(e) = scenes[idx].validate() {
+			return Err(
+				SceneError::new().push_str("Scene #").push_num(idx).push_str(": ").push_str(e)
+			);
+		}
+		total += scenes[idx].steps();
+		if (u16::MAX as usize) <= total {
+			return Err(SceneError::new().push_str("Step overflow (").push_num(total).push_str(")."));
+		}
+		idx += 1;
+	}
+
+	if total == 0 { Err(SceneError::new().push_str("Scene lists must have at least one scene.")) }
+	else { Ok(()) }
+}
+
+/// # Splice.
+///
+/// Return a copy of the `fixed` (template) scenes with the `N` starting at
+/// `idx` replaced by `scaled`, i.e. screen-scaled versions of the same.
+///
+/// ## Panics
+///
+/// This will panic if the replacements don't fit within the template. (The
+/// dynamic lists are checked at compile time, so this shouldn't happen in
+/// practice.)
+pub(crate) const fn splice<const N: usize, const M: usize>(
+	fixed: &[Scene; M],
+	idx: usize,
+	scaled: [Scene; N],
+) -> [Scene; M] {
+	assert!(idx + N <= M, "Invalid splice.");
+
+	let mut scenes = *fixed;
+	let mut i = 0;
+	while i < N {
+		scenes[idx + i] = scaled[i];
+		i += 1;
 	}
+	scenes
+}
+
+
+
+#[derive(Debug, Clone, Copy)]
+/// # Inline Scenes.
+///
+/// A screen-scaled scene list — e.g. from `splice` — held by value rather
+/// than on the heap. The capacity is sized by build.rs to fit the longest
+/// dynamic template, so the builders can have any number of scenes.
+pub(crate) struct InlineScenes {
+	/// # Scenes.
+	scenes: [Scene; DYNAMIC_SCENES],
+
+	/// # Length.
+	len: usize,
+}
+
+impl InlineScenes {
+	/// # New.
+	///
+	/// ## Panics
+	///
+	/// This will panic if `N` exceeds the capacity. (The dynamic builders are
+	/// all called at compile time, so this shouldn't happen in practice.)
+	pub(crate) const fn new<const N: usize>(src: [Scene; N]) -> Self {
+		assert!(N <= DYNAMIC_SCENES, "Too many inline scenes.");
+
+		let mut scenes = [Scene::new(0, &[]); DYNAMIC_SCENES];
+		let mut i = 0;
+		while i < N {
+			scenes[i] = src[i];
+			i += 1;
+		}
+		Self { scenes, len: N }
+	}
+
+	/// # As Slice.
+	const fn as_slice(&self) -> &[Scene] { self.scenes.split_at(self.len).0 }
 }
 
 
 
 #[derive(Debug)]
+#[expect(clippy::large_enum_variant, reason = "Keeping dynamic lists off the heap is the point.")]
 /// # Scene List Kind.
 ///
 /// This works kind of like a `Cow`, allowing us to work with either static
@@ -180,47 +498,32 @@ pub(crate) enum SceneListKind {
 	/// # Fixed Scene.
 	Fixed(&'static [Scene]),
 
-	/// # Dynamic Scene.
-	Dynamic1([Scene; 1]),
-
-	/// # Two Dynamic Scenes.
-	Dynamic2([Scene; 2]),
+	/// # Inline Scenes.
+	///
+	/// Screen-scaled scenes from the dynamic builders.
+	Inline(InlineScenes),
 
-	/// # Three Dynamic Scenes.
-	Dynamic3([Scene; 3]),
+	/// # Owned Scenes.
+	///
+	/// Any number of scenes, e.g. from a runtime-loaded pack.
+	Owned(Vec<Scene>),
 }
 
 impl SceneListKind {
-	#[cfg(test)]
 	/// # As Slice.
 	pub(crate) const fn as_slice(&self) -> &[Scene] {
 		match self {
 			Self::Fixed(s) => s,
-			Self::Dynamic1(d) => d.as_slice(),
-			Self::Dynamic2(d) => d.as_slice(),
-			Self::Dynamic3(d) => d.as_slice(),
+			Self::Inline(s) => s.as_slice(),
+			Self::Owned(d) => d.as_slice(),
 		}
 	}
 
 	/// # Get.
-	pub(crate) fn get(&self, idx: usize) -> Option<&Scene> {
-		match self {
-			Self::Fixed(s) => s.get(idx),
-			Self::Dynamic1(d) => d.get(idx),
-			Self::Dynamic2(d) => d.get(idx),
-			Self::Dynamic3(d) => d.get(idx),
-		}
-	}
+	pub(crate) fn get(&self, idx: usize) -> Option<&Scene> { self.as_slice().get(idx) }
 
 	/// # Length.
-	pub(crate) const fn len(&self) -> usize {
-		match self {
-			Self::Fixed(s) => s.len(),
-			Self::Dynamic1(_) => 1,
-			Self::Dynamic2(_) => 2,
-			Self::Dynamic3(_) => 3,
-		}
-	}
+	pub(crate) const fn len(&self) -> usize { self.as_slice().len() }
 }
 
 
@@ -249,6 +552,65 @@ impl SceneList {
 			step_idx: 0,
 		}
 	}
+
+	/// # Duration.
+	///
+	/// Return the total playback time for the list in milliseconds, at
+	/// normal speed.
+	pub(crate) fn duration(&self) -> u32 {
+		self.scenes.as_slice().iter().fold(0_u32, |acc, s| {
+			let steps = u32::try_from(s.steps()).unwrap_or(u32::MAX);
+			acc.saturating_add(u32::from(s.fpms()).saturating_mul(steps))
+		})
+	}
+
+	/// # Position.
+	///
+	/// Return the overall index of the most recently yielded step, counting
+	/// across all scenes, or `None` if nothing has been yielded yet.
+	pub(crate) fn position(&self) -> Option<usize> {
+		let before: usize = self.scenes.as_slice()
+			.iter()
+			.take(self.scene_idx)
+			.map(Scene::steps)
+			.sum();
+		(before + self.step_idx).checked_sub(1)
+	}
+
+	#[cfg(test)]
+	/// # Validate.
+	///
+	/// Validate each scene, and make sure the total number of steps fits in a
+	/// `u16`, since that is used for index access in some places.
+	///
+	/// ## Errors
+	///
+	/// Returns a description of the first problem found, if any.
+	pub(crate) fn validate(&self) -> Result<(), String> {
+		check_scenes(self.scenes.as_slice()).map_err(|e| e.as_str().to_owned())
+	}
+
+	#[cfg(feature = "director")]
+	/// # Seek.
+	///
+	/// Rewind or fast-forward the iterator so the step at the overall index
+	/// `idx` is the next one yielded.
+	///
+	/// Returns `false` — leaving the iterator as it was — if the index is out
+	/// of range.
+	pub(crate) fn seek(&mut self, mut idx: usize) -> bool {
+		for (scene_idx, scene) in self.scenes.as_slice().iter().enumerate() {
+			let steps = scene.steps();
+			if idx < steps {
+				self.scene_idx = scene_idx;
+				self.step_idx = idx;
+				return true;
+			}
+			idx -= steps;
+		}
+
+		false
+	}
 }
 
 impl Iterator for SceneList {
@@ -277,21 +639,15 @@ impl Iterator for SceneList {
 					else { scene.flags };
 
 				// Calculate the step movement, if any.
+				// Eased movements are computed as the difference between
+				// cumulative positions so the steps add up to the total.
 				let move_to = scene.move_to.and_then(|m|
-					if scene.ease() {
+					if let Some(ease) = scene.ease {
 						let total_x = m.x * steps as i32;
 						let total_y = m.y * steps as i32;
-
-						let (mut x, mut y) =
-							if self.scene_idx == steps { (total_x, total_y) }
-							else {
-								ease(self.step_idx, steps, total_x, total_y, scene.flags)?
-							};
-
-						if let Some((last_x, last_y)) = ease(self.step_idx - 1, steps, total_x, total_y, scene.flags) {
-							x -= last_x;
-							y -= last_y;
-						}
+						let (x, y) = ease.at(self.step_idx, steps, total_x, total_y);
+						let (last_x, last_y) = ease.at(self.step_idx - 1, steps, total_x, total_y);
+						let (x, y) = (x - last_x, y - last_y);
 
 						if x == 0 && y == 0 { None }
 						else { Some(Position::new(x, y)) }
@@ -307,7 +663,7 @@ impl Iterator for SceneList {
 					move_to,
 					direction,
 					frame: scene.frame_at_step(self.step_idx - 1),
-					sound: scene.sound_at_step(self.step_idx - 1),
+					sounds: scene.sounds_at_step(self.step_idx - 1),
 					next_tick: scene.next_tick(),
 					scene_flags,
 				});
@@ -340,8 +696,8 @@ pub(crate) struct Step {
 	/// # Current Frame.
 	frame: Frame,
 
-	/// # Current Sound.
-	sound: Option<Sound>,
+	/// # Current Sounds.
+	sounds: u8,
 
 	/// # Scene Flags.
 	scene_flags: u8,
@@ -360,8 +716,10 @@ impl Step {
 	/// # Frame.
 	pub(crate) const fn frame(&self) -> Frame { self.frame }
 
-	/// # Sound.
-	pub(crate) const fn sound(&self) -> Option<Sound> { self.sound }
+	/// # Sounds.
+	///
+	/// Return the set of sounds to play, if any. (See `Sound::bit`.)
+	pub(crate) const fn sounds(&self) -> u8 { self.sounds }
 
 	/// # Done.
 	///
@@ -381,33 +739,6 @@ impl Step {
 
 
 
-#[expect(
-	clippy::cast_precision_loss,
-	clippy::cast_possible_truncation,
-	reason = "False positive.",
-)]
-/// # Ease In/Out.
-///
-/// This returns the cumulative eased movement for a given step, if any.
-fn ease(e: usize, d: usize, x: i32, y: i32, ease: u8) -> Option<(i32, i32)> {
-	if d == 0 || (x == 0 && y == 0) { return None; }
-
-	// Figure out the scale.
-	let mut scale = e as f32 / d as f32;
-	if Scene::EASE_IN == ease & Scene::EASE_IN { scale *= scale; }
-	else { scale = (scale * (2.0 - scale)).powi(2); }
-
-	// Apply it!
-	let x = (scale * x as f32) as i32;
-	let y = (scale * y as f32) as i32;
-
-	// Return the coordinates if either are non-zero.
-	if x == 0 && y == 0 { None }
-	else { Some((x, y)) }
-}
-
-
-
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -416,6 +747,7 @@ mod tests {
 
 	#[wasm_bindgen_test]
 	fn t_builder() {
+		const SOUNDS: &[SoundCue] = &[SoundCue::new(Sound::Baa, 0)];
 		let expected = Scene {
 			move_to: Some(Position::new(55, 0)),
 			fpms: 100,
@@ -425,8 +757,9 @@ mod tests {
 				Frame::F069, Frame::F070, Frame::F006,
 			],
 			repeat: Some((NonZeroU16::new(20).unwrap(), 1)),
-			sound: Some((Sound::Baa, 0)),
-			flags: Scene::EASE_OUT | Scene::GRAVITY,
+			sounds: SOUNDS,
+			ease: Some(Ease::Out),
+			flags: Scene::GRAVITY,
 		};
 
 		let built = Scene::new(
@@ -439,16 +772,137 @@ mod tests {
 		)
 			.with_move_to(Position::new(55, 0))
 			.with_repeat(20, 1)
-			.with_sound(Sound::Baa, 0)
-			.with_flags(Scene::EASE_OUT | Scene::GRAVITY);
+			.with_sounds(SOUNDS)
+			.with_ease(Ease::Out)
+			.with_flags(Scene::GRAVITY);
 
 		assert_eq!(expected, built);
 	}
 
+	#[wasm_bindgen_test]
+	fn t_inline() {
+		const FIXED: [Scene; 2] = [
+			Scene::new(100, &[Frame::F001]),
+			Scene::new(100, &[Frame::F002]).with_repeat(3, 0),
+		];
+		let scenes = SceneListKind::Inline(InlineScenes::new(FIXED));
+		assert_eq!(scenes.as_slice(), FIXED);
+		assert_eq!(scenes.len(), 2);
+		assert_eq!(scenes.get(1), Some(&FIXED[1]));
+		assert!(scenes.get(2).is_none(), "Only the used slots should be visible.");
+
+		// The dynamic animations should all be built inline, at any size.
+		for a in Animation::ALL {
+			if a.repeat_formulas().is_empty() { continue; }
+			for size in [(0, 0), (1920, 1080), (7680, 4320)] {
+				let list = a.scenes(size.0, size.1);
+				assert!(
+					matches!(list.scenes, SceneListKind::Inline(_)),
+					"{} should be inline.", a.as_str(),
+				);
+				assert!(list.scenes.len() <= DYNAMIC_SCENES, "{} is too long.", a.as_str());
+			}
+		}
+	}
+
+	#[wasm_bindgen_test]
+	fn t_splice() {
+		const FIXED: &[Scene; 3] = &[
+			Scene::new(100, &[Frame::F001]),
+			Scene::new(100, &[Frame::F002]),
+			Scene::new(100, &[Frame::F003]),
+		];
+		const LONG: &[Scene; 20] = &[Scene::new(100, &[Frame::F001]); 20];
+
+		let scenes = splice(FIXED, 1, [FIXED[1].with_repeat(5, 0)]);
+		assert_eq!(scenes, [FIXED[0], FIXED[1].with_repeat(5, 0), FIXED[2]]);
+
+		// Nothing to replace.
+		assert_eq!(&splice(FIXED, 3, []), FIXED);
+
+		// There's no limit to the length.
+		let scenes = splice(LONG, 18, [LONG[18].with_repeat(2, 0), LONG[19].with_repeat(3, 0)]);
+		assert_eq!(scenes[..18], LONG[..18]);
+		assert_eq!(scenes[18..], [LONG[18].with_repeat(2, 0), LONG[19].with_repeat(3, 0)]);
+		let scenes = SceneList::new(SceneListKind::Owned(Vec::from(scenes)));
+		assert_eq!(scenes.validate(), Ok(()));
+		assert_eq!(scenes.count(), 18 + 3 + 4);
+	}
+
+	#[wasm_bindgen_test]
+	fn t_scene_error() {
+		const OUT_OF_RANGE: &[SoundCue] = &[SoundCue::new(Sound::Baa, 1)];
+		const NEVER: &[SoundCue] = &[SoundCue::new(Sound::Baa, 0).with_chance(0)];
+
+		for n in [0, 1, 9, 10, 65_535, usize::MAX] {
+			let e = SceneError::new().push_str("#").push_num(n).push_str(".");
+			assert_eq!(e.as_str(), format!("#{n}."));
+		}
+
+		// Overflow is truncated.
+		let long = "x".repeat(200);
+		assert_eq!(SceneError::new().push_str(&long).as_str(), &long[..96]);
+
+		// Validation should report the same things either way.
+		let scenes = SceneList::new(SceneListKind::Owned(vec![
+			Scene::new(100, &[Frame::F001]),
+			Scene::new(100, &[Frame::F001]).with_sounds(OUT_OF_RANGE),
+		]));
+		assert_eq!(scenes.validate(), Err("Scene #1: Scene sound index out of range.".to_owned()));
+		let scenes = SceneList::new(SceneListKind::Owned(vec![
+			Scene::new(100, &[Frame::F001]).with_sounds(NEVER),
+		]));
+		assert_eq!(
+			scenes.validate(),
+			Err("Scene #0: Scene sound chance must be between 1 and 100.".to_owned()),
+		);
+	}
+
+	#[wasm_bindgen_test]
+	fn t_sound_cues() {
+		const SOUNDS: &[SoundCue] = &[
+			SoundCue::new(Sound::Baa, 1),
+			SoundCue::new(Sound::Yawn, 1).every_loop(),
+			SoundCue::new(Sound::Sneeze, 0).with_chance(50).every_loop(),
+		];
+		let scene = Scene::new(100, &[Frame::F001, Frame::F002])
+			.with_repeat(2, 0)
+			.with_sounds(SOUNDS);
+		assert_eq!(scene.steps(), 6);
+
+		// The first-time cue only fires once; the every-loop one fires on
+		// each pass.
+		let baa = Sound::Baa.bit();
+		let yawn = Sound::Yawn.bit();
+		for (idx, expected) in [(1, baa | yawn), (3, yawn), (5, yawn)] {
+			assert_eq!(scene.sounds_at_step(idx), expected);
+		}
+
+		// The coin flip should land both ways eventually.
+		let sneeze = Sound::Sneeze.bit();
+		let rolls: Vec<u8> = (0..64).map(|_| scene.sounds_at_step(2)).collect();
+		assert!(rolls.iter().all(|r| *r == 0 || *r == sneeze));
+		assert!(rolls.contains(&0));
+		assert!(rolls.contains(&sneeze));
+	}
+
+	#[wasm_bindgen_test]
+	fn t_dynamic_sizes() {
+		// The compile-time checks only sample the screen sizes, so make sure
+		// everything holds up across the full range.
+		for a in Animation::ALL {
+			for (w, h) in (0..=7680).map(|w| (w, 1080)).chain((0..=4320).map(|h| (1920, h))) {
+				if let Err(e) = a.scenes(w, h).validate() {
+					panic!("{} ({w}x{h}): {e}", a.as_str());
+				}
+			}
+		}
+	}
+
 	#[wasm_bindgen_test]
 	fn t_scenes() {
 		for a in Animation::ALL {
-			let scenes = a.scenes(3840);
+			let scenes = a.scenes(3840, 2160);
 
 			let mut total_steps = 0;
 			for s in scenes.scenes.as_slice() {
@@ -471,15 +925,24 @@ mod tests {
 					s.repeat.is_none_or(|(_, r)| usize::from(r) < s.frames.len()),
 					"Repeat overflow {}.", a.as_str(),
 				);
-				assert!(
-					s.sound.is_none_or(|(_, r)| usize::from(r) < s.frames.len()),
-					"Sound overflow {}.", a.as_str(),
-				);
+				for cue in s.sounds {
+					assert!(usize::from(cue.idx) < s.frames.len(), "Sound overflow {}.", a.as_str());
+					assert!(
+						(1..=100).contains(&cue.chance),
+						"Sound chance out of range {}.", a.as_str(),
+					);
+				}
 			}
 
+			// The shared validation should agree.
+			assert_eq!(scenes.validate(), Ok(()), "Invalid scenes {}.", a.as_str());
+
 			let mut count = 0;
 			let mut done = false;
-			for s in scenes {
+			let mut scenes = scenes;
+			assert!(scenes.position().is_none(), "Position should be none.");
+			while let Some(s) = scenes.next() {
+				assert_eq!(scenes.position(), Some(count), "Position mismatch {}.", a.as_str());
 				count += 1;
 				assert!(!done, "Steps shouldn't be done yet.");
 				if s.done() { done = true; }
@@ -495,4 +958,24 @@ mod tests {
 			);
 		}
 	}
+
+	#[cfg(feature = "director")]
+	#[wasm_bindgen_test]
+	fn t_seek() {
+		for a in Animation::ALL {
+			let all: Vec<(Frame, bool)> = a.scenes(3840, 2160).map(|s| (s.frame(), s.done())).collect();
+
+			// Seeking to each step should pick up right where it says.
+			let mut scenes = a.scenes(3840, 2160);
+			for (idx, expected) in all.iter().enumerate().rev() {
+				assert!(scenes.seek(idx), "Seek failed {}.", a.as_str());
+				let s = scenes.next().expect("Missing step.");
+				assert_eq!((s.frame(), s.done()), *expected, "Seek mismatch {}.", a.as_str());
+				assert_eq!(scenes.position(), Some(idx), "Position mismatch {}.", a.as_str());
+			}
+
+			// Out of range.
+			assert!(! scenes.seek(all.len()), "Seek should fail {}.", a.as_str());
+		}
+	}
 }
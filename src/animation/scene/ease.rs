@@ -0,0 +1,394 @@
+/*!
+# RS Mate Poe: Scene Easing
+*/
+
+use std::{
+	f32::consts::{
+		FRAC_PI_2,
+		PI,
+	},
+	fmt,
+	str::FromStr,
+};
+
+
+
+/// # Ease Names.
+///
+/// The named curves, along with their text/TOML and Javascript names, in
+/// canonical order.
+const NAMES: [(Ease, &str, &str); 11] = [
+	(Ease::In, "in", "in"),
+	(Ease::Out, "out", "out"),
+	(Ease::InOut, "in-out", "inOut"),
+	(Ease::CubicIn, "cubic-in", "cubicIn"),
+	(Ease::CubicOut, "cubic-out", "cubicOut"),
+	(Ease::CubicInOut, "cubic-in-out", "cubicInOut"),
+	(Ease::SineIn, "sine-in", "sineIn"),
+	(Ease::SineOut, "sine-out", "sineOut"),
+	(Ease::SineInOut, "sine-in-out", "sineInOut"),
+	(Ease::Bounce, "bounce", "bounce"),
+	(Ease::Elastic, "elastic", "elastic"),
+];
+
+/// # Bezier Y Range (Thousandths).
+///
+/// The control points' `y` values may overshoot the start or end — that's
+/// what makes them fun — but only so far.
+const BEZIER_Y: std::ops::RangeInclusive<i16> = -1000..=2000;
+
+
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+/// # Easing Curve.
+///
+/// This maps the progress through a scene — from zero to one — to the
+/// fraction of its total movement that should have been covered by then.
+///
+/// Curves may overshoot (`Bounce`, `Elastic`, and some beziers), but always
+/// start at zero and finish at one. (See `Ease::at`.)
+pub(crate) enum Ease {
+	/// # Quadratic In.
+	In,
+
+	/// # Out.
+	///
+	/// This is a squared quadratic ease-out, a little more dramatic than the
+	/// usual.
+	Out,
+
+	/// # Quadratic In/Out.
+	InOut,
+
+	/// # Cubic In.
+	CubicIn,
+
+	/// # Cubic Out.
+	CubicOut,
+
+	/// # Cubic In/Out.
+	CubicInOut,
+
+	/// # Sine In.
+	SineIn,
+
+	/// # Sine Out.
+	SineOut,
+
+	/// # Sine In/Out.
+	SineInOut,
+
+	/// # Bounce (Out).
+	Bounce,
+
+	/// # Elastic (Out).
+	Elastic,
+
+	/// # Cubic Bezier.
+	///
+	/// The `x1`, `y1`, `x2`, `y2` control points, CSS-style, in thousandths.
+	Bezier([i16; 4]),
+}
+
+impl Ease {
+	/// # Validate.
+	///
+	/// Bezier `x` values have to be within `0..=1` for the curve to make any
+	/// sense, and `y` values within `-1..=2`.
+	///
+	/// ## Errors
+	///
+	/// Returns a description of the problem, if any.
+	pub(crate) const fn validate(self) -> Result<(), &'static str> {
+		if let Self::Bezier([x1, y1, x2, y2]) = self {
+			if x1 < 0 || 1000 < x1 || x2 < 0 || 1000 < x2 {
+				return Err("Scene bezier x values must be between 0 and 1.");
+			}
+			if y1 < *BEZIER_Y.start() || *BEZIER_Y.end() < y1 || y2 < *BEZIER_Y.start() || *BEZIER_Y.end() < y2 {
+				return Err("Scene bezier y values must be between -1 and 2.");
+			}
+		}
+		Ok(())
+	}
+
+	/// # From Javascript Name.
+	///
+	/// Return the named curve corresponding to the camel-cased name used by
+	/// `Poe.inject` and `Poe.loadPack`, if any. (Beziers are given as arrays
+	/// instead.)
+	pub(crate) fn from_js_name(src: &str) -> Option<Self> {
+		NAMES.iter().find_map(|(e, _, js)| (*js == src).then_some(*e))
+	}
+
+	/// # Bezier From Floats.
+	///
+	/// Return a bezier from `x1`, `y1`, `x2`, `y2` control points, rounded to
+	/// the nearest thousandth, or `None` if any are out of range.
+	pub(crate) fn bezier(src: [f64; 4]) -> Option<Self> {
+		let mut out = [0_i16; 4];
+		for (o, n) in out.iter_mut().zip(src) {
+			let n = (n * 1000.0).round();
+			if ! n.is_finite() || n < f64::from(*BEZIER_Y.start()) || f64::from(*BEZIER_Y.end()) < n {
+				return None;
+			}
+			#[expect(clippy::cast_possible_truncation, reason = "Range checked above.")]
+			{ *o = n as i16; }
+		}
+
+		let out = Self::Bezier(out);
+		if out.validate().is_ok() { Some(out) }
+		else { None }
+	}
+}
+
+impl Ease {
+	#[expect(
+		clippy::cast_precision_loss,
+		clippy::cast_possible_truncation,
+		reason = "False positive.",
+	)]
+	/// # Cumulative Movement.
+	///
+	/// Return the eased share of the total `x`/`y` movement that should have
+	/// been covered after `e` of `d` steps.
+	///
+	/// The first and last values are always exact — zero and the total,
+	/// respectively — so the per-step differences always add up to the total
+	/// distance, however the curve wiggles in between.
+	pub(crate) fn at(self, e: usize, d: usize, x: i32, y: i32) -> (i32, i32) {
+		if e == 0 { (0, 0) }
+		else if d <= e { (x, y) }
+		else {
+			let scale = self.scale(e as f32 / d as f32);
+			((scale * x as f32) as i32, (scale * y as f32) as i32)
+		}
+	}
+
+	/// # Scale.
+	///
+	/// Return the curve's value for progress `t`.
+	fn scale(self, t: f32) -> f32 {
+		match self {
+			Self::In => t * t,
+			Self::Out => (t * (2.0 - t)).powi(2),
+			Self::InOut =>
+				if t < 0.5 { 2.0 * t * t }
+				else { 1.0 - 2.0_f32.mul_add(-t, 2.0).powi(2) / 2.0 },
+			Self::CubicIn => t.powi(3),
+			Self::CubicOut => 1.0 - (1.0 - t).powi(3),
+			Self::CubicInOut =>
+				if t < 0.5 { 4.0 * t.powi(3) }
+				else { 1.0 - 2.0_f32.mul_add(-t, 2.0).powi(3) / 2.0 },
+			Self::SineIn => 1.0 - (t * FRAC_PI_2).cos(),
+			Self::SineOut => (t * FRAC_PI_2).sin(),
+			Self::SineInOut => (1.0 - (t * PI).cos()) / 2.0,
+			Self::Bounce => bounce(t),
+			Self::Elastic =>
+				(-10.0 * t).exp2().mul_add((t.mul_add(10.0, -0.75) * PI * 2.0 / 3.0).sin(), 1.0),
+			Self::Bezier([x1, y1, x2, y2]) => bezier(
+				t,
+				f32::from(x1) / 1000.0,
+				f32::from(y1) / 1000.0,
+				f32::from(x2) / 1000.0,
+				f32::from(y2) / 1000.0,
+			),
+		}
+	}
+}
+
+impl fmt::Display for Ease {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if let Self::Bezier(points) = self {
+			f.write_str("cubic-bezier(")?;
+			for (idx, n) in points.iter().enumerate() {
+				if idx != 0 { f.write_str(",")?; }
+				write_thousandths(f, *n)?;
+			}
+			f.write_str(")")
+		}
+		else {
+			let name = NAMES.iter()
+				.find_map(|(e, n, _)| (e == self).then_some(*n))
+				.unwrap_or_default();
+			f.write_str(name)
+		}
+	}
+}
+
+impl FromStr for Ease {
+	type Err = String;
+
+	fn from_str(src: &str) -> Result<Self, Self::Err> {
+		if let Some(e) = NAMES.iter().find_map(|(e, n, _)| (*n == src).then_some(*e)) {
+			return Ok(e);
+		}
+
+		let mut points = [0_f64; 4];
+		let mut len = 0;
+		for n in src.strip_prefix("cubic-bezier(")
+			.and_then(|s| s.strip_suffix(')'))
+			.ok_or_else(|| format!("Invalid ease: {src}."))?
+			.split(',')
+		{
+			let n = n.parse::<f64>().map_err(|_| format!("Invalid ease: {src}."))?;
+			if len < 4 { points[len] = n; }
+			len += 1;
+		}
+
+		if len == 4 { Self::bezier(points).ok_or_else(|| format!("Invalid ease: {src}.")) }
+		else { Err(format!("Invalid ease: {src}.")) }
+	}
+}
+
+
+
+/// # Bounce (Out).
+///
+/// The classic: three diminishing bounces after the initial drop.
+fn bounce(mut t: f32) -> f32 {
+	/// # Strength.
+	const N: f32 = 7.5625;
+
+	/// # Bounce Spacing.
+	const D: f32 = 2.75;
+
+	if t < 1.0 / D { N * t * t }
+	else if t < 2.0 / D {
+		t -= 1.5 / D;
+		(N * t).mul_add(t, 0.75)
+	}
+	else if t < 2.5 / D {
+		t -= 2.25 / D;
+		(N * t).mul_add(t, 0.9375)
+	}
+	else {
+		t -= 2.625 / D;
+		(N * t).mul_add(t, 0.984_375)
+	}
+}
+
+/// # Cubic Bezier.
+///
+/// Find the curve's `y` for a given `x` (progress). Since `x` is monotonic
+/// for valid control points, a simple bisection does the trick.
+fn bezier(t: f32, x1: f32, y1: f32, x2: f32, y2: f32) -> f32 {
+	/// # Curve Point.
+	fn point(s: f32, p1: f32, p2: f32) -> f32 {
+		let inv = 1.0 - s;
+		s * (3.0 * inv * inv).mul_add(p1, s * (3.0 * inv).mul_add(p2, s))
+	}
+
+	let mut lo = 0.0_f32;
+	let mut hi = 1.0_f32;
+	for _ in 0..24 {
+		let mid = f32::midpoint(lo, hi);
+		if point(mid, x1, x2) < t { lo = mid; }
+		else { hi = mid; }
+	}
+
+	point(f32::midpoint(lo, hi), y1, y2)
+}
+
+/// # Write Thousandths.
+///
+/// Print a thousandths value as a (trimmed) decimal, e.g. `420` as `0.42`.
+fn write_thousandths(f: &mut fmt::Formatter<'_>, n: i16) -> fmt::Result {
+	if n < 0 { f.write_str("-")?; }
+	let n = n.unsigned_abs();
+	write!(f, "{}", n / 1000)?;
+	let mut frac = n % 1000;
+	if frac != 0 {
+		let mut digits = 3;
+		while frac.is_multiple_of(10) {
+			frac /= 10;
+			digits -= 1;
+		}
+		write!(f, ".{frac:0digits$}")?;
+	}
+	Ok(())
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use wasm_bindgen_test::*;
+
+	/// # Every Kind of Curve.
+	const ALL: [Ease; 14] = [
+		Ease::In, Ease::Out, Ease::InOut,
+		Ease::CubicIn, Ease::CubicOut, Ease::CubicInOut,
+		Ease::SineIn, Ease::SineOut, Ease::SineInOut,
+		Ease::Bounce, Ease::Elastic,
+		Ease::Bezier([420, 0, 580, 1000]),
+		Ease::Bezier([680, -550, 265, 1550]),
+		Ease::Bezier([0, 0, 1000, 1000]),
+	];
+
+	#[wasm_bindgen_test]
+	fn t_sums() {
+		// However bumpy the ride, the steps should add up exactly.
+		for ease in ALL {
+			for d in 1..=64_u8 {
+				for (x, y) in [(1, 0), (-3, 7), (16, -16), (-11, 0), (0, 2), (250, 3)] {
+					let total_x = x * i32::from(d);
+					let total_y = y * i32::from(d);
+					let d = usize::from(d);
+					let (mut sum_x, mut sum_y) = (0, 0);
+					for e in 1..=d {
+						let (x1, y1) = ease.at(e, d, total_x, total_y);
+						let (x0, y0) = ease.at(e - 1, d, total_x, total_y);
+						sum_x += x1 - x0;
+						sum_y += y1 - y0;
+					}
+					assert_eq!((sum_x, sum_y), (total_x, total_y), "{ease} is off for {d} steps.");
+				}
+			}
+		}
+	}
+
+	#[wasm_bindgen_test]
+	fn t_curves() {
+		for ease in ALL {
+			// The ends are fixed.
+			assert!(ease.scale(0.0).abs() < 0.001, "{ease} doesn't start at zero.");
+			assert!((ease.scale(1.0) - 1.0).abs() < 0.001, "{ease} doesn't end at one.");
+		}
+
+		// The halfway points for a few symmetric curves.
+		for ease in [Ease::InOut, Ease::CubicInOut, Ease::SineInOut, Ease::Bezier([0, 0, 1000, 1000])] {
+			assert!((ease.scale(0.5) - 0.5).abs() < 0.001, "{ease} is off at the midpoint.");
+		}
+
+		// The original two shouldn't have changed.
+		assert_eq!(Ease::In.at(5, 10, 100, -40), (25, -10));
+		assert_eq!(Ease::Out.at(5, 10, 100, -40), (56, -22));
+
+		// Overshoot is allowed.
+		assert!(Ease::Elastic.scale(0.1) > 1.0, "Elastic should overshoot.");
+		assert!(Ease::Bezier([680, -550, 265, 1550]).scale(0.1) < 0.0, "Bezier should undershoot.");
+	}
+
+	#[wasm_bindgen_test]
+	fn t_names() {
+		for ease in ALL {
+			let s = ease.to_string();
+			assert_eq!(s.parse::<Ease>(), Ok(ease), "Round trip failed: {s}");
+		}
+		for (ease, _, js) in NAMES {
+			assert_eq!(Ease::from_js_name(js), Some(ease), "JS name mismatch: {js}");
+		}
+
+		assert_eq!(Ease::Bezier([420, 0, 580, 1000]).to_string(), "cubic-bezier(0.42,0,0.58,1)");
+		assert_eq!(Ease::Bezier([5, -120, 1000, 2000]).to_string(), "cubic-bezier(0.005,-0.12,1,2)");
+		assert_eq!("cubic-bezier(0.1234,0,1,1)".parse(), Ok(Ease::Bezier([123, 0, 1000, 1000])));
+
+		for bad in [
+			"", "inout", "cubic-bezier(0,0,1)", "cubic-bezier(0,0,1,1,1)", "cubic-bezier(1.1,0,1,1)",
+			"cubic-bezier(0,3,1,1)", "cubic-bezier(0,0,1,1", "cubic-bezier(a,0,1,1)",
+		] {
+			assert!(bad.parse::<Ease>().is_err(), "Ease should be invalid: {bad}");
+		}
+	}
+}
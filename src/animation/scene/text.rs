@@ -0,0 +1,824 @@
+/*!
+# RS Mate Poe: Scene Text
+
+This module implements a compact, human-readable text format for scenes and
+scene lists, e.g. for diffing, external editors, or `Poe.loadPack`.
+
+Each scene is written on its own line as whitespace-separated tokens: the
+frame rate (milliseconds per frame), one or more frames, then any options:
+
+```text
+100 F002 F003 move=-2,0 repeat=41@0 gravity
+```
+
+| Token | Description |
+| ----- | ----------- |
+| `F002` | A frame name. Runs can be abbreviated, e.g. `F017*3`. |
+| `move=X,Y` | X/Y movement per step. |
+| `repeat=N@FROM` | Repeat count and the frame index to repeat from. |
+| `sound=NAME@IDX[,N%][,loop]` | Sound name and the frame index to play it at, optionally only `N` percent of the time, and/or on every repetition. May be given more than once. |
+| `ease=CURVE` | Movement easing; see below. |
+| `flip-x-next`, `gravity`, `ignore-edges`, `entrance` | Flags. |
+
+The easing curves are `in`, `out`, `in-out`, `cubic-in`, `cubic-out`,
+`cubic-in-out`, `sine-in`, `sine-out`, `sine-in-out`, `bounce`, `elastic`,
+and `cubic-bezier(X1,Y1,X2,Y2)`, the latter CSS-style, with up to three
+decimal places.
+
+Repeat counts may also be formulas of the screen width `w` and height `h`,
+using whole numbers, `+`, `-`, `*`, `/`, parentheses, `min(a,b)`, and
+`max(a,b)`, e.g. `repeat=(w+160)/32+1@0`. All math is unsigned 16-bit; subtraction saturates
+at zero, and division truncates.
+
+In lists, blank lines and lines starting with `#` are ignored.
+*/
+
+use crate::{
+	Animation,
+	Frame,
+	Position,
+	Scene,
+	SceneList,
+	Sound,
+};
+use super::super::pack;
+use super::{
+	Ease,
+	SoundCue,
+};
+use std::{
+	cell::RefCell,
+	fmt,
+	str::FromStr,
+	thread::LocalKey,
+};
+use super::SceneListKind;
+
+
+
+/// # Flag Names.
+///
+/// In canonical order.
+const FLAGS: [(u8, &str); 4] = [
+	(Scene::FLIP_X_NEXT, "flip-x-next"),
+	(Scene::GRAVITY, "gravity"),
+	(Scene::IGNORE_EDGES, "ignore-edges"),
+	(Scene::ENTRANCE, "entrance"),
+];
+
+
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+/// # Operator.
+pub(crate) enum Op {
+	/// # Addition (Wrapping).
+	Add,
+
+	/// # Subtraction (Saturating).
+	Sub,
+
+	/// # Multiplication (Wrapping).
+	Mul,
+
+	/// # Division.
+	Div,
+}
+
+impl Op {
+	/// # Precedence.
+	const fn precedence(self) -> u8 {
+		match self {
+			Self::Add | Self::Sub => 1,
+			Self::Mul | Self::Div => 2,
+		}
+	}
+
+	/// # As Char.
+	const fn as_char(self) -> char {
+		match self {
+			Self::Add => '+',
+			Self::Sub => '-',
+			Self::Mul => '*',
+			Self::Div => '/',
+		}
+	}
+}
+
+
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+/// # Formula.
+///
+/// A (screen-dependent) expression, used for repeat counts.
+pub(crate) enum Expr {
+	/// # Number.
+	Num(u16),
+
+	/// # Screen Width.
+	Width,
+
+	/// # Screen Height.
+	Height,
+
+	/// # Minimum.
+	Min(Box<Self>, Box<Self>),
+
+	/// # Binary Operation.
+	Op(Box<Self>, Op, Box<Self>),
+
+	/// # Maximum.
+	Max(Box<Self>, Box<Self>),
+}
+
+impl fmt::Display for Expr {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Num(n) => write!(f, "{n}"),
+			Self::Width => f.write_str("w"),
+			Self::Height => f.write_str("h"),
+			Self::Op(a, op, b) => {
+				// Parentheses are only needed when precedence would otherwise
+				// change the shape of the tree. Everything is left-associative,
+				// so equal-precedence operations on the right need them too.
+				let p = op.precedence();
+				if a.precedence() < p { write!(f, "({a})")?; }
+				else { write!(f, "{a}")?; }
+				write!(f, "{}", op.as_char())?;
+				if b.precedence() <= p { write!(f, "({b})") }
+				else { write!(f, "{b}") }
+			},
+			Self::Min(a, b) => write!(f, "min({a},{b})"),
+			Self::Max(a, b) => write!(f, "max({a},{b})"),
+		}
+	}
+}
+
+impl Expr {
+	/// # Evaluate.
+	///
+	/// Compute the value for the given screen size.
+	pub(crate) fn eval(&self, width: u16, height: u16) -> u16 {
+		match self {
+			Self::Num(n) => *n,
+			Self::Width => width,
+			Self::Height => height,
+			Self::Op(a, op, b) => {
+				let a = a.eval(width, height);
+				let b = b.eval(width, height);
+				match op {
+					Op::Add => a.wrapping_add(b),
+					Op::Sub => a.saturating_sub(b),
+					Op::Mul => a.wrapping_mul(b),
+					Op::Div => a.checked_div(b).unwrap_or(0),
+				}
+			},
+			Self::Min(a, b) => a.eval(width, height).min(b.eval(width, height)),
+			Self::Max(a, b) => a.eval(width, height).max(b.eval(width, height)),
+		}
+	}
+
+	/// # Precedence.
+	const fn precedence(&self) -> u8 {
+		if let Self::Op(_, op, _) = self { op.precedence() }
+		else { 3 }
+	}
+
+	/// # Parse.
+	fn parse(src: &str) -> Result<Self, String> {
+		let mut parser = ExprParser { src: src.as_bytes(), pos: 0 };
+		let out = parser.expr()?;
+		if parser.pos == parser.src.len() { Ok(out) }
+		else { Err(format!("Invalid formula: {src}.")) }
+	}
+}
+
+
+
+/// # Expression Parser.
+///
+/// A simple recursive-descent parser for `Expr`.
+struct ExprParser<'a> {
+	/// # Source.
+	src: &'a [u8],
+
+	/// # Position.
+	pos: usize,
+}
+
+impl ExprParser<'_> {
+	/// # Error.
+	fn err(&self) -> String {
+		format!("Invalid formula: {}.", String::from_utf8_lossy(self.src))
+	}
+
+	/// # Eat a Byte.
+	fn eat(&mut self, b: u8) -> bool {
+		if self.src.get(self.pos) == Some(&b) {
+			self.pos += 1;
+			true
+		}
+		else { false }
+	}
+
+	/// # Expression.
+	///
+	/// Terms separated by `+` or `-`.
+	fn expr(&mut self) -> Result<Expr, String> {
+		let mut out = self.term()?;
+		loop {
+			let op =
+				if self.eat(b'+') { Op::Add }
+				else if self.eat(b'-') { Op::Sub }
+				else { return Ok(out); };
+			out = Expr::Op(Box::new(out), op, Box::new(self.term()?));
+		}
+	}
+
+	/// # Term.
+	///
+	/// Atoms separated by `*` or `/`.
+	fn term(&mut self) -> Result<Expr, String> {
+		let mut out = self.atom()?;
+		loop {
+			let op =
+				if self.eat(b'*') { Op::Mul }
+				else if self.eat(b'/') { Op::Div }
+				else { return Ok(out); };
+			let rhs = self.atom()?;
+			if op == Op::Div && rhs == Expr::Num(0) { return Err(self.err()); }
+			out = Expr::Op(Box::new(out), op, Box::new(rhs));
+		}
+	}
+
+	/// # Atom.
+	///
+	/// A number, `w`, `h`, `min(a,b)`, `max(a,b)`, or parenthesized
+	/// expression.
+	fn atom(&mut self) -> Result<Expr, String> {
+		if self.eat(b'w') { return Ok(Expr::Width); }
+		if self.eat(b'h') { return Ok(Expr::Height); }
+		let min = self.src[self.pos..].starts_with(b"min(");
+		if min || self.src[self.pos..].starts_with(b"max(") {
+			self.pos += 4;
+			let a = Box::new(self.expr()?);
+			if ! self.eat(b',') { return Err(self.err()); }
+			let b = Box::new(self.expr()?);
+			if ! self.eat(b')') { return Err(self.err()); }
+			return Ok(if min { Expr::Min(a, b) } else { Expr::Max(a, b) });
+		}
+		if self.eat(b'(') {
+			let out = self.expr()?;
+			if self.eat(b')') { return Ok(out); }
+			return Err(self.err());
+		}
+
+		let len = self.src[self.pos..].iter().take_while(|b| b.is_ascii_digit()).count();
+		let num = std::str::from_utf8(&self.src[self.pos..self.pos + len]).ok()
+			.and_then(|n| n.parse::<u16>().ok())
+			.ok_or_else(|| self.err())?;
+		self.pos += len;
+		Ok(Expr::Num(num))
+	}
+}
+
+
+
+thread_local! {
+	/// # Interned Frames.
+	static FRAMES: RefCell<Vec<&'static [Frame]>> = const { RefCell::new(Vec::new()) };
+
+	/// # Interned Sound Cues.
+	static SOUNDS: RefCell<Vec<&'static [SoundCue]>> = const { RefCell::new(Vec::new()) };
+}
+
+#[cfg(test)]
+/// # Interned Counts.
+///
+/// Return the number of distinct frame and sound lists leaked so far.
+pub(crate) fn interned() -> (usize, usize) {
+	(FRAMES.with_borrow(Vec::len), SOUNDS.with_borrow(Vec::len))
+}
+
+/// # Intern.
+///
+/// `Scene`s borrow their frames and sound cues for the life of the program,
+/// so runtime-defined scenes have to leak theirs. This keeps that to one
+/// copy per distinct list, reusing any identical list leaked before.
+fn intern<T: Copy + PartialEq + 'static>(
+	cache: &'static LocalKey<RefCell<Vec<&'static [T]>>>,
+	src: &[T],
+) -> &'static [T] {
+	if src.is_empty() { return &[]; }
+	cache.with_borrow_mut(|c| {
+		let old = c.iter().find(|old| **old == src).copied();
+		old.unwrap_or_else(|| {
+			let new: &'static [T] = Box::leak(Box::from(src));
+			c.push(new);
+			new
+		})
+	})
+}
+
+
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+/// # Scene Spec.
+///
+/// An owned, unvalidated `Scene` whose repeat count may be a formula of the
+/// screen size.
+pub(crate) struct SceneSpec {
+	/// # Scene (Sans Frames, Sounds, and Repeat).
+	scene: Scene,
+
+	/// # Frames.
+	frames: Vec<Frame>,
+
+	/// # Sound Cues.
+	sounds: Vec<SoundCue>,
+
+	/// # Repeat Count and Index.
+	repeat: Option<(Expr, u8)>,
+}
+
+impl From<Scene> for SceneSpec {
+	fn from(scene: Scene) -> Self {
+		Self::new(scene.with_frames(&[]).with_sounds(&[]), scene.frames.to_vec(), scene.sounds.to_vec())
+	}
+}
+
+impl fmt::Display for SceneSpec {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.scene.fpms)?;
+
+		// Frames, with runs collapsed.
+		let mut iter = self.frames.iter().peekable();
+		while let Some(frame) = iter.next() {
+			let mut count = 1;
+			while iter.next_if_eq(&frame).is_some() { count += 1; }
+			if count == 1 { write!(f, " {frame:?}")?; }
+			else { write!(f, " {frame:?}*{count}")?; }
+		}
+
+		if let Some(pos) = self.scene.move_to { write!(f, " move={},{}", pos.x, pos.y)?; }
+		if let Some((n, from)) = &self.repeat { write!(f, " repeat={n}@{from}")?; }
+		for cue in &self.sounds {
+			write!(f, " sound={}@{}", cue.sound().name(), cue.idx())?;
+			if cue.chance() < 100 { write!(f, ",{}%", cue.chance())?; }
+			if cue.every() { f.write_str(",loop")?; }
+		}
+		if let Some(ease) = self.scene.ease { write!(f, " ease={ease}")?; }
+		for (flag, name) in FLAGS {
+			if flag == self.scene.flags & flag { write!(f, " {name}")?; }
+		}
+
+		Ok(())
+	}
+}
+
+impl FromStr for SceneSpec {
+	type Err = String;
+
+	fn from_str(src: &str) -> Result<Self, Self::Err> {
+		let mut parts = src.split_whitespace();
+		let fpms = parts.next()
+			.and_then(|n| n.parse::<u16>().ok())
+			.ok_or("Scenes must start with their frame rate.")?;
+
+		let mut frames = Vec::new();
+		let mut move_to = None;
+		let mut repeat = None;
+		let mut sounds = Vec::new();
+		let mut ease = None;
+		let mut flags = 0;
+		for part in parts {
+			if let Some(v) = part.strip_prefix("move=") {
+				let (x, y) = v.split_once(',')
+					.and_then(|(x, y)| Some((x.parse::<i32>().ok()?, y.parse::<i32>().ok()?)))
+					.ok_or_else(|| format!("Invalid move: {v}."))?;
+				move_to.replace(Position::new(x, y));
+			}
+			else if let Some(v) = part.strip_prefix("repeat=") {
+				let (n, from) = v.rsplit_once('@')
+					.ok_or_else(|| format!("Invalid repeat: {v}."))?;
+				let n = Expr::parse(n)?;
+				if n == Expr::Num(0) { return Err("Repeat counts must be greater than zero.".to_owned()); }
+				let from = from.parse::<u8>().map_err(|_| format!("Invalid repeat: {v}."))?;
+				repeat.replace((n, from));
+			}
+			else if let Some(v) = part.strip_prefix("sound=") {
+				sounds.push(parse_sound_cue(v).ok_or_else(|| format!("Invalid sound: {v}."))?);
+			}
+			else if let Some(v) = part.strip_prefix("ease=") {
+				ease.replace(v.parse::<Ease>()?);
+			}
+			else if let Some((flag, _)) = FLAGS.iter().find(|(_, n)| *n == part) {
+				flags |= flag;
+			}
+			else {
+				let (name, count) = match part.split_once('*') {
+					Some((name, count)) => (
+						name,
+						count.parse::<usize>().ok()
+							.filter(|n| *n != 0)
+							.ok_or_else(|| format!("Invalid frame count: {part}."))?,
+					),
+					None => (part, 1),
+				};
+				let frame = frame_from_name(name).ok_or_else(|| format!("Invalid token: {part}."))?;
+				frames.extend(std::iter::repeat_n(frame, count));
+			}
+		}
+
+		if frames.is_empty() { return Err("Scenes must have at least one frame.".to_owned()); }
+
+		let mut scene = Scene::new(fpms, &[]).with_flags(flags);
+		if let Some(pos) = move_to { scene = scene.with_move_to(pos); }
+		if let Some(e) = ease { scene = scene.with_ease(e); }
+		Ok(Self { scene, frames, sounds, repeat })
+	}
+}
+
+impl SceneSpec {
+	/// # New.
+	///
+	/// Split the owned `frames` and `sounds` from the rest of the `scene`,
+	/// whose own frames and sounds are ignored.
+	pub(crate) fn new(mut scene: Scene, frames: Vec<Frame>, sounds: Vec<SoundCue>) -> Self {
+		let repeat = scene.repeat.take().map(|(n, from)| (Expr::Num(n.get()), from));
+		Self { scene, frames, sounds, repeat }
+	}
+
+	/// # Scene (Sans Frames and Sounds).
+	///
+	/// Return the `Scene` for the given screen size, minus the (owned)
+	/// frames and sounds.
+	fn scaled(&self, width: u16, height: u16) -> Scene {
+		match &self.repeat {
+			Some((n, from)) => self.scene.with_repeat(n.eval(width, height), *from),
+			None => self.scene,
+		}
+	}
+
+	/// # Validate.
+	///
+	/// Make sure the scene is sane at the given screen size, returning its
+	/// step count if so. See `Scene::validate`.
+	///
+	/// ## Errors
+	///
+	/// Returns a description of the first problem found, if any.
+	pub(crate) fn validate(&self, width: u16, height: u16) -> Result<usize, &'static str> {
+		let scene = self.scaled(width, height);
+		scene.validate_with(&self.frames, &self.sounds)?;
+		Ok(scene.steps_with(self.frames.len()))
+	}
+
+	/// # Intern.
+	///
+	/// Pair a `Scene` borrowing (interned copies of) the frames and sounds
+	/// with the repeat formula, if any.
+	fn intern(&self) -> (Scene, Option<(Expr, u8)>) {
+		(
+			self.scene
+				.with_frames(intern(&FRAMES, &self.frames))
+				.with_sounds(intern(&SOUNDS, &self.sounds)),
+			self.repeat.clone(),
+		)
+	}
+}
+
+
+
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+/// # Scene List Spec.
+///
+/// A list of `SceneSpec`s, one per line.
+pub(crate) struct SceneListSpec(Vec<SceneSpec>);
+
+impl From<Vec<SceneSpec>> for SceneListSpec {
+	fn from(src: Vec<SceneSpec>) -> Self { Self(src) }
+}
+
+impl From<Vec<Scene>> for SceneListSpec {
+	fn from(src: Vec<Scene>) -> Self {
+		Self(src.into_iter().map(SceneSpec::from).collect())
+	}
+}
+
+impl From<&SceneTemplate> for SceneListSpec {
+	fn from(src: &SceneTemplate) -> Self {
+		Self(
+			src.0.iter()
+				.map(|(scene, repeat)| {
+					let mut spec = SceneSpec::from(*scene);
+					spec.repeat.clone_from(repeat);
+					spec
+				})
+				.collect()
+		)
+	}
+}
+
+impl fmt::Display for SceneListSpec {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for (idx, s) in self.0.iter().enumerate() {
+			if idx != 0 { f.write_str("\n")?; }
+			write!(f, "{s}")?;
+		}
+		Ok(())
+	}
+}
+
+impl FromStr for SceneListSpec {
+	type Err = String;
+
+	fn from_str(src: &str) -> Result<Self, Self::Err> {
+		src.lines()
+			.map(str::trim)
+			.filter(|line| ! line.is_empty() && ! line.starts_with('#'))
+			.enumerate()
+			.map(|(idx, line)| line.parse().map_err(|e| format!("Scene #{idx}: {e}")))
+			.collect::<Result<Vec<_>, _>>()
+			.map(Self)
+	}
+}
+
+impl SceneListSpec {
+	/// # Dynamic?
+	///
+	/// Returns `true` if any of the repeat counts are formulas.
+	pub(crate) fn is_dynamic(&self) -> bool {
+		self.0.iter().any(|s| ! matches!(s.repeat, None | Some((Expr::Num(_), _))))
+	}
+
+	/// # Validate.
+	///
+	/// Validate each scene at the given screen size, and make sure the total
+	/// number of steps fits in a `u16`. See `check_scenes`.
+	///
+	/// ## Errors
+	///
+	/// Returns a description of the first problem found, if any.
+	pub(crate) fn validate(&self, width: u16, height: u16) -> Result<(), String> {
+		let mut total = 0_usize;
+		for (idx, s) in self.0.iter().enumerate() {
+			total += s.validate(width, height).map_err(|e| format!("Scene #{idx}: {e}"))?;
+			if usize::from(u16::MAX) <= total { return Err(format!("Step overflow ({total}).")); }
+		}
+
+		if total == 0 { Err("Scene lists must have at least one scene.".to_owned()) }
+		else { Ok(()) }
+	}
+
+	/// # Intern.
+	///
+	/// Convert the (validated) specs into a `SceneTemplate`, interning the
+	/// frames and sounds. This leaks memory for any lists not seen before,
+	/// so should only be called once validation has passed.
+	pub(crate) fn intern(&self) -> SceneTemplate {
+		SceneTemplate(self.0.iter().map(SceneSpec::intern).collect())
+	}
+}
+
+
+
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+/// # Scene Template.
+///
+/// A validated and interned `SceneListSpec`, ready to be built for any
+/// screen size.
+pub(crate) struct SceneTemplate(Vec<(Scene, Option<(Expr, u8)>)>);
+
+impl SceneTemplate {
+	/// # Build.
+	///
+	/// Return the `SceneList` for the given screen size.
+	pub(crate) fn build(&self, width: u16, height: u16) -> SceneList {
+		SceneList::new(SceneListKind::Owned(
+			self.0.iter()
+				.map(|(scene, repeat)| match repeat {
+					Some((n, from)) => scene.with_repeat(n.eval(width, height), *from),
+					None => *scene,
+				})
+				.collect()
+		))
+	}
+}
+
+
+
+impl Animation {
+	/// # Scene Spec.
+	///
+	/// Return the animation's scenes as a `SceneListSpec`, with any
+	/// screen-scaled repeat counts written as formulas, i.e. the text
+	/// equivalent of its `skel/animations.toml` (or pack) definition.
+	pub(crate) fn scene_spec(self) -> SceneListSpec {
+		if let Some(spec) = pack::scene_spec(self) { return spec; }
+
+		let mut spec = SceneListSpec::from(self.scenes(0, 0).scenes.as_slice().to_vec());
+		for (idx, formula, from) in self.repeat_formulas() {
+			if
+				let Some(s) = spec.0.get_mut(*idx) &&
+				let Ok(n) = Expr::parse(formula)
+			{
+				s.repeat.replace((n, *from));
+			}
+		}
+		spec
+	}
+}
+
+
+
+impl fmt::Display for Scene {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		SceneSpec::from(*self).fmt(f)
+	}
+}
+
+impl fmt::Display for SceneList {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for (idx, s) in self.scenes.as_slice().iter().enumerate() {
+			if idx != 0 { f.write_str("\n")?; }
+			write!(f, "{s}")?;
+		}
+		Ok(())
+	}
+}
+
+
+
+/// # Frame From Name.
+fn frame_from_name(src: &str) -> Option<Frame> {
+	(0..=Frame::None as u8)
+		.filter_map(Frame::from_u8)
+		.find(|f| format!("{f:?}") == src)
+}
+
+/// # Parse Sound Cue.
+///
+/// Parse the `NAME@IDX[,N%][,loop]` part of a `sound=` token.
+fn parse_sound_cue(src: &str) -> Option<SoundCue> {
+	let mut parts = src.split(',');
+	let (name, idx) = parts.next()?.split_once('@')?;
+	let mut cue = SoundCue::new(Sound::from_name(name)?, idx.parse::<u8>().ok()?);
+	for part in parts {
+		if part == "loop" && ! cue.every() { cue = cue.every_loop(); }
+		else {
+			let chance = part.strip_suffix('%')?.parse::<u8>().ok()?;
+			if cue.chance() != 100 || ! (1..=100).contains(&chance) { return None; }
+			cue = cue.with_chance(chance);
+		}
+	}
+	Some(cue)
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::Animation;
+	use wasm_bindgen_test::*;
+
+	#[wasm_bindgen_test]
+	fn t_static() {
+		for a in Animation::ALL {
+			if ! a.repeat_formulas().is_empty() { continue; }
+
+			let scenes = a.scenes(0, 0);
+			let text = scenes.to_string();
+			let spec = text.parse::<SceneListSpec>()
+				.unwrap_or_else(|e| panic!("{a:?}: {e}\n{text}"));
+			assert!(! spec.is_dynamic(), "{a:?} should not be dynamic.");
+			assert_eq!(spec.to_string(), text, "{a:?} did not round-trip.");
+			assert_eq!(a.scene_spec(), spec, "{a:?} spec mismatch.");
+			assert_eq!(
+				spec.intern().build(0, 0).scenes.as_slice(),
+				scenes.scenes.as_slice(),
+				"{a:?} did not round-trip.",
+			);
+		}
+	}
+
+	#[wasm_bindgen_test]
+	fn t_dynamic() {
+		for a in Animation::ALL {
+			let formulas = a.repeat_formulas();
+			if formulas.is_empty() { continue; }
+			for (idx, f, _) in formulas {
+				assert!(Expr::parse(f).is_ok(), "{a:?} scene #{idx}: invalid formula {f}.");
+			}
+
+			let spec = a.scene_spec();
+			let text = spec.to_string();
+			assert!(spec.is_dynamic(), "{a:?} should be dynamic.");
+			assert_eq!(text.parse::<SceneListSpec>(), Ok(spec.clone()), "{a:?} did not round-trip.");
+
+			// The formulas should match the builders for any (sane) width
+			// or height.
+			let spec = spec.intern();
+			for w in 0..=u16::MAX - Frame::SIZE * 4 {
+				assert_eq!(
+					spec.build(w, 1080).scenes.as_slice(),
+					a.scenes(w, 1080).scenes.as_slice(),
+					"{a:?} differs at {w}x1080.",
+				);
+			}
+			for h in 0..=u16::MAX {
+				assert_eq!(
+					spec.build(1920, h).scenes.as_slice(),
+					a.scenes(1920, h).scenes.as_slice(),
+					"{a:?} differs at 1920x{h}.",
+				);
+			}
+		}
+	}
+
+	#[wasm_bindgen_test]
+	fn t_intern() {
+		let spec = "100 F001 F002 sound=Baa@1\n200 F001 F002 move=1,0 sound=Baa@1"
+			.parse::<SceneListSpec>()
+			.expect("Invalid scene list.");
+		let (frames, sounds) = interned();
+
+		// Validation shouldn't leak anything.
+		assert_eq!(spec.validate(0, 0), Ok(()));
+		assert_eq!(interned(), (frames, sounds));
+
+		// Interning should leak identical lists just the once.
+		let a = spec.intern();
+		let b = spec.intern();
+		assert_eq!(a, b);
+		assert!(std::ptr::eq(a.0[0].0.frames, a.0[1].0.frames), "Frames were not shared.");
+		assert!(std::ptr::eq(a.0[0].0.frames, b.0[0].0.frames), "Frames were not shared.");
+		assert!(std::ptr::eq(a.0[0].0.sounds, b.0[1].0.sounds), "Sounds were not shared.");
+		assert_eq!(interned(), (frames + 1, sounds + 1));
+		assert_eq!(a.build(0, 0).to_string(), spec.to_string());
+	}
+
+	#[wasm_bindgen_test]
+	fn t_expr() {
+		for (src, expected) in [
+			("w", "w"),
+			("(w)", "w"),
+			("1+2*3", "1+2*3"),
+			("(1+2)*3", "(1+2)*3"),
+			("1-(2-3)", "1-(2-3)"),
+			("(1-2)-3", "1-2-3"),
+			("w/(2*3)", "w/(2*3)"),
+			("max(w-1,(2))", "max(w-1,2)"),
+			("min(h,w)*(h)", "min(h,w)*h"),
+		] {
+			let expr = Expr::parse(src).expect("Invalid formula.");
+			assert_eq!(expr.to_string(), expected, "Print mismatch: {src}");
+			assert_eq!(Expr::parse(expected), Ok(expr), "Parse mismatch: {src}");
+		}
+
+		let expr = Expr::parse("max(w-100,3)*2/w").expect("Invalid formula.");
+		assert_eq!(expr.eval(0, 0), 0); // Division by zero.
+		assert_eq!(expr.eval(50, 0), 0);
+		assert_eq!(expr.eval(200, 0), 1);
+
+		let expr = Expr::parse("min(w,h)-h/2").expect("Invalid formula.");
+		assert_eq!(expr.eval(1920, 1080), 540);
+		assert_eq!(expr.eval(100, 1080), 0); // Saturating.
+
+		for bad in ["", "w+", "(w", "max(w)", "min(w,h", "x", "w/0", "70000", "w w"] {
+			assert!(Expr::parse(bad).is_err(), "Formula should be invalid: {bad}");
+		}
+	}
+
+	#[wasm_bindgen_test]
+	fn t_invalid() {
+		for (src, expected) in [
+			("F001", "Scene #0: Scenes must start with their frame rate."),
+			("100", "Scene #0: Scenes must have at least one frame."),
+			("100 F001\n100 Nope", "Scene #1: Invalid token: Nope."),
+			("100 F001*0", "Scene #0: Invalid frame count: F001*0."),
+			("100 F001 move=1", "Scene #0: Invalid move: 1."),
+			("100 F001 repeat=0@0", "Scene #0: Repeat counts must be greater than zero."),
+			("100 F001 repeat=2", "Scene #0: Invalid repeat: 2."),
+			("100 F001 sound=Moo@0", "Scene #0: Invalid sound: Moo@0."),
+			("100 F001 sound=Baa@0,0%", "Scene #0: Invalid sound: Baa@0,0%."),
+			("100 F001 sound=Baa@0,101%", "Scene #0: Invalid sound: Baa@0,101%."),
+			("100 F001 sound=Baa@0,loop,loop", "Scene #0: Invalid sound: Baa@0,loop,loop."),
+			("100 F001 ease=wobble", "Scene #0: Invalid ease: wobble."),
+			("100 F001 ease=cubic-bezier(2,0,1,1)", "Scene #0: Invalid ease: cubic-bezier(2,0,1,1)."),
+			("100 F001 ease-in", "Scene #0: Invalid token: ease-in."),
+		] {
+			assert_eq!(src.parse::<SceneListSpec>(), Err(expected.to_owned()));
+		}
+
+		// Comments and blank lines are fine.
+		let spec = "# Hello\n\n100 F001 sound=Baa@0\n".parse::<SceneListSpec>()
+			.expect("Invalid scene list.");
+		assert_eq!(spec.to_string(), "100 F001 sound=Baa@0");
+
+		// Multiple sound cues, with options in any order.
+		let spec = "100 F001 F002 sound=Baa@0 sound=Yawn@1,loop,25%".parse::<SceneListSpec>()
+			.expect("Invalid scene list.");
+		assert_eq!(spec.to_string(), "100 F001 F002 sound=Baa@0 sound=Yawn@1,25%,loop");
+	}
+}
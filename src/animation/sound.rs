@@ -2,6 +2,12 @@
 # RS Mate Poe: Sounds.
 */
 
+use js_sys::Array;
+use wasm_bindgen::{
+	JsCast,
+	JsValue,
+};
+
 #[expect(clippy::missing_docs_in_private_items, reason = "Self-explanatory.")]
 #[repr(u8)]
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
@@ -11,3 +17,92 @@ pub(crate) enum Sound {
 	Sneeze,
 	Yawn,
 }
+
+impl Sound {
+	/// # All Sounds.
+	pub(crate) const ALL: [Self; 3] = [Self::Baa, Self::Sneeze, Self::Yawn];
+
+	/// # From Name.
+	///
+	/// Return the sound matching the name, if any.
+	pub(crate) fn from_name(src: &str) -> Option<Self> {
+		match src {
+			"Baa" => Some(Self::Baa),
+			"Sneeze" => Some(Self::Sneeze),
+			"Yawn" => Some(Self::Yawn),
+			_ => None,
+		}
+	}
+
+	/// # Name.
+	pub(crate) const fn name(self) -> &'static str {
+		match self {
+			Self::Baa => "Baa",
+			Self::Sneeze => "Sneeze",
+			Self::Yawn => "Yawn",
+		}
+	}
+
+	/// # Parse Set.
+	///
+	/// Convert an array of sound names into a bitset.
+	///
+	/// ## Errors
+	///
+	/// An error is returned if the value isn't an array of sound names.
+	pub(crate) fn parse_set(src: &JsValue) -> Result<u8, String> {
+		if ! Array::is_array(src) { return Err("Muted sounds must be an array.".to_owned()); }
+
+		let mut out = 0;
+		for v in src.unchecked_ref::<Array>().iter() {
+			let Some(s) = v.as_string().and_then(|s| Self::from_name(&s)) else {
+				return Err(format!("Invalid sound: {v:?}."));
+			};
+			out |= s.bit();
+		}
+		Ok(out)
+	}
+
+	/// # Set to Array.
+	///
+	/// Convert a bitset back into an array of sound names.
+	pub(crate) fn set_to_array(set: u8) -> Array {
+		Self::from_set(set)
+			.map(|s| JsValue::from_str(s.name()))
+			.collect()
+	}
+
+	/// # From Set.
+	///
+	/// Return the sounds in a bitset, e.g. the muted ones, or those cued for
+	/// a `Step`.
+	pub(crate) fn from_set(set: u8) -> impl Iterator<Item=Self> {
+		Self::ALL.into_iter().filter(move |s| 0 != set & s.bit())
+	}
+
+	/// # Bit.
+	///
+	/// Return the bit representing this sound in a set.
+	pub(crate) const fn bit(self) -> u8 { 1 << self as u8 }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use wasm_bindgen_test::*;
+
+	#[wasm_bindgen_test]
+	fn t_names() {
+		let mut bits = 0;
+		for s in Sound::ALL {
+			assert_eq!(Sound::from_name(s.name()), Some(s));
+			assert_eq!(bits & s.bit(), 0, "Duplicate bit.");
+			bits |= s.bit();
+		}
+		assert!(Sound::from_name("Moo").is_none());
+		assert!(Sound::from_set(bits).eq(Sound::ALL), "Set mismatch.");
+		assert!(Sound::from_set(0).next().is_none(), "Set mismatch.");
+	}
+}
@@ -0,0 +1,322 @@
+/*!
+# RS Mate Poe: Configuration
+*/
+
+use crate::{
+	Animation,
+	dom,
+	Universe,
+};
+use js_sys::Array;
+use wasm_bindgen::{
+	JsCast,
+	prelude::*,
+};
+
+
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+/// # Runtime Configuration.
+///
+/// This holds the tunables that can be overridden at runtime via
+/// `Poe.configure`:
+///
+/// | Key | Description | Range | Default |
+/// | --- | ----------- | ----- | ------- |
+/// | `entranceDelay` | Milliseconds to wait before (re-)entering the screen. | `0..=10000` | `1000` |
+/// | `exitOdds` | One-in-n chance a walk/run may leave the screen. | `0..=1000` | `25` |
+/// | `walkOdds` | One-in-n chance the default choice will be a walk. | `0..=1000` | `3` |
+/// | `weights` | Default animation weights, by name. | `0..=1000` | — |
+/// | `entranceWeights` | Entrance animation weights, by name. | `0..=1000` | — |
+/// | `nextWeights` | Successor weights, by animation and successor name. | `0..=1000` | — |
+/// | `nextEdgeWeights` | Edge successor weights, by animation and successor name. | `0..=1000` | — |
+/// | `exclude` | Animation names (or presets) to never choose. | — | `[]` |
+///
+/// For the odds, zero means never.
+///
+/// Numbers are rounded and clamped to their respective ranges, but anything
+/// else — unknown keys or animations, non-numeric values — results in an
+/// error. Parsing is all-or-nothing; nothing is applied unless the whole
+/// thing checks out.
+pub(crate) struct Config {
+	/// # Entrance Delay (ms).
+	entrance_delay: u16,
+
+	/// # Exit Odds (1/n).
+	exit_odds: u16,
+
+	/// # Walk Odds (1/n).
+	walk_odds: u16,
+
+	/// # Default Animation Weights.
+	weights: [u16; Animation::DEFAULT_CHOICES.len()],
+
+	/// # Entrance Animation Weights.
+	entrance_weights: [u16; Animation::ENTRANCE_CHOICES.len()],
+
+	/// # Transition Weights.
+	transition_weights: [u16; Animation::TRANSITION_CHOICES.len()],
+
+	/// # Excluded Animations (Bitset).
+	excluded: [u64; 2],
+}
+
+impl Default for Config {
+	fn default() -> Self {
+		Self {
+			entrance_delay: Universe::DEFAULT_ENTRANCE_DELAY,
+			exit_odds: Universe::DEFAULT_EXIT_ODDS,
+			walk_odds: Universe::DEFAULT_WALK_ODDS,
+			weights: Animation::DEFAULT_WEIGHTS,
+			entrance_weights: Animation::ENTRANCE_WEIGHTS,
+			transition_weights: Animation::TRANSITION_WEIGHTS,
+			excluded: [0, 0],
+		}
+	}
+}
+
+impl TryFrom<&JsValue> for Config {
+	type Error = String;
+
+	fn try_from(src: &JsValue) -> Result<Self, Self::Error> {
+		let mut out = Self::default();
+
+		// Nothing is the same as the defaults.
+		if src.is_undefined() || src.is_null() { return Ok(out); }
+
+		for (k, v) in dom::entries(src).ok_or("Configuration must be an object.")? {
+			match k.as_str() {
+				"entranceDelay" => {
+					out.entrance_delay = number(&k, &v, Self::MAX_ENTRANCE_DELAY)?;
+				},
+				"exitOdds" => { out.exit_odds = number(&k, &v, Self::MAX_ODDS)?; },
+				"walkOdds" => { out.walk_odds = number(&k, &v, Self::MAX_ODDS)?; },
+				"weights" => {
+					weights(&k, &v, &Animation::DEFAULT_CHOICES, &mut out.weights)?;
+				},
+				"entranceWeights" => {
+					weights(&k, &v, &Animation::ENTRANCE_CHOICES, &mut out.entrance_weights)?;
+				},
+				"nextWeights" => {
+					transition_weights(&k, &v, false, &mut out.transition_weights)?;
+				},
+				"nextEdgeWeights" => {
+					transition_weights(&k, &v, true, &mut out.transition_weights)?;
+				},
+				"exclude" => { out.excluded = excluded(&k, &v)?; },
+				_ => return Err(format!("Unknown option: {k}.")),
+			}
+		}
+
+		Ok(out)
+	}
+}
+
+impl Config {
+	/// # Maximum Entrance Delay (ms).
+	const MAX_ENTRANCE_DELAY: u16 = 10_000;
+
+	/// # Maximum Odds.
+	const MAX_ODDS: u16 = 1000;
+
+	/// # Maximum Weight.
+	///
+	/// Note: the weights are summed as `u16` during selection, so this needs
+	/// to be small enough to keep that from overflowing.
+	const MAX_WEIGHT: u16 = 1000;
+
+	/// # Apply.
+	///
+	/// Push the settings to the [`Universe`] and [`Animation`] choosers.
+	pub(crate) fn apply(&self) {
+		Universe::set_tunables(self.entrance_delay, self.exit_odds, self.walk_odds);
+		Animation::set_default_weights(&self.weights);
+		Animation::set_entrance_weights(&self.entrance_weights);
+		Animation::set_transition_weights(&self.transition_weights);
+		Animation::set_excluded(self.excluded);
+	}
+
+	/// # Set Excluded Animations.
+	///
+	/// Replace the exclusion list with the parsed contents of `src`, an array
+	/// of animation names and/or presets.
+	///
+	/// ## Errors
+	///
+	/// An error is returned if the list is invalid.
+	pub(crate) fn set_excluded(&mut self, src: &JsValue) -> Result<(), String> {
+		self.excluded = excluded("exclude", src)?;
+		Ok(())
+	}
+}
+
+
+
+/// # Excluded Animations.
+///
+/// Parse an array of animation names into an exclusion bitset. In addition
+/// to the excludable animations, the following presets are supported:
+///
+/// | Preset | Animations |
+/// | ------ | ---------- |
+/// | `"family"` | `Abduction`, `Glitch`, `ShadowShowdown`, `Urinate` |
+fn excluded(key: &str, src: &JsValue) -> Result<[u64; 2], String> {
+	if ! Array::is_array(src) { return Err(format!("{key} must be an array.")); }
+
+	let mut out = [0_u64; 2];
+	for v in src.unchecked_ref::<Array>().iter() {
+		let Some(k) = v.as_string() else {
+			return Err(format!("{key} must be an array of strings."));
+		};
+
+		if k == "family" {
+			for a in Animation::FAMILY { exclude(&mut out, a); }
+		}
+		else {
+			let Some(a) = Animation::from_name(&k) else {
+				return Err(format!("{key}: unknown animation {k}."));
+			};
+			if ! a.excludable() {
+				return Err(format!("{key}: {k} cannot be excluded."));
+			}
+			exclude(&mut out, a);
+		}
+	}
+
+	Ok(out)
+}
+
+/// # Exclude Animation.
+///
+/// Add an animation to an exclusion bitset.
+const fn exclude(set: &mut [u64; 2], animation: Animation) {
+	let (idx, bit) = animation.exclusion_bit();
+	set[idx] |= bit;
+}
+
+/// # Number.
+///
+/// Parse a numeric value, rounding and clamping it to `0..=max`.
+pub(crate) fn number(key: &str, src: &JsValue, max: u16) -> Result<u16, String> {
+	src.as_f64()
+		.and_then(|n| clamp_number(n, max))
+		.ok_or_else(|| format!("{key} must be a number."))
+}
+
+#[expect(
+	clippy::cast_possible_truncation,
+	clippy::cast_sign_loss,
+	reason = "False positive.",
+)]
+/// # Clamp Number.
+///
+/// Round and clamp a float to `0..=max`, returning `None` if it isn't finite.
+fn clamp_number(src: f64, max: u16) -> Option<u16> {
+	if src.is_finite() { Some(src.round().clamp(0.0, f64::from(max)) as u16) }
+	else { None }
+}
+
+/// # Weights.
+///
+/// Parse an object of animation names and weights, updating the matching
+/// entries in `out`. Animations must be part of the relevant `set`.
+fn weights<const N: usize>(
+	key: &str,
+	src: &JsValue,
+	set: &[Animation; N],
+	out: &mut [u16; N],
+) -> Result<(), String> {
+	for (k, v) in dom::entries(src).ok_or_else(|| format!("{key} must be an object."))? {
+		let Some(a) = Animation::from_name(&k) else {
+			return Err(format!("{key}: unknown animation {k}."));
+		};
+		let Some(idx) = set.iter().position(|b| a == *b) else {
+			return Err(format!("{key}: {k} is not eligible."));
+		};
+		out[idx] = number(&format!("{key}.{k}"), &v, Config::MAX_WEIGHT)?;
+	}
+
+	Ok(())
+}
+
+
+/// # Transition Weights.
+///
+/// Parse an object of animation names and successor weights — e.g.
+/// `{ Run: { Hydroplane: 5 } }` — updating the matching entries in `out`.
+/// Only the built-in successors may be reweighted, and each table must keep
+/// at least one non-zero weight.
+fn transition_weights<const N: usize>(
+	key: &str,
+	src: &JsValue,
+	edge: bool,
+	out: &mut [u16; N],
+) -> Result<(), String> {
+	for (k, v) in dom::entries(src).ok_or_else(|| format!("{key} must be an object."))? {
+		let Some(a) = Animation::from_name(&k) else {
+			return Err(format!("{key}: unknown animation {k}."));
+		};
+		let Some(range) = (if edge { a.next_edge_table() } else { a.next_table() }) else {
+			return Err(format!("{key}: {k} has no transitions."));
+		};
+
+		let set = &Animation::TRANSITION_CHOICES[range.clone()];
+		let out = &mut out[range];
+		for (k2, v2) in dom::entries(&v).ok_or_else(|| format!("{key}.{k} must be an object."))? {
+			let Some(idx) = Animation::from_name(&k2).and_then(|b| set.iter().position(|c| b == *c)) else {
+				return Err(format!("{key}.{k}: {k2} is not a successor."));
+			};
+			out[idx] = number(&format!("{key}.{k}.{k2}"), &v2, Config::MAX_WEIGHT)?;
+		}
+
+		if out.iter().all(|w| *w == 0) {
+			return Err(format!("{key}.{k} must have at least one non-zero weight."));
+		}
+	}
+
+	Ok(())
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use wasm_bindgen_test::*;
+
+	#[wasm_bindgen_test]
+	fn t_clamp_number() {
+		assert_eq!(clamp_number(3.0, 10), Some(3));
+		assert_eq!(clamp_number(3.4, 10), Some(3));
+		assert_eq!(clamp_number(3.5, 10), Some(4));
+		assert_eq!(clamp_number(-3.0, 10), Some(0));
+		assert_eq!(clamp_number(30.0, 10), Some(10));
+		assert_eq!(clamp_number(f64::INFINITY, 10), None);
+		assert_eq!(clamp_number(f64::NAN, 10), None);
+	}
+
+	#[wasm_bindgen_test]
+	fn t_max_weight() {
+		// Make sure the weights can't overflow.
+		let max = usize::from(Config::MAX_WEIGHT);
+		assert!(
+			max * Animation::DEFAULT_CHOICES.len() < usize::from(u16::MAX),
+			"Default weights could overflow.",
+		);
+		assert!(
+			max * Animation::ENTRANCE_CHOICES.len() < usize::from(u16::MAX),
+			"Entrance weights could overflow.",
+		);
+
+		let longest = Animation::ALL.into_iter()
+			.flat_map(|a| [a.next_table(), a.next_edge_table()])
+			.flatten()
+			.map(|r| r.len())
+			.max()
+			.unwrap_or(0);
+		assert!(
+			max * longest < usize::from(u16::MAX),
+			"Transition weights could overflow.",
+		);
+	}
+}
@@ -2,7 +2,15 @@
 # RS Mate Poe: DOM
 */
 
-#[cfg(feature = "director")] use wasm_bindgen::prelude::*;
+use js_sys::{
+	Array,
+	Object,
+	Reflect,
+};
+use wasm_bindgen::{
+	JsCast,
+	prelude::*,
+};
 use web_sys::{
 	Document,
 	Element,
@@ -53,6 +61,45 @@ pub(crate) fn document_element() -> Option<Element> {
 		.and_then(|d| d.document_element())
 }
 
+/// # Now.
+///
+/// Return the current high-resolution timestamp in milliseconds, the same
+/// clock used by `requestAnimationFrame`.
+pub(crate) fn now() -> Option<f64> {
+	web_sys::window()
+		.and_then(|w| w.performance())
+		.map(|p| p.now())
+}
+
+/// # Plain Object.
+///
+/// Build a plain Javascript object from key/value pairs.
+pub(crate) fn object<const N: usize>(entries: [(&str, JsValue); N]) -> Object {
+	let out = Object::new();
+	for (k, v) in entries {
+		let _res = Reflect::set(&out, &JsValue::from_str(k), &v).ok();
+	}
+	out
+}
+
+/// # Object Entries.
+///
+/// Return the key/value pairs of a plain Javascript object, or `None` if the
+/// value isn't a (non-array) object.
+pub(crate) fn entries(src: &JsValue) -> Option<Vec<(String, JsValue)>> {
+	if ! src.is_object() || Array::is_array(src) { return None; }
+
+	Some(
+		Object::entries(src.unchecked_ref())
+			.iter()
+			.filter_map(|e| {
+				let e: Array = e.dyn_into().ok()?;
+				Some((e.get(0).as_string()?, e.get(1)))
+			})
+			.collect()
+	)
+}
+
 /// # Is Quirks?
 ///
 /// Returns `true` if the page is operating in "quirks" mode.
@@ -0,0 +1,317 @@
+/*!
+# RS Mate Poe: Initialization
+*/
+
+use crate::{
+	config,
+	Config,
+	dom,
+	Skin,
+	Universe,
+};
+use js_sys::{
+	Array,
+	JSON,
+};
+use wasm_bindgen::{
+	JsCast,
+	prelude::*,
+};
+use web_sys::Blob;
+
+
+
+#[derive(Debug, Default)]
+/// # Initialization Options.
+///
+/// This holds the options passed to `Poe.init`, typically straight from the
+/// loader script's `dataset`, so values may be given either natively or as
+/// strings:
+///
+/// | Key | Description | Example |
+/// | --- | ----------- | ------- |
+/// | `audio` / `noAudio` | Enable/disable audio. | `true` |
+/// | `config` | Tunables; see [`Config`]. | `{"walkOdds":5}` |
+/// | `exclude` | Animations (or presets) to never choose. | `"family, Yoyo"` |
+/// | `focus` / `noFocus` | Enable/disable click-and-drag. | `true` |
+/// | `insets` | Screen margins to keep clear of, CSS-style. | `"60 0 0"` |
+/// | `interpolate` / `noInterpolate` | Enable/disable smooth movement between steps. | `true` |
+/// | `seed` | A fixed PRNG seed; zero for random. | `12345` |
+/// | `skin` | A custom sprite sheet URL or `Blob`. | `"/poe.png"` |
+/// | `speed` | Playback speed multiplier (`0.01..=10`). | `1.5` |
+/// | `start` / `noStart` | Start Poe right away. | `true` |
+/// | `volume` | Master volume (`0..=1`). | `0.5` |
+///
+/// For the `no*` flags, as with HTML attributes, presence alone — an empty
+/// string — counts as `true`.
+///
+/// Unknown keys are collected rather than rejected — the `dataset` may well
+/// contain attributes meant for something else — but invalid values for known
+/// keys result in an error. Parsing is all-or-nothing.
+pub(crate) struct Init {
+	/// # Audio.
+	audio: Option<bool>,
+
+	/// # Configuration.
+	config: Option<Config>,
+
+	/// # Focus.
+	focus: Option<bool>,
+
+	/// # Insets.
+	insets: Option<[u16; 4]>,
+
+	/// # Interpolate.
+	interpolate: Option<bool>,
+
+	/// # Seed.
+	seed: Option<u64>,
+
+	/// # Skin.
+	skin: Option<JsValue>,
+
+	/// # Speed.
+	speed: Option<f32>,
+
+	/// # Start.
+	start: Option<bool>,
+
+	/// # Volume.
+	volume: Option<f32>,
+
+	/// # Unknown Keys.
+	unknown: Vec<String>,
+}
+
+impl TryFrom<&JsValue> for Init {
+	type Error = String;
+
+	fn try_from(src: &JsValue) -> Result<Self, Self::Error> {
+		let mut out = Self::default();
+
+		// Nothing is the same as the defaults.
+		if src.is_undefined() || src.is_null() { return Ok(out); }
+
+		let mut exclude = None;
+		for (k, v) in dom::entries(src).ok_or("Options must be an object.")? {
+			match k.as_str() {
+				"audio" => { out.audio.replace(flag(&k, &v)?); },
+				"noAudio" => { out.audio.replace(! flag(&k, &v)?); },
+				"config" => { out.config.replace(parse_config(&v)?); },
+				"exclude" => { exclude.replace(v); },
+				"focus" => { out.focus.replace(flag(&k, &v)?); },
+				"noFocus" => { out.focus.replace(! flag(&k, &v)?); },
+				"insets" => { out.insets.replace(insets(&k, &v)?); },
+				"interpolate" => { out.interpolate.replace(flag(&k, &v)?); },
+				"noInterpolate" => { out.interpolate.replace(! flag(&k, &v)?); },
+				"seed" => { out.seed.replace(seed(&k, &v)?); },
+				"skin" => {
+					if v.is_null() || v.is_string() || v.is_instance_of::<Blob>() {
+						out.skin.replace(v);
+					}
+					else { return Err("skin must be a URL or Blob.".to_owned()); }
+				},
+				"speed" => {
+					let n = speed(float(&k, &v)?).ok_or("speed must be between 0.01 and 10.")?;
+					out.speed.replace(n);
+				},
+				"start" => { out.start.replace(flag(&k, &v)?); },
+				"noStart" => { out.start.replace(! flag(&k, &v)?); },
+				"volume" => { out.volume.replace(float(&k, &v)?); },
+				_ => { out.unknown.push(k); },
+			}
+		}
+
+		// Exclusions get merged into the configuration.
+		if let Some(v) = exclude {
+			let v = v.as_string().map_or(v, |s|
+				s.split(|c: char| c == ',' || c.is_whitespace())
+					.filter(|s| ! s.is_empty())
+					.map(JsValue::from_str)
+					.collect::<Array>()
+					.into()
+			);
+			out.config.get_or_insert_default().set_excluded(&v)?;
+		}
+
+		Ok(out)
+	}
+}
+
+impl Init {
+	/// # Apply.
+	///
+	/// Apply the settings — starting Poe unless told not to — and return the
+	/// list of unrecognized keys, if any.
+	pub(crate) fn apply(self) -> Array {
+		if let Some(c) = self.config { c.apply(); }
+		if let Some(v) = self.audio { Universe::set_audio(v); }
+		if let Some(v) = self.focus { Universe::set_no_focus(! v); }
+		if let Some(v) = self.insets { Universe::set_insets(v); }
+		if let Some(v) = self.interpolate { Universe::set_interpolate(v); }
+		if let Some(v) = self.seed { Universe::set_seed(v); }
+		if let Some(v) = self.speed { Universe::set_speed(v); }
+		if let Some(v) = self.volume { Universe::set_volume(v); }
+		if let Some(v) = self.skin { let _res = Skin::set(&v); }
+		if self.start.unwrap_or(true) { Universe::set_active(true); }
+
+		self.unknown.into_iter().map(JsValue::from).collect()
+	}
+}
+
+
+
+/// # Flag.
+///
+/// Parse a boolean, or a string like `""`, `"true"`, or `"false"`.
+fn flag(key: &str, src: &JsValue) -> Result<bool, String> {
+	if let Some(b) = src.as_bool() { return Ok(b); }
+	match src.as_string().as_deref().map(str::trim) {
+		Some("" | "1" | "true") => Ok(true),
+		Some("0" | "false") => Ok(false),
+		_ => Err(format!("{key} must be a boolean.")),
+	}
+}
+
+#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+/// # Float.
+///
+/// Parse a finite number, or a string containing one.
+fn float(key: &str, src: &JsValue) -> Result<f32, String> {
+	src.as_f64()
+		.or_else(|| src.as_string().and_then(|s| s.trim().parse::<f64>().ok()))
+		.filter(|n| n.is_finite())
+		.map(|n| n as f32)
+		.ok_or_else(|| format!("{key} must be a number."))
+}
+
+/// # Speed.
+///
+/// Return the playback speed if it is within `0.01..=10`. Unlike
+/// `Poe.speed`, zero isn't allowed here, as there'd be no way to undo it
+/// outside director builds.
+fn speed(n: f32) -> Option<f32> {
+	if (0.01..=10.0).contains(&n) { Some(n) }
+	else { None }
+}
+
+/// # Insets.
+///
+/// Parse CSS-style shorthand — one to four numbers for the top, right,
+/// bottom, and left, in that order — given as a number, array, or
+/// space-separated string (`px` units are allowed), or an object with
+/// explicit `top`, `right`, `bottom`, and/or `left` keys.
+fn insets(key: &str, src: &JsValue) -> Result<[u16; 4], String> {
+	// An object with named sides.
+	if src.is_object() && ! Array::is_array(src) {
+		let mut out = [0_u16; 4];
+		for (k, v) in dom::entries(src).ok_or_else(|| format!("{key} must be an object."))? {
+			let idx = match k.as_str() {
+				"top" => 0,
+				"right" => 1,
+				"bottom" => 2,
+				"left" => 3,
+				_ => return Err(format!("{key}: unknown side {k}.")),
+			};
+			out[idx] = config::number(&format!("{key}.{k}"), &v, u16::MAX)?;
+		}
+		return Ok(out);
+	}
+
+	// Otherwise collect a list of numbers.
+	let list: Vec<JsValue> = src.as_string().map_or_else(
+		|| {
+			if Array::is_array(src) { src.unchecked_ref::<Array>().iter().collect() }
+			else { vec![src.clone()] }
+		},
+		|s| s.split_whitespace()
+			.map(|s| JsValue::from_str(s.strip_suffix("px").unwrap_or(s)))
+			.collect(),
+	);
+
+	let mut nums = Vec::with_capacity(list.len());
+	for v in list {
+		let n = float(key, &v)?;
+		nums.push(config::number(key, &JsValue::from(n), u16::MAX)?);
+	}
+
+	shorthand(&nums).ok_or_else(|| format!("{key} must have one to four values."))
+}
+
+/// # Parse Configuration.
+///
+/// Parse a [`Config`] from an object or JSON string.
+fn parse_config(src: &JsValue) -> Result<Config, String> {
+	if let Some(s) = src.as_string() {
+		let v = JSON::parse(&s).map_err(|_| "config must be valid JSON.".to_owned())?;
+		Config::try_from(&v)
+	}
+	else { Config::try_from(src) }
+}
+
+#[expect(
+	clippy::cast_possible_truncation,
+	clippy::cast_sign_loss,
+	reason = "False positive.",
+)]
+/// # Seed.
+///
+/// Parse a non-negative integer — or a string containing one, decimal or
+/// `0x`-prefixed hex — for use as a PRNG seed. Numbers are limited to the
+/// "safe" integer range; larger seeds must be passed as strings.
+fn seed(key: &str, src: &JsValue) -> Result<u64, String> {
+	/// # Maximum Safe Integer.
+	const MAX_SAFE: f64 = 9_007_199_254_740_991.0;
+
+	if let Some(n) = src.as_f64() {
+		if n.fract() == 0.0 && (0.0..=MAX_SAFE).contains(&n) { return Ok(n as u64); }
+	}
+	else if let Some(s) = src.as_string() {
+		let s = s.trim();
+		let res = s.strip_prefix("0x")
+			.or_else(|| s.strip_prefix("0X"))
+			.map_or_else(|| s.parse::<u64>(), |hex| u64::from_str_radix(hex, 16));
+		if let Ok(n) = res { return Ok(n); }
+	}
+
+	Err(format!("{key} must be a non-negative integer."))
+}
+
+/// # Expand Shorthand.
+///
+/// Expand one to four CSS-style values into top, right, bottom, left.
+const fn shorthand(src: &[u16]) -> Option<[u16; 4]> {
+	match *src {
+		[a] => Some([a, a, a, a]),
+		[a, b] => Some([a, b, a, b]),
+		[a, b, c] => Some([a, b, c, b]),
+		[a, b, c, d] => Some([a, b, c, d]),
+		_ => None,
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use wasm_bindgen_test::*;
+
+	#[wasm_bindgen_test]
+	fn t_shorthand() {
+		assert_eq!(shorthand(&[1]), Some([1, 1, 1, 1]));
+		assert_eq!(shorthand(&[1, 2]), Some([1, 2, 1, 2]));
+		assert_eq!(shorthand(&[1, 2, 3]), Some([1, 2, 3, 2]));
+		assert_eq!(shorthand(&[1, 2, 3, 4]), Some([1, 2, 3, 4]));
+		assert_eq!(shorthand(&[]), None);
+		assert_eq!(shorthand(&[1, 2, 3, 4, 5]), None);
+	}
+
+	#[wasm_bindgen_test]
+	fn t_speed() {
+		for n in [0.01, 0.5, 1.0, 10.0] { assert_eq!(speed(n), Some(n)); }
+		for n in [0.0, -0.0, 0.001, -1.0, 10.01, f32::NAN, f32::INFINITY, f32::NEG_INFINITY] {
+			assert_eq!(speed(n), None, "Speed {n} should be rejected.");
+		}
+	}
+}
@@ -53,10 +53,14 @@
 #![expect(clippy::redundant_pub_crate, reason = "Unresolvable.")]
 
 mod animation;
+mod config;
 mod css;
 pub(crate) mod dom;
+mod init;
 mod mate;
 mod position;
+mod queue;
+mod skin;
 mod state;
 mod universe;
 
@@ -65,6 +69,12 @@ mod universe;
 use animation::{
 	Animation,
 	frame::Frame,
+	pack::{
+		Pack,
+		PackStart,
+		StartX,
+		StartY,
+	},
 	scene::{
 		Scene,
 		SceneList,
@@ -72,12 +82,21 @@ use animation::{
 	},
 	sound::Sound,
 };
+#[cfg(feature = "director")] use animation::custom::CustomScenes;
+use config::Config;
 use css::CssPropertyBuffer;
-use mate::Mate;
+use init::Init;
+use mate::{
+	Bubble,
+	Goal,
+	Mate,
+};
 use position::{
 	Direction,
 	Position,
 };
+use queue::Queue;
+use skin::Skin;
 use state::{
 	State,
 	StateAudio,
@@ -94,7 +113,7 @@ include!(concat!(env!("OUT_DIR"), "/media.rs"));
 
 
 #[cfg(feature = "director")]
-#[wasm_bindgen(start)]
+#[wasm_bindgen(start, skip_typescript)]
 /// # Bootstrap.
 ///
 /// Print library details after initialization.
@@ -108,13 +127,22 @@ pub fn bootstrap() {
 
 	// Print the playlist.
 	web_sys::console::info_2(
-		&JsValue::from_str(concat!("%c", include_str!("../skel/playlist.txt"))),
+		&JsValue::from(format!("%c{}", Animation::PLAYLIST)),
 		&JsValue::from_str("color:#b2bec3;font-family:monospace;"),
 	);
 }
 
 
 
+#[wasm_bindgen(typescript_custom_section)]
+/// # Typescript Types.
+///
+/// The interfaces and aliases referenced by the `Poe` declarations, which
+/// wasm-bindgen generates from the exports below.
+const TS_TYPES: &str = include_str!("../skel/js/poe.d.ts");
+
+
+
 #[wasm_bindgen]
 #[derive(Debug, Clone, Copy, Default)]
 /// # Public Interface.
@@ -136,6 +164,37 @@ impl Poe {
 	/// Return `true` if active, or `false` if not.
 	pub fn active() -> bool { Universe::active() }
 
+	#[wasm_bindgen(getter, unchecked_return_type = "PoeAnimation[]")]
+	#[must_use]
+	/// # Animation Catalog.
+	///
+	/// Return an array describing every animation — primary and child alike —
+	/// as plain objects with the following keys:
+	///
+	/// | Key | Type | Description |
+	/// | --- | ---- | ----------- |
+	/// | `id` | `number` | The animation ID. |
+	/// | `name` | `string` | The (code) name, as used by `Poe.configure`. |
+	/// | `title` | `string` | A human-readable title. |
+	/// | `primary` | `bool` | True for primary animations, false for child ones. |
+	/// | `child` | `number\|null` | The ID of the accompanying child animation, if any. |
+	/// | `playable` | `bool` | True if the animation can be cued directly. |
+	/// | `mayExit` | `bool` | True if the animation is allowed to leave the screen. |
+	/// | `duration` | `number` | The length of a single pass in milliseconds. |
+	/// | `next` | `[string, number][]` | The weighted successors when the sequence finishes. |
+	/// | `nextEdge` | `[string, number][]` | The weighted successors when a screen edge is reached. |
+	/// | `scenes` | `string` | The scenes as scene text (see `Poe.loadPack`). |
+	///
+	/// Some animations scale with the screen size; their durations reflect
+	/// the current (or most recent) one, while their scene text spells out
+	/// the scaled repeat counts as formulas.
+	///
+	/// Any animations loaded via `Poe.loadPack` are included at the end.
+	pub fn animations() -> js_sys::Array {
+		let (width, height) = Universe::size();
+		Animation::all().map(|a| a.catalog_entry(width, height)).collect()
+	}
+
 	#[wasm_bindgen(getter)]
 	#[must_use]
 	/// # Audio Allowed?
@@ -143,6 +202,20 @@ impl Poe {
 	/// Return `true` if audio playback is allowed, or `false` if not.
 	pub fn audio() -> bool { Universe::audio() }
 
+	#[wasm_bindgen(getter, unchecked_return_type = "PoeSound[]")]
+	#[must_use]
+	/// # Muted Sounds.
+	///
+	/// Return the names of any individually-muted sounds.
+	pub fn muted() -> js_sys::Array { Sound::set_to_array(Universe::muted_set()) }
+
+	#[wasm_bindgen(getter)]
+	#[must_use]
+	/// # Volume.
+	///
+	/// Return the master volume, from `0.0` to `1.0`.
+	pub fn volume() -> f32 { Universe::volume() }
+
 	#[wasm_bindgen(getter)]
 	#[must_use]
 	/// # Is Focus/Dragging Allowed?
@@ -151,6 +224,51 @@ impl Poe {
 	/// `false` if not.
 	pub fn focus() -> bool { ! Universe::no_focus() }
 
+	#[wasm_bindgen(getter)]
+	#[must_use]
+	/// # Interpolate Movement?
+	///
+	/// Return `true` if movements are smoothed out between steps, or `false`
+	/// if Poe jumps from one step to the next like the original.
+	pub fn interpolate() -> bool { Universe::interpolate() }
+
+	#[wasm_bindgen(getter, unchecked_return_type = "PoeMateState[] | null")]
+	#[must_use]
+	/// # State Snapshot.
+	///
+	/// Return an array describing the current state of each mate — primary,
+	/// then child — as plain objects with the following keys, or `null` if
+	/// Poe isn't running:
+	///
+	/// | Key | Type | Description |
+	/// | --- | ---- | ----------- |
+	/// | `primary` | `bool` | True for the primary mate. |
+	/// | `animation` | `number\|null` | The current animation ID, if any. |
+	/// | `name` | `string\|null` | The current animation name, if any. |
+	/// | `frame` | `number\|null` | The current sprite frame, if any. |
+	/// | `x` | `number` | The horizontal position in pixels. |
+	/// | `y` | `number` | The vertical position in pixels. |
+	/// | `flipped` | `bool` | True if flipped horizontally. |
+	/// | `gravity` | `bool` | True if gravity applies. |
+	/// | `ignoreEdges` | `bool` | True if screen edges are being ignored. |
+	/// | `dragging` | `bool` | True if being dragged. |
+	/// | `step` | `number\|null` | The current step index within the animation, if any. |
+	/// | `nextTick` | `number` | Milliseconds until the next tick. |
+	/// | `next` | `number\|null` | The queued next animation ID, if any. |
+	/// | `nextName` | `string\|null` | The queued next animation name, if any. |
+	/// | `custom` | `bool` | True while `Poe.inject` scenes are playing. (Always `false` outside director builds.) |
+	///
+	/// This is a copy; changing it has no effect on Poe.
+	pub fn state() -> JsValue { State::snapshot() }
+
+	#[cfg(feature = "director")]
+	#[wasm_bindgen(getter)]
+	#[must_use]
+	/// # Debug Overlay?
+	///
+	/// Return `true` if the debug overlay is enabled.
+	pub fn debug() -> bool { Universe::debug() }
+
 	#[cfg(feature = "director")]
 	#[wasm_bindgen(getter)]
 	#[must_use]
@@ -176,12 +294,225 @@ impl Poe {
 	/// to the document body.
 	pub fn fix_bindings() { Universe::set_fix_bindings(); }
 
+	#[wasm_bindgen]
+	/// # Configure.
+	///
+	/// Override one or more of the default tunables — animation and
+	/// transition weights, walk/exit odds, entrance delay, excluded
+	/// animations — with the values from a plain object. Omitted values
+	/// revert to their defaults, as does calling this method without an
+	/// argument.
+	///
+	/// ## Errors
+	///
+	/// An error is thrown if the configuration is invalid, in which case
+	/// nothing is changed.
+	pub fn configure(
+		#[wasm_bindgen(unchecked_optional_param_type = "PoeConfig | null")] cfg: &JsValue,
+	) -> Result<(), JsError> {
+		let cfg = Config::try_from(cfg).map_err(|e| JsError::new(&e))?;
+		cfg.apply();
+		Ok(())
+	}
+
+	#[wasm_bindgen(unchecked_return_type = "string[]")]
+	/// # Initialize.
+	///
+	/// Apply a batch of startup options — typically the loader script's
+	/// `dataset` — and start Poe unless `noStart` (or `start: false`) is
+	/// given. The supported keys are `audio`/`noAudio`, `config`, `exclude`,
+	/// `focus`/`noFocus`, `insets`, `interpolate`/`noInterpolate`, `seed`,
+	/// `skin`, `speed` (`0.01..=10`), `start`/`noStart`, and `volume`.
+	///
+	/// Returns an array of any unrecognized keys, which are otherwise
+	/// ignored.
+	///
+	/// ## Errors
+	///
+	/// An error is thrown if any of the values are invalid, in which case
+	/// nothing is changed.
+	pub fn init(
+		#[wasm_bindgen(unchecked_optional_param_type = "PoeInit | DOMStringMap | null")] opts: &JsValue,
+	) -> Result<js_sys::Array, JsError> {
+		let init = Init::try_from(opts).map_err(|e| JsError::new(&e))?;
+		Ok(init.apply())
+	}
+
+	#[wasm_bindgen(js_name = "loadPack")]
+	/// # Load Animation Pack.
+	///
+	/// Register new animations at runtime, without a rebuild. The pack is
+	/// given as an array of objects — or a JSON string encoding one — with
+	/// the following keys:
+	///
+	/// | Key | Type | Description | Required |
+	/// | --- | ---- | ----------- | -------- |
+	/// | `name` | `string` | A unique (code) name, e.g. `MoonWalk`. | Yes |
+	/// | `title` | `string` | A human-readable title. Defaults to the name. | |
+	/// | `kind` | `string` | `"playable"`, `"support"`, or `"child"`. Defaults to `"playable"`. | |
+	/// | `scenes` | `object[]\|string` | The scenes, in the same format as `Poe.inject`, or as scene text. | Yes |
+	/// | `next` | `string\|[string, number][]` | The successor(s) when the sequence finishes. | |
+	/// | `nextEdge` | `string\|[string, number][]` | The successor(s) when a screen edge is reached. | |
+	/// | `weight` | `number` | The weight within the default pool. Defaults to `0` (never chosen). | |
+	/// | `child` | `string` | The accompanying child animation. (Primary only.) | |
+	/// | `start` | `object` | The starting `x` (`"left"`, `"right"`, `"random"`, or a number) and `y` (`"top"`, `"bottom"`, or a number). | |
+	/// | `offset` | `[number, number]` | The starting position relative to the primary mate. (Child only.) | |
+	/// | `flipX` | `bool` | Flip the sprite horizontally. | |
+	/// | `mayExit` | `bool` | Allow the animation to leave the screen. | |
+	/// | `smooth` | `bool` | Apply transform smoothing. | |
+	/// | `minWidth` | `number` | The minimum screen width required for automatic selection. (Primary only.) | |
+	/// | `minHeight` | `number` | The minimum screen height required for automatic selection. (Primary only.) | |
+	///
+	/// Scene text puts one scene per line, e.g. `100 F002 F003 move=-2,0
+	/// repeat=(w+40)/32@0 gravity`; repeat counts may be formulas of the
+	/// screen width `w` and height `h`.
+	///
+	/// Names referenced by `next`, `nextEdge`, and `child` can be built-in
+	/// animations, previously-loaded ones, or others in the same pack.
+	///
+	/// Loaded animations are permanent, and receive IDs following the
+	/// built-in ones. They can be played, queued, and excluded just like
+	/// the rest.
+	///
+	/// ## Errors
+	///
+	/// An error is thrown if anything in the pack is invalid, in which case
+	/// none of it is loaded.
+	pub fn load_pack(
+		#[wasm_bindgen(unchecked_param_type = "PoePackAnimation[] | string")] pack: &JsValue,
+	) -> Result<(), JsError> {
+		Pack::load(pack).map_err(|e| JsError::new(&e))
+	}
+
+	#[wasm_bindgen(js_name = "moveTo")]
+	/// # Move To.
+	///
+	/// Teleport the primary mate to the given X/Y coordinates — the top-left
+	/// corner of the sprite — with a beam-in effect. Coordinates are clamped
+	/// to the screen, and gravity applies as usual once the beam is done.
+	///
+	/// This is ignored if Poe is inactive.
+	pub fn move_to(x: i32, y: i32) {
+		Universe::set_goal(Goal::MoveTo(Position::new(x, y)));
+	}
+
+	#[wasm_bindgen(js_name = "walkTo")]
+	/// # Walk To.
+	///
+	/// Have the primary mate walk — or run, if it's far — along the floor to
+	/// the given X coordinate (the left edge of the sprite), turning around
+	/// first if needed. Once there, Poe will idle for a moment and then
+	/// return to its usual business.
+	///
+	/// If Poe is in the middle of something off the floor, like climbing a
+	/// wall, the journey will begin once it has landed.
+	///
+	/// This is ignored if Poe is inactive.
+	pub fn walk_to(x: i32) { Universe::set_goal(Goal::WalkTo(x)); }
+
+	#[wasm_bindgen(unchecked_return_type = "Promise<boolean>")]
+	/// # Queue Animations.
+	///
+	/// Play a list of directly-playable animations — by ID or name — back to
+	/// back, replacing any previous queue. Once the list runs out, Poe will
+	/// either return to its usual random behaviors or, if `then` is `"stop"`,
+	/// deactivate.
+	///
+	/// The optional second argument accepts the following options:
+	///
+	/// | Key | Type | Description | Default |
+	/// | --- | ---- | ----------- | ------- |
+	/// | `loop` | `bool` | Repeat the list indefinitely. | `false` |
+	/// | `then` | `string` | What to do afterward: `"resume"` or `"stop"`. | `"resume"` |
+	///
+	/// The returned promise resolves to `true` once the list has finished,
+	/// or `false` if it was cut short by a new queue or deactivation.
+	///
+	/// ## Errors
+	///
+	/// An error is thrown if the list or options are invalid, in which case
+	/// nothing is changed.
+	pub fn queue(
+		#[wasm_bindgen(unchecked_param_type = "PoeAnimationRef[]")] list: &JsValue,
+		#[wasm_bindgen(unchecked_optional_param_type = "PoeQueueOptions | null")] opts: &JsValue,
+	) -> Result<js_sys::Promise, JsError> {
+		Queue::set(list, opts).map_err(|e| JsError::new(&e))
+	}
+
+	#[wasm_bindgen]
+	/// # Say.
+	///
+	/// Show the text in a speech bubble above the primary mate, replacing any
+	/// previous message. The bubble follows the sprite around, pauses any
+	/// walking or running while shown, and is dismissed when clicked or once
+	/// its time is up. Empty text dismisses it immediately.
+	///
+	/// The text is always treated as plain text, never HTML.
+	///
+	/// The optional second argument accepts the following options:
+	///
+	/// | Key | Type | Description | Default |
+	/// | --- | ---- | ----------- | ------- |
+	/// | `duration` | `number` | Display time in milliseconds (`500..=60000`). | Based on length. |
+	///
+	/// Returns `false` if Poe is inactive.
+	///
+	/// ## Errors
+	///
+	/// An error is thrown if the options are invalid.
+	pub fn say(
+		text: &str,
+		#[wasm_bindgen(unchecked_optional_param_type = "PoeSayOptions | null")] opts: &JsValue,
+	) -> Result<bool, JsError> {
+		State::say(text, opts).map_err(|e| JsError::new(&e))
+	}
+
 	#[wasm_bindgen(setter)]
 	/// # Set Active.
 	///
 	/// Enable or disable Poe.
 	pub fn set_active(v: bool) { Universe::set_active(v); }
 
+	#[wasm_bindgen(setter)]
+	/// # Set Muted Sounds.
+	///
+	/// Mute individual sounds — `"Baa"`, `"Sneeze"`, and/or `"Yawn"` — by
+	/// name, unmuting any not in the list.
+	///
+	/// ## Errors
+	///
+	/// An error is thrown if the value is not an array of sound names.
+	pub fn set_muted(#[wasm_bindgen(unchecked_param_type = "PoeSound[]")] v: &JsValue) -> Result<(), JsError> {
+		let set = Sound::parse_set(v).map_err(|e| JsError::new(&e))?;
+		Universe::set_muted(set);
+		Ok(())
+	}
+
+	#[wasm_bindgen(setter)]
+	/// # Set Volume.
+	///
+	/// Change the master volume, from `0.0` (silent) to `1.0` (full).
+	pub fn set_volume(v: f32) { Universe::set_volume(v); }
+
+	#[wasm_bindgen(setter)]
+	/// # Set Skin.
+	///
+	/// Replace the sprite sheet with a custom one, given as a URL or `Blob`,
+	/// or restore the built-in art with `null`.
+	///
+	/// The image is loaded in the background, and must have the exact same
+	/// dimensions — and so the same tile layout — as the original (5680x40).
+	/// If it fails to load or validate, the built-in art is used instead.
+	///
+	/// The skin persists across deactivation/reactivation.
+	///
+	/// ## Errors
+	///
+	/// An error is thrown if the value is not a string, `Blob`, or `null`.
+	pub fn set_skin(#[wasm_bindgen(unchecked_param_type = "string | Blob | null")] v: &JsValue) -> Result<(), JsError> {
+		Skin::set(v).map_err(|e| JsError::new(&e))
+	}
+
 	#[wasm_bindgen(setter)]
 	/// # Toggle Audio.
 	///
@@ -194,6 +525,25 @@ impl Poe {
 	/// Enable or disable the ability to click and drag the primary sprite.
 	pub fn set_focus(v: bool) { Universe::set_no_focus(! v); }
 
+	#[wasm_bindgen(setter)]
+	/// # Toggle Interpolation.
+	///
+	/// Enable or disable smooth movement between steps. Disable it for the
+	/// authentic 1996 look.
+	pub fn set_interpolate(v: bool) { Universe::set_interpolate(v); }
+
+	#[cfg(feature = "director")]
+	#[wasm_bindgen(setter)]
+	/// # Toggle Debug Overlay.
+	///
+	/// Show or hide a debug overlay for each mate, outlining its 40x40
+	/// hitbox and labelling it with the current animation name and ID, frame
+	/// ID, position, direction, gravity/ignore-edges/may-exit flags, and the
+	/// milliseconds until the next tick. Dashed guides mark the furthest the
+	/// primary mate can go before hitting the right and bottom edges of the
+	/// screen.
+	pub fn set_debug(v: bool) { Universe::set_debug(v); }
+
 	#[cfg(feature = "director")]
 	#[wasm_bindgen(setter)]
 	/// # Set Playback Speed.
@@ -208,4 +558,80 @@ impl Poe {
 	///
 	/// Cue up a specific animation by its ID. Invalid entries are ignored.
 	pub fn set_play(id: u8) { Universe::set_next_animation(id); }
+
+	#[cfg(feature = "director")]
+	#[wasm_bindgen]
+	/// # Inject Scenes.
+	///
+	/// Play a custom scene list on the primary mate right away, for
+	/// prototyping new animations without a rebuild. The scenes are given as
+	/// an array of objects — or a JSON string encoding one — with the
+	/// following keys:
+	///
+	/// | Key | Type | Description | Required |
+	/// | --- | ---- | ----------- | -------- |
+	/// | `fpms` | `number` | Milliseconds per frame (`6..=65535`). | Yes |
+	/// | `frames` | `number[]` | Frame IDs, as reported by `Poe.state`. | Yes |
+	/// | `moveTo` | `[number, number]` | X/Y movement per step. | |
+	/// | `repeat` | `[number, number]` | Repeat count and the frame index to repeat from. | |
+	/// | `sound` | `[string, number]` | Sound name and the frame index to play it at. | |
+	/// | `sounds` | `object[]` | Additional sound cues: `{ sound, idx, chance?, everyLoop? }`, with an optional percent `chance` and/or `everyLoop` to replay on repetitions. | |
+	/// | `ease` | `string\|number[]` | Movement easing: `in`, `out`, `inOut`, `cubicIn`, `cubicOut`, `cubicInOut`, `sineIn`, `sineOut`, `sineInOut`, `bounce`, `elastic`, or `[x1, y1, x2, y2]` cubic bezier control points. | |
+	/// | `flags` | `string[]` | Any of `flipXNext`, `gravity`, `ignoreEdges`. | |
+	///
+	/// The scenes are held to the same rules as the built-in ones. While they
+	/// play, `Poe.state` reports the stand-in `Blink` animation with `custom`
+	/// set. Once they finish, Poe returns to its usual business.
+	///
+	/// ## Errors
+	///
+	/// An error is thrown if the scenes are invalid, in which case nothing is
+	/// played.
+	pub fn inject(
+		#[wasm_bindgen(unchecked_param_type = "PoeScene[] | string")] scenes: &JsValue,
+	) -> Result<(), JsError> {
+		CustomScenes::set(scenes).map_err(|e| JsError::new(&e))
+	}
+
+	#[cfg(feature = "director")]
+	#[wasm_bindgen]
+	#[must_use]
+	/// # Step.
+	///
+	/// While paused — `Poe.speed = 0` — advance the primary and child mates
+	/// by exactly `n` animation steps, defaulting to one, so each frame and
+	/// movement can be inspected in turn. Steps are played on the next
+	/// animation frame.
+	///
+	/// Returns `false` if Poe is inactive or not paused.
+	pub fn step(n: Option<u16>) -> bool {
+		if Universe::active() && Universe::paused() {
+			Universe::add_steps(n.unwrap_or(1));
+			true
+		}
+		else { false }
+	}
+
+	#[cfg(feature = "director")]
+	#[wasm_bindgen]
+	#[must_use]
+	/// # Seek.
+	///
+	/// Jump the primary mate to a specific step — counting from zero across
+	/// all of its scenes — of its current animation. If paused, that step is
+	/// played right away; otherwise playback simply continues from there.
+	///
+	/// The mate's position is left as-is, since movements are relative.
+	/// Out-of-range indices are ignored; see the `step` and `name` fields of
+	/// `Poe.state` for the current position.
+	///
+	/// Returns `false` if Poe is inactive.
+	pub fn seek(idx: u16) -> bool {
+		if Universe::active() {
+			Universe::set_seek(idx);
+			true
+		}
+		else { false }
+	}
 }
+
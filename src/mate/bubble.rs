@@ -0,0 +1,234 @@
+/*!
+# RS Mate Poe: Speech Bubble
+*/
+
+use crate::{
+	config,
+	CssPropertyBuffer,
+	dom,
+	Position,
+};
+use std::{
+	cell::Cell,
+	rc::Rc,
+};
+use wasm_bindgen::{
+	JsCast,
+	prelude::*,
+};
+use web_sys::HtmlElement;
+
+
+
+#[derive(Debug)]
+/// # Speech Bubble.
+///
+/// This holds the primary mate's speech bubble element — a sibling of the
+/// sprite wrapper inside the same shadow root — along with the click handler
+/// used to dismiss it early.
+///
+/// The bubble tracks the sprite's `--pos`, sitting above and to the right of
+/// it by default, flipping left and/or below as needed to stay on screen.
+pub(crate) struct Bubble {
+	/// # Element.
+	el: HtmlElement,
+
+	/// # Expiration Time.
+	///
+	/// This is shared with the click handler, which zeroes it to dismiss the
+	/// bubble on the next frame.
+	until: Rc<Cell<u32>>,
+
+	/// # Click Handler.
+	click: Closure<dyn FnMut()>,
+
+	/// # Shown?
+	shown: bool,
+}
+
+impl Drop for Bubble {
+	fn drop(&mut self) {
+		let _res = self.el.remove_event_listener_with_callback(
+			"click",
+			self.click.as_ref().unchecked_ref(),
+		);
+	}
+}
+
+impl Bubble {
+	/// # Default Duration (ms).
+	///
+	/// Used as the base when no explicit duration is given; a little more
+	/// time is added for each character of text.
+	const DEFAULT_DURATION: u32 = 1500;
+
+	/// # Maximum Duration (ms).
+	const MAX_DURATION: u16 = 60_000;
+
+	/// # Minimum Duration (ms).
+	const MIN_DURATION: u16 = 500;
+
+	/// # Height Allowance.
+	///
+	/// If the sprite is closer than this to the top of the screen, the bubble
+	/// is drawn beneath it instead.
+	const HEIGHT: i32 = 100;
+
+	/// # Horizontal Offset.
+	///
+	/// The bubble is anchored to the middle of the sprite.
+	const OFFSET: i32 = 20;
+
+	/// # Width.
+	///
+	/// This must match the `max-width` of `#b` in `core.scss`. If the bubble
+	/// would run off the right edge of the screen, it is drawn to the left of
+	/// the sprite instead.
+	const WIDTH: i32 = 240;
+}
+
+impl Bubble {
+	/// # New.
+	///
+	/// Create the (hidden) bubble element and bind its click handler.
+	pub(super) fn new() -> Self {
+		let el: HtmlElement = dom::document()
+			.and_then(|d| d.create_element("div").ok())
+			.and_then(|e| e.dyn_into().ok())
+			.expect_throw("!");
+		el.set_id("b");
+		el.set_class_name("off");
+
+		let until = Rc::new(Cell::new(0));
+		let until2 = Rc::clone(&until);
+		let click: Closure<dyn FnMut()> = Closure::wrap(Box::new(move || { until2.set(0); }));
+		el.add_event_listener_with_callback("click", click.as_ref().unchecked_ref())
+			.expect_throw("!");
+
+		Self { el, until, click, shown: false }
+	}
+
+	/// # Element.
+	pub(super) const fn el(&self) -> &HtmlElement { &self.el }
+
+	/// # Shown?
+	pub(super) const fn shown(&self) -> bool { self.shown }
+
+	/// # Parse Options.
+	///
+	/// Return the `duration` — in milliseconds — from the `Poe.say` options
+	/// object, if any.
+	///
+	/// ## Errors
+	///
+	/// An error is returned if the options are not an object, contain unknown
+	/// keys, or have a non-numeric duration.
+	pub(crate) fn parse_opts(src: &JsValue) -> Result<Option<u16>, String> {
+		let mut out = None;
+
+		if src.is_undefined() || src.is_null() { return Ok(out); }
+
+		for (k, v) in dom::entries(src).ok_or("Say options must be an object.")? {
+			match k.as_str() {
+				"duration" => { out.replace(config::number(&k, &v, Self::MAX_DURATION)?); },
+				_ => return Err(format!("Unknown option: {k}.")),
+			}
+		}
+
+		Ok(out)
+	}
+}
+
+impl Bubble {
+	/// # Show.
+	///
+	/// Set the text — as plain text, never markup — and reveal the bubble for
+	/// `duration` milliseconds, or a length-based default if `None`. Empty
+	/// text hides the bubble instead.
+	pub(super) fn show(&mut self, text: &str, duration: Option<u16>, now: u32) {
+		if text.is_empty() {
+			self.hide();
+			return;
+		}
+
+		let duration = duration.map_or_else(
+			|| default_duration(text),
+			|d| u32::from(d.clamp(Self::MIN_DURATION, Self::MAX_DURATION)),
+		);
+
+		self.el.set_text_content(Some(text));
+		self.until.set(now.saturating_add(duration).max(1));
+		if ! self.shown {
+			self.shown = true;
+			let _res = self.el.class_list().remove_1("off");
+		}
+	}
+
+	/// # Hide.
+	pub(super) fn hide(&mut self) {
+		self.until.set(0);
+		if self.shown {
+			self.shown = false;
+			let _res = self.el.class_list().add_1("off");
+			self.el.set_text_content(None);
+		}
+	}
+
+	/// # Expire.
+	///
+	/// Hide the bubble if its time is up or it was clicked.
+	pub(super) fn expire(&mut self, now: u32) {
+		if self.shown && self.until.get() <= now { self.hide(); }
+	}
+
+	/// # Place.
+	///
+	/// Sync the bubble's position with the sprite's, flipping sides if it
+	/// would otherwise run off the screen. The `offset` is added to the final
+	/// CSS coordinates to account for any screen insets.
+	pub(super) fn place(
+		&self,
+		pos: Position,
+		offset: Position,
+		width: u16,
+		buf: &mut CssPropertyBuffer,
+	) {
+		if ! self.shown { return; }
+
+		let list = self.el.class_list();
+		let left = i32::from(width) < pos.x + Self::OFFSET + Self::WIDTH;
+		let below = pos.y < Self::HEIGHT;
+		let _res = list.toggle_with_force("l", left);
+		let _res = list.toggle_with_force("u", below);
+		let _res = self.el.style().set_property("--pos", buf.format_xy(pos.x + offset.x, pos.y + offset.y));
+	}
+}
+
+
+
+/// # Default Duration.
+///
+/// Return a reading-time-ish duration for the text.
+fn default_duration(text: &str) -> u32 {
+	let chars = u32::try_from(text.chars().count()).unwrap_or(u32::MAX);
+	chars.saturating_mul(60)
+		.saturating_add(Bubble::DEFAULT_DURATION)
+		.min(u32::from(Bubble::MAX_DURATION))
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use wasm_bindgen_test::*;
+
+	#[wasm_bindgen_test]
+	fn t_default_duration() {
+		assert_eq!(default_duration("Hi"), Bubble::DEFAULT_DURATION + 120);
+		assert_eq!(
+			default_duration(&"a".repeat(10_000)),
+			u32::from(Bubble::MAX_DURATION),
+		);
+	}
+}
@@ -84,6 +84,7 @@ macro_rules! get {
 
 impl MateFlags {
 	get!("Flipped (X)", FLIPPED_X, flipped_x);
+	get!("Flipping (X) Next", FLIP_X_NEXT, flip_x_next);
 	get!("Gravity Applies", GRAVITY, gravity);
 	get!("Ignore Edges", IGNORE_EDGES, ignore_edges);
 	get!("Allowed to Exit Screen", MAY_EXIT, may_exit);
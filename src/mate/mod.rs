@@ -2,8 +2,12 @@
 # RS Mate Poe: Mate
 */
 
+mod bubble;
 mod flags;
+#[cfg(feature = "director")] mod overlay;
+mod snapshot;
 
+#[cfg(feature = "director")] use crate::CustomScenes;
 use crate::{
 	Animation,
 	CssPropertyBuffer,
@@ -12,14 +16,22 @@ use crate::{
 	Frame,
 	IMAGE_HEIGHT,
 	IMAGE_WIDTH,
+	PackStart,
 	Position,
+	Queue,
 	SceneList,
 	Sound,
+	StartX,
+	StartY,
 	StateAudio,
 	Step,
 	Universe,
 };
+pub(crate) use bubble::Bubble;
 use flags::MateFlags;
+#[cfg(feature = "director")] use overlay::Overlay;
+use js_sys::Object;
+use snapshot::Snapshot;
 use wasm_bindgen::prelude::*;
 use web_sys::{
 	DomTokenList,
@@ -32,6 +44,139 @@ use web_sys::{
 
 
 
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+/// # Goal.
+///
+/// A userland movement request for the primary mate, which takes precedence
+/// over the usual (random) animation choices until fulfilled.
+pub(crate) enum Goal {
+	/// # Teleport to Position.
+	MoveTo(Position),
+
+	/// # Walk to X Position.
+	WalkTo(i32),
+}
+
+
+
+/// # Goal: Idle Animation.
+///
+/// The animation to play upon reaching a `Goal::WalkTo` destination.
+const GOAL_IDLE: Animation = Animation::Blink;
+
+/// # Goal: Run Distance.
+///
+/// Walking is slow; for `Goal::WalkTo` destinations further away than this,
+/// run instead.
+const GOAL_RUN: i32 = 320;
+
+/// # Walk Animation.
+///
+/// Return the next step toward a `Goal::WalkTo` destination for a floored
+/// mate at `pos_x` facing `right` (or left): `Rotate` if facing the wrong
+/// way, `Run` if it's far and `run` is allowed, `Walk` otherwise, or
+/// `GOAL_IDLE` upon arrival, in which case the goal is cleared.
+const fn walk_animation(goal: &mut Option<Goal>, pos_x: i32, right: bool, run: bool)
+-> Animation {
+	let Some(Goal::WalkTo(x)) = *goal else { return GOAL_IDLE; };
+	let dx = x - pos_x;
+	if dx == 0 {
+		*goal = None;
+		GOAL_IDLE
+	}
+	else if right != dx.is_positive() { Animation::Rotate }
+	else if run && GOAL_RUN < dx.abs() { Animation::Run }
+	else { Animation::Walk }
+}
+
+/// # Walk Arrival.
+///
+/// Check whether a relative movement of `dx` has carried a mate now at
+/// `pos_x` to (or past) its `Goal::WalkTo` destination. If so, the goal is
+/// cleared and the destination returned so the mate can be snapped into
+/// place.
+const fn walk_arrival(goal: &mut Option<Goal>, pos_x: i32, dx: i32) -> Option<i32> {
+	if
+		let Some(Goal::WalkTo(x)) = *goal &&
+		((dx < 0 && pos_x <= x) || (0 < dx && x <= pos_x))
+	{
+		*goal = None;
+		Some(x)
+	}
+	else { None }
+}
+
+
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+/// # Glide.
+///
+/// The starting position and time window of a step's movement, for
+/// interpolation. (See `Mate::drawn_pos`.)
+struct Glide {
+	/// # Starting (Drawn) Position.
+	from: Position,
+
+	/// # Start Time.
+	start: u32,
+
+	/// # End Time.
+	end: u32,
+}
+
+impl Glide {
+	/// # Maximum Glide.
+	///
+	/// Step movements larger than this (on either axis) are meant to be
+	/// jumps, and aren't interpolated.
+	const MAX: i32 = Frame::SIZE_I;
+
+	/// # New.
+	///
+	/// Glide from `from` by the relative step `by` between `start` and `end`,
+	/// unless it's a jump.
+	const fn new(from: Position, by: Position, start: u32, end: u32) -> Option<Self> {
+		if by.x.abs() <= Self::MAX && by.y.abs() <= Self::MAX {
+			Some(Self { from, start, end })
+		}
+		else { None }
+	}
+
+	/// # Position At.
+	///
+	/// Return where a mate gliding to `to` should be drawn at `now`.
+	const fn at(self, to: Position, now: u32) -> Position {
+		if self.start <= now && now < self.end {
+			self.from.lerp(to, now - self.start, self.end - self.start)
+		}
+		else { to }
+	}
+}
+
+/// # Place.
+///
+/// Move `pos` to `to`, or by `to` if not `absolute`, returning `true` if
+/// anything changed. Absolute moves are jumps, so clear any `glide` too.
+const fn place(pos: &mut Position, glide: &mut Option<Glide>, to: Position, absolute: bool)
+-> bool {
+	if absolute {
+		if to.x != pos.x || to.y != pos.y {
+			pos.x = to.x;
+			pos.y = to.y;
+			*glide = None;
+			true
+		}
+		else { false }
+	}
+	else if to.x != 0 || to.y != 0 {
+		pos.move_to(to);
+		true
+	}
+	else { false }
+}
+
+
+
 #[derive(Debug)]
 /// # Mate.
 pub(crate) struct Mate {
@@ -53,8 +198,10 @@ pub(crate) struct Mate {
 	/// # Current Frame.
 	frame: Frame,
 
-	/// # Current Sound.
-	sound: Option<Sound>,
+	/// # Current Sounds.
+	///
+	/// A set of sounds to play on the next render. (See `Sound::bit`.)
+	sounds: u8,
 
 	/// # Current Position.
 	pos: Position,
@@ -68,11 +215,46 @@ pub(crate) struct Mate {
 	/// # Next Animation.
 	next_animation: Option<Animation>,
 
+	/// # Movement Goal.
+	goal: Option<Goal>,
+
 	/// # Next Tick Time.
 	next_tick: u32,
 
+	/// # Glide.
+	///
+	/// The most recent step's movement, for interpolation.
+	glide: Option<Glide>,
+
+	/// # Drawn Position.
+	///
+	/// The position most recently written to the DOM.
+	drawn: Position,
+
 	/// # CSS Property Buffer.
 	buf: CssPropertyBuffer,
+
+	/// # Speech Bubble.
+	///
+	/// Only the primary mate gets one.
+	bubble: Option<Bubble>,
+
+	#[cfg(feature = "director")]
+	/// # Last Direction.
+	///
+	/// The (absolute) direction of the most recent step, for debugging.
+	dir: Direction,
+
+	#[cfg(feature = "director")]
+	/// # Debug Overlay.
+	overlay: Option<Overlay>,
+
+	#[cfg(feature = "director")]
+	/// # Playing Custom Scenes?
+	///
+	/// True while `Poe.inject` scenes are playing in place of the stand-in
+	/// animation's own.
+	custom: bool,
 }
 
 impl Mate {
@@ -81,6 +263,14 @@ impl Mate {
 	/// Create a new instance (and supporting DOM elements).
 	pub(crate) fn new(primary: bool, image: &str) -> Self {
 		let (el, el_inner, el_image) = make_elements(primary, image);
+		let bubble = primary.then(|| {
+			let b = Bubble::new();
+			el.shadow_root()
+				.and_then(|s| s.append_child(b.el()).ok())
+				.expect_throw("!");
+			b
+		});
+
 		Self {
 			el,
 			el_inner,
@@ -88,13 +278,20 @@ impl Mate {
 			size: Universe::size(),
 			flags: MateFlags::new(primary),
 			frame: Frame::None,
-			sound: None,
+			sounds: 0,
 			pos: Position::new(0, 0),
 			animation: None,
 			scenes: None,
 			next_animation: None,
+			goal: None,
 			next_tick: 0,
+			glide: None,
+			drawn: Position::new(0, 0),
 			buf: CssPropertyBuffer::DEFAULT,
+			bubble,
+			#[cfg(feature = "director")] dir: Direction::None,
+			#[cfg(feature = "director")] overlay: None,
+			#[cfg(feature = "director")] custom: false,
 		}
 	}
 }
@@ -106,9 +303,8 @@ impl Mate {
 	/// # Child Animation.
 	///
 	/// Return the child animation required by this animation, if any.
-	pub(crate) const fn child(&self) -> Option<Animation> {
-		if let Some(a) = self.animation { a.child() }
-		else { None }
+	pub(crate) fn child(&self) -> Option<Animation> {
+		self.animation.and_then(Animation::child)
 	}
 
 	/// # Element.
@@ -118,6 +314,31 @@ impl Mate {
 	/// # Position.
 	pub(crate) const fn pos(&self) -> Position { self.pos }
 	*/
+
+	/// # Snapshot.
+	///
+	/// Return the mate's current state as a plain Javascript object, for
+	/// `Poe.state`. The `now` timestamp is used to work out the time
+	/// remaining until the next tick.
+	pub(crate) fn snapshot(&self, now: f64) -> Object { self.state(now).into_object() }
+
+	/// # State.
+	///
+	/// Gather up the details for `Mate::snapshot` (and the debug overlay).
+	fn state(&self, now: f64) -> Snapshot {
+		let offset = Universe::offset();
+		let out = Snapshot::new(
+			self.flags,
+			self.animation,
+			self.scenes.as_ref(),
+			self.frame,
+			Position::new(self.pos.x + offset.x, self.pos.y + offset.y),
+		)
+			.with_next(self.next_animation, self.next_tick, now);
+
+		#[cfg(feature = "director")] let out = out.with_custom(self.custom);
+		out
+	}
 }
 
 impl Mate {
@@ -129,6 +350,7 @@ impl Mate {
 		if self.flags.primary() {
 			// Clear some settings.
 			self.flags.clear();
+			self.goal = None;
 			self.set_animation(Animation::entrance_choice(true), true);
 		}
 	}
@@ -136,7 +358,7 @@ impl Mate {
 	/// # Stop.
 	///
 	/// Stop the animation, if any.
-	pub(crate) const fn stop(&mut self) {
+	pub(crate) fn stop(&mut self) {
 		// Clear animations and scenes, if any.
 		let a = self.animation.take();
 		let b = self.next_animation.take();
@@ -179,7 +401,7 @@ impl Mate {
 			},
 			Animation::AbductionChild => Some(Position::new(
 				self.pos.x,
-				self.pos.y - Frame::SIZE_I * 2 - 480,
+				self.pos.y - Frame::SIZE_I * 2 - Animation::abduction_descent(self.size.1),
 			)),
 			Animation::ShadowShowdownChild1 => {
 				let x =
@@ -188,7 +410,15 @@ impl Mate {
 				Some(Position::new(x, self.pos.y))
 			},
 			Animation::SneezeShadow | Animation::SplatGhost => Some(self.pos),
-			_ => None,
+			// Packs may declare an offset.
+			_ =>
+				if let Some(PackStart::Offset(off)) = animation.pack_start() {
+					let x =
+						if self.flags.flipped_x() { self.pos.x - off.x }
+						else { self.pos.x + off.x };
+					Some(Position::new(x, self.pos.y + off.y))
+				}
+				else { None },
 		} {
 			child.set_position(pos, true);
 		}
@@ -206,6 +436,8 @@ impl Mate {
 			return;
 		}
 
+		#[cfg(feature = "director")] { self.custom = false; }
+
 		// Clear and store the old animation to prevent recursion.
 		self.next_animation.take();
 		self.next_tick = 0;
@@ -236,12 +468,19 @@ impl Mate {
 			self.flags.mark_first();
 		}
 
-		// Exiting off-screen has a 1/15 probability for animations that allow
-		// it.
-		let animation_exit = animation.may_exit();
+		// Exiting off-screen has a (configurable) 1/25 probability for
+		// animations that allow it.
+		// Goal-directed and queued movement has to stay on screen.
+		let animation_exit =
+			animation.may_exit() &&
+			self.goal.is_none() &&
+			! (self.flags.primary() && Queue::active());
 		if animation_changed || ! animation_exit { self.flags.set_may_exit(false); }
-		if animation_exit && ! self.flags.may_exit() && 0 == Universe::rand_mod(25) {
-			self.flags.set_may_exit(true);
+		if animation_exit && ! self.flags.may_exit() {
+			let odds = Universe::exit_odds();
+			if odds != 0 && 0 == Universe::rand_mod(odds) {
+				self.flags.set_may_exit(true);
+			}
 		}
 
 		// Apply animation-wide flips.
@@ -249,7 +488,7 @@ impl Mate {
 
 		// Store the new animation!
 		self.animation.replace(animation);
-		self.scenes.replace(animation.scenes(self.size.0));
+		self.scenes.replace(animation.scenes(self.size.0, self.size.1));
 
 		// Finally, if this requires a child, request it.
 		if animation.child().is_some() { Universe::set_assign_child(); }
@@ -258,7 +497,7 @@ impl Mate {
 		dom::console_debug(&format!(
 			"Playing: {} (#{})",
 			animation.as_str(),
-			animation as u8,
+			animation.id(),
 		));
 	}
 
@@ -314,15 +553,16 @@ impl Mate {
 				w,
 				h - 149 - Frame::SIZE_I,
 			)),
+			// Keep the star well above the mate in short windows.
 			Animation::StargazeChild => Some(Position::new(
 				-Frame::SIZE_I,
-				Frame::SIZE_I * 2,
-			)),
-			// Randomize positioning.
-			Animation::BeamIn => Some(Position::new(
-				self.random_x(),
-				h - Frame::SIZE_I,
+				(h - Frame::SIZE_I * 4).clamp(0, Frame::SIZE_I * 2),
 			)),
+			// Randomize positioning (unless we're going somewhere specific).
+			Animation::BeamIn => Some(
+				if let Some(Goal::MoveTo(pos)) = self.goal { pos }
+				else { Position::new(self.random_x(), h - Frame::SIZE_I) }
+			),
 			Animation::FloatIn => Some(Position::new(
 				self.random_x().max(10),
 				h,
@@ -334,7 +574,24 @@ impl Mate {
 				Animation::WallSlide
 				if first || self.pos.x < 0 || w - Frame::SIZE_I < self.pos.x =>
 					Some(Position::new(self.random_x(), -Frame::SIZE_I)),
-			_ => None,
+			// Packs may declare a position.
+			_ =>
+				if let Some(PackStart::Screen(x, y)) = animation.pack_start() {
+					Some(Position::new(
+						match x {
+							StartX::Left => -Frame::SIZE_I,
+							StartX::Right => w,
+							StartX::Random => self.random_x(),
+							StartX::At(x) => x,
+						},
+						match y {
+							StartY::Top => -Frame::SIZE_I,
+							StartY::Bottom => h - Frame::SIZE_I,
+							StartY::At(y) => y,
+						},
+					))
+				}
+				else { None },
 		} {
 			self.flags.flip_x(Some(false));
 			self.set_position(pos, true);
@@ -357,30 +614,113 @@ impl Mate {
 
 	/// # Set Position.
 	pub(crate) const fn set_position(&mut self, pos: Position, absolute: bool) {
-		if absolute {
-			if pos.x != self.pos.x || pos.y != self.pos.y {
-				self.pos.x = pos.x;
-				self.pos.y = pos.y;
-				self.flags.mark_transform_changed();
-			}
-		}
-		else if pos.x != 0 || pos.y != 0 {
-			self.pos.move_to(pos);
+		if place(&mut self.pos, &mut self.glide, pos, absolute) {
 			self.flags.mark_transform_changed();
 		}
 	}
 }
 
 impl Mate {
+	#[cfg(feature = "director")]
+	/// # Custom Scene Stand-In.
+	///
+	/// `Poe.inject` scenes are played in place of this animation's own, as
+	/// it has no special positioning, styling, or children to get in the way.
+	///
+	/// Its ID and name are reported as-is while they play, alongside a
+	/// `custom` flag in `Poe.state` (and the debug overlay).
+	const CUSTOM_BASE: Animation = Animation::Blink;
+
+	/// # Talk Pause (ms).
+	///
+	/// How long to wait before checking again whether a walk paused for the
+	/// speech bubble can resume.
+	const TALK_PAUSE: u32 = 100;
+
 	/// # Paint!
 	///
 	/// Crunch the animation step details and repaint the DOM elements if
 	/// needed.
 	pub(crate) fn paint(&mut self, now: u32, audio: &StateAudio) {
+		if let Some(b) = &mut self.bubble { b.expire(now); }
 		if self.pretick(now) { self.tick(now); }
-		self.render(audio);
+		self.render(now, audio);
+		#[cfg(feature = "director")] self.paint_overlay(now);
+	}
+
+	#[cfg(feature = "director")]
+	/// # Paint Debug Overlay.
+	///
+	/// Create, update, or remove the debug overlay, per `Poe.debug`.
+	fn paint_overlay(&mut self, now: u32) {
+		if ! Universe::debug() || ! self.active() {
+			self.overlay = None;
+			return;
+		}
+
+		let max = Position::new(self.max_x(), self.max_y());
+		let offset = Universe::offset();
+		let text = self.state(f64::from(now)).label(self.dir);
+
+		let primary = self.flags.primary();
+		self.overlay.get_or_insert_with(|| Overlay::new(&self.el, primary))
+			.update(self.pos, max, offset, text);
+	}
+
+	/// # Say.
+	///
+	/// Show (or with empty text, hide) the speech bubble. This only applies
+	/// to the primary mate; it is a no-op for the child.
+	pub(crate) fn say(&mut self, text: &str, duration: Option<u16>, now: u32) {
+		if let Some(b) = &mut self.bubble {
+			b.show(text, duration, now);
+			b.place(self.drawn, Universe::offset(), self.size.0, &mut self.buf);
+		}
 	}
 
+	#[cfg(feature = "director")]
+	/// # Hurry.
+	///
+	/// Make the next paint tick regardless of timing, for manual stepping.
+	pub(crate) const fn hurry(&mut self) { self.next_tick = 0; }
+
+	#[cfg(feature = "director")]
+	/// # Seek.
+	///
+	/// Jump to the given step of the current animation, making it the next
+	/// one played. Positions are left as-is, as movements are relative.
+	///
+	/// Out-of-range indices are ignored.
+	pub(crate) fn seek(&mut self, idx: u16) {
+		if self.scenes.as_mut().is_some_and(|s| s.seek(usize::from(idx))) {
+			self.next_animation = None;
+			self.next_tick = 0;
+		}
+	}
+
+	/// # Set Image.
+	///
+	/// Swap out the sprite sheet.
+	pub(crate) fn set_image(&self, src: &str) {
+		let _res = self.el_image.set_attribute("src", src);
+	}
+
+	#[expect(clippy::cast_precision_loss, reason = "False positive.")]
+	/// # Stereo Pan.
+	///
+	/// Return the sprite's horizontal position as a stereo pan value, from
+	/// `-1.0` (far left) to `1.0` (far right).
+	fn pan(&self) -> f32 {
+		if self.size.0 == 0 { return 0.0; }
+		let x = (self.pos.x + Frame::SIZE_I / 2) as f32;
+		(x / f32::from(self.size.0)).mul_add(2.0, -1.0).clamp(-1.0, 1.0)
+	}
+
+	/// # Talking?
+	///
+	/// Returns `true` if the speech bubble is currently shown.
+	fn talking(&self) -> bool { self.bubble.as_ref().is_some_and(Bubble::shown) }
+
 	/// # Pre-Tick.
 	///
 	/// Determine whether or not we should do anything at all, and maybe apply
@@ -399,6 +739,7 @@ impl Mate {
 		// status.
 		else if self.flags.primary() && dragging != Universe::dragging() {
 			Universe::set_no_child();
+			self.goal = None;
 			if dragging { self.set_animation(Animation::Fall, false); }
 			else { self.set_animation(Animation::Drag, false); }
 			true
@@ -424,6 +765,39 @@ impl Mate {
 				self.next_animation.replace(n);
 			}
 
+			// Userland queue?
+			if self.flags.primary() && let Some(n) = Queue::start() {
+				Universe::set_no_child();
+				self.goal = None;
+				self.animation.take();
+				self.next_animation.replace(n);
+			}
+
+			// Userland movement request?
+			if self.flags.primary() && let Some(goal) = Universe::take_goal() {
+				self.set_goal(goal);
+			}
+
+			// Custom scenes?
+			#[cfg(feature = "director")]
+			if self.flags.primary() && let Some(scenes) = CustomScenes::take() {
+				Universe::set_no_child();
+				self.goal = None;
+				self.set_animation(Self::CUSTOM_BASE, true);
+				self.scenes.replace(scenes);
+				self.custom = true;
+			}
+
+			// Hold still while talking, unless there's somewhere else to be.
+			if
+				self.next_animation.is_none() &&
+				matches!(self.animation, Some(Animation::Walk | Animation::Run)) &&
+				self.talking()
+			{
+				self.next_tick = now + Self::TALK_PAUSE;
+				return false;
+			}
+
 			// Flip if flipping is needed.
 			self.flags.apply_next();
 
@@ -468,6 +842,9 @@ impl Mate {
 			return;
 		};
 
+		// Note where we're drawn before anything moves.
+		let from = self.drawn_pos(now);
+
 		// Adjust the timings.
 		self.next_tick = now + u32::from(step.next_tick());
 		self.flags.set_scene_flags(step.mate_flags());
@@ -475,17 +852,26 @@ impl Mate {
 		// Easy stuff.
 		self.set_frame(step.frame());
 
-		// Sound if enabled.
-		if let Some(sound) = step.sound() && Universe::audio() {
-			self.sound.replace(sound);
-			self.flags.mark_sound_changed();
+		// Note the direction for the debug overlay.
+		#[cfg(feature = "director")] {
+			self.dir = step.direction();
+			if self.flags.flipped_x() { self.dir = self.dir.invert_x(); }
 		}
-		else { self.sound = None; }
+
+		// Sound if enabled.
+		self.sounds =
+			if Universe::audio() { step.sounds() & ! Universe::muted_set() }
+			else { 0 };
+		if self.sounds != 0 { self.flags.mark_sound_changed(); }
 
 		// Move it?
 		if let Some(mut pos) = step.move_to() {
 			if self.flags.flipped_x() { pos = pos.invert_x(); }
 			self.set_position(pos, false);
+			self.glide = Glide::new(from, pos, now, self.next_tick);
+
+			// Stop short if we've arrived.
+			if self.tick_goal(pos.x) { return; }
 		}
 
 		// Edge-related business.
@@ -542,15 +928,18 @@ impl Mate {
 	///
 	/// Choose the next animation, factoring in the sprite's visibility on the
 	/// screen.
-	fn tick_next_animation(&self) -> Option<Animation> {
+	fn tick_next_animation(&mut self) -> Option<Animation> {
 		if self.flags.primary() {
 			match self.visibility() {
 				// If hidden, go with an entrance animation.
 				0 => Some(Animation::entrance_choice(false)),
 				// If partially visible and exiting, keep going.
 				1 if self.flags.may_exit() => self.animation,
-				// Otherwise go with the animation's named successor.
-				_ => self.animation.and_then(Animation::next),
+				// Otherwise go with the goal, queue, or animation's named
+				// successor.
+				_ => self.goal_animation()
+					.or_else(|| self.queue_animation())
+					.or_else(|| self.animation.and_then(Animation::next)),
 			}
 				// Fall back to the default choice.
 				.or_else(|| Some(Animation::default_choice()))
@@ -558,11 +947,38 @@ impl Mate {
 		else { self.animation.and_then(Animation::next) }
 	}
 
+	/// # Queue Animation.
+	///
+	/// If there's a userland queue in progress, return the next animation
+	/// from it, unless the current animation's successor is an internal
+	/// follow-up that needs to play out first.
+	fn queue_animation(&self) -> Option<Animation> {
+		if ! Queue::active() { return None; }
+
+		let next = self.animation.and_then(Animation::next);
+		if next.is_some_and(|a| ! a.playable()) { next }
+		else { Queue::next().or(next) }
+	}
+
+	/// # Drawn Position.
+	///
+	/// Return the position the mate should be drawn at, which is normally
+	/// just its position, but when interpolation is enabled, steps glide
+	/// there from wherever the mate was drawn before, arriving just in time
+	/// for the next one.
+	fn drawn_pos(&self, now: u32) -> Position {
+		if Universe::interpolate() && let Some(glide) = self.glide {
+			glide.at(self.pos, now)
+		}
+		else { self.pos }
+	}
+
 	/// # Render.
 	///
 	/// Apply any and all necessary changes to the DOM elements.
-	fn render(&mut self, audio: &StateAudio) {
-		if ! self.flags.changed() { return; }
+	fn render(&mut self, now: u32, audio: &StateAudio) {
+		let drawn = self.drawn_pos(now);
+		if ! self.flags.changed() && drawn == self.drawn { return; }
 
 		// Update the wrapper div's classes and/or styles.
 		if self.flags.class_changed() {
@@ -573,11 +989,14 @@ impl Mate {
 			if self.frame.reversed() { rx = ! rx; }
 			toggle_class(&list, "rx", rx);
 
-			// Smoothing?
+			// Smoothing? (Not needed if we're interpolating; the two would
+			// just fight each other.)
 			toggle_class(
 				&list,
 				"smooth",
-				! self.flags.first() && self.animation.is_some_and(Animation::smooth)
+				! Universe::interpolate() &&
+				! self.flags.first() &&
+				self.animation.is_some_and(Animation::smooth)
 			);
 
 			// Special frame and animation classes.
@@ -589,11 +1008,18 @@ impl Mate {
 		}
 
 		// Move X?
-		if self.flags.transform_changed() {
+		if self.flags.transform_changed() || drawn != self.drawn {
+			self.drawn = drawn;
+			let offset = Universe::offset();
 			let _res = self.el_inner.style().set_property(
 				"--pos",
-				self.buf.format_xy(self.pos.x, self.pos.y)
+				self.buf.format_xy(drawn.x + offset.x, drawn.y + offset.y)
 			);
+
+			// The speech bubble follows along.
+			if let Some(b) = &self.bubble {
+				b.place(drawn, offset, self.size.0, &mut self.buf);
+			}
 		}
 
 		// Update the image frame class.
@@ -604,14 +1030,94 @@ impl Mate {
 			);
 		}
 
-		// Play a sound?
-		if let Some(sound) = self.sound.take() { audio.play(sound); }
+		// Play sounds?
+		let sounds = std::mem::take(&mut self.sounds);
+		for sound in Sound::from_set(sounds) { audio.play(sound, self.pan()); }
 
 		// Reset the change flags.
 		self.flags.clear_changed();
 	}
 }
 
+impl Mate {
+	/// # Set Goal.
+	///
+	/// Clamp the goal to the screen, store it, and cue up the first step
+	/// toward fulfilling it, interrupting whatever was happening before.
+	fn set_goal(&mut self, goal: Goal) {
+		Universe::set_no_child();
+		let max_x = self.max_x();
+		match goal {
+			Goal::MoveTo(pos) => {
+				self.goal = Some(Goal::MoveTo(Position::new(
+					pos.x.clamp(0, max_x),
+					pos.y.clamp(0, self.max_y()),
+				)));
+				self.animation.take();
+				self.next_animation.replace(Animation::BeamIn);
+			},
+			Goal::WalkTo(x) => {
+				self.goal = Some(Goal::WalkTo(x.clamp(0, max_x)));
+				if let Some(next) = self.goal_animation() {
+					self.next_animation.replace(next);
+				}
+			},
+		}
+	}
+
+	/// # Goal Animation.
+	///
+	/// Return the next animation needed to fulfill the current goal, if any,
+	/// clearing it once done.
+	///
+	/// For `Goal::MoveTo`, that's just the one `BeamIn`, after which normal
+	/// behaviors resume.
+	///
+	/// For `Goal::WalkTo`, this returns `Rotate` if facing the wrong way,
+	/// `Walk` or `Run` otherwise, and `GOAL_IDLE` upon arrival. If the mate
+	/// isn't on the floor, `None` is returned so the current animation can
+	/// play out, but the goal remains for next time.
+	fn goal_animation(&mut self) -> Option<Animation> {
+		match self.goal? {
+			Goal::MoveTo(_) => {
+				if self.animation == Some(Animation::BeamIn) { self.goal = None; }
+				None
+			},
+			Goal::WalkTo(_) => {
+				if self.pos.y != self.max_y() { return None; }
+
+				// Walking goes left unless flipped (or about to be).
+				let right = self.flags.flipped_x() != self.flags.flip_x_next();
+				Some(walk_animation(
+					&mut self.goal,
+					self.pos.x,
+					right,
+					! Animation::Run.excluded(),
+				))
+			},
+		}
+	}
+
+	/// # Tick Goal.
+	///
+	/// Check whether a relative movement of `dx` has carried a walking or
+	/// running mate to (or past) its `Goal::WalkTo` destination, and if so,
+	/// snap it into place and cue up the idle animation.
+	///
+	/// Returns `true` if the goal was reached.
+	const fn tick_goal(&mut self, dx: i32) -> bool {
+		if
+			matches!(self.animation, Some(Animation::Walk | Animation::Run)) &&
+			let Some(x) = walk_arrival(&mut self.goal, self.pos.x, dx)
+		{
+			self.set_position(Position::new(x, self.pos.y), true);
+			self.next_animation.replace(GOAL_IDLE);
+			true
+		}
+		else { false }
+	}
+}
+
 impl Mate {
 	/// # Check Edges.
 	///
@@ -629,8 +1135,12 @@ impl Mate {
 		let max_x = self.max_x();
 		let max_y = self.max_y();
 
-		// Check gravity.
-		if self.flags.gravity() && self.pos.y != max_y {
+		// Check gravity (unless we're mid-teleport).
+		if
+			self.flags.gravity() &&
+			self.pos.y != max_y &&
+			! matches!(self.goal, Some(Goal::MoveTo(_)))
+		{
 			if self.flags.primary() {
 				self.set_animation(Animation::Fall, false);
 			}
@@ -828,3 +1338,114 @@ fn make_element_image(src: &str) -> Result<HtmlImageElement, JsValue> {
 fn toggle_class(list: &DomTokenList, class: &str, force: bool) {
 	let _res = list.toggle_with_force(class, force);
 }
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use wasm_bindgen_test::*;
+
+	#[wasm_bindgen_test]
+	fn t_walk_animation() {
+		// Facing the wrong way means turning around first, either way.
+		let mut goal = Some(Goal::WalkTo(500));
+		assert_eq!(walk_animation(&mut goal, 400, false, true), Animation::Rotate);
+		assert_eq!(goal, Some(Goal::WalkTo(500)));
+		let mut goal = Some(Goal::WalkTo(300));
+		assert_eq!(walk_animation(&mut goal, 400, true, true), Animation::Rotate);
+		assert_eq!(goal, Some(Goal::WalkTo(300)));
+
+		// Nearby destinations are walked to.
+		let mut goal = Some(Goal::WalkTo(400 + GOAL_RUN));
+		assert_eq!(walk_animation(&mut goal, 400, true, true), Animation::Walk);
+		let mut goal = Some(Goal::WalkTo(400 - GOAL_RUN));
+		assert_eq!(walk_animation(&mut goal, 400, false, true), Animation::Walk);
+
+		// Faraway ones are run to, unless running isn't allowed.
+		let mut goal = Some(Goal::WalkTo(401 + GOAL_RUN));
+		assert_eq!(walk_animation(&mut goal, 400, true, true), Animation::Run);
+		assert_eq!(walk_animation(&mut goal, 400, true, false), Animation::Walk);
+		let mut goal = Some(Goal::WalkTo(399 - GOAL_RUN));
+		assert_eq!(walk_animation(&mut goal, 400, false, true), Animation::Run);
+		assert_eq!(goal, Some(Goal::WalkTo(399 - GOAL_RUN)));
+
+		// Arrival idles and clears the goal, whichever way we're facing.
+		for right in [false, true] {
+			let mut goal = Some(Goal::WalkTo(400));
+			assert_eq!(walk_animation(&mut goal, 400, right, true), GOAL_IDLE);
+			assert!(goal.is_none(), "Goal not cleared on arrival.");
+		}
+	}
+
+	#[wasm_bindgen_test]
+	fn t_walk_arrival() {
+		// Not there yet.
+		let mut goal = Some(Goal::WalkTo(500));
+		assert_eq!(walk_arrival(&mut goal, 499, 2), None);
+		assert_eq!(walk_arrival(&mut goal, 600, -2), None);
+		assert_eq!(walk_arrival(&mut goal, 500, 0), None);
+		assert_eq!(goal, Some(Goal::WalkTo(500)));
+
+		// Exactly there, or past it either way, snaps to the destination
+		// and clears the goal.
+		for (pos_x, dx) in [(500, 2), (500, -2), (503, 2), (497, -2)] {
+			let mut goal = Some(Goal::WalkTo(500));
+			assert_eq!(walk_arrival(&mut goal, pos_x, dx), Some(500));
+			assert!(goal.is_none(), "Goal not cleared on arrival.");
+		}
+
+		// Moving away from a passed destination doesn't count.
+		let mut goal = Some(Goal::WalkTo(500));
+		assert_eq!(walk_arrival(&mut goal, 503, -2), None);
+
+		// Teleports aren't walks.
+		let mut goal = Some(Goal::MoveTo(Position::new(500, 0)));
+		assert_eq!(walk_arrival(&mut goal, 500, 2), None);
+		assert!(goal.is_some());
+	}
+
+	#[wasm_bindgen_test]
+	fn t_glide() {
+		let from = Position::new(100, 100);
+		let to = Position::new(100 + Glide::MAX, 100 - Glide::MAX);
+
+		// Steps up to the maximum glide on either axis.
+		let glide = Glide::new(from, Position::new(Glide::MAX, -Glide::MAX), 1000, 1100)
+			.expect("Maximum step should glide.");
+		assert_eq!(glide.at(to, 999), to);
+		assert_eq!(glide.at(to, 1000), from);
+		assert_eq!(glide.at(to, 1050), Position::new(100 + Glide::MAX / 2, 100 - Glide::MAX / 2));
+		assert_eq!(glide.at(to, 1100), to);
+
+		// Anything bigger is a jump.
+		for by in [
+			Position::new(Glide::MAX + 1, 0),
+			Position::new(0, Glide::MAX + 1),
+			Position::new(-Glide::MAX - 1, 0),
+			Position::new(0, -Glide::MAX - 1),
+		] {
+			assert!(Glide::new(from, by, 1000, 1100).is_none(), "{by:?} should jump.");
+		}
+	}
+
+	#[wasm_bindgen_test]
+	fn t_place() {
+		let mut pos = Position::new(100, 100);
+		let mut glide = Glide::new(Position::new(98, 100), Position::new(2, 0), 0, 30);
+		assert!(glide.is_some());
+
+		// Relative moves keep gliding.
+		assert!(! place(&mut pos, &mut glide, Position::new(0, 0), false));
+		assert!(place(&mut pos, &mut glide, Position::new(2, 0), false));
+		assert_eq!(pos, Position::new(102, 100));
+		assert!(glide.is_some(), "Relative move cleared glide.");
+
+		// Absolute ones don't, unless nothing changed.
+		assert!(! place(&mut pos, &mut glide, Position::new(102, 100), true));
+		assert!(glide.is_some(), "No-op move cleared glide.");
+		assert!(place(&mut pos, &mut glide, Position::new(500, 0), true));
+		assert_eq!(pos, Position::new(500, 0));
+		assert!(glide.is_none(), "Absolute move kept glide.");
+	}
+}
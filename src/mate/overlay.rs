@@ -0,0 +1,184 @@
+/*!
+# RS Mate Poe: Debug Overlay
+*/
+
+use crate::{
+	dom,
+	Position,
+};
+use wasm_bindgen::{
+	JsCast,
+	prelude::*,
+};
+use web_sys::{
+	Element,
+	HtmlElement,
+};
+
+
+
+#[derive(Debug)]
+/// # Debug Overlay.
+///
+/// This holds the `Poe.debug` elements for a single mate: an outline of its
+/// hitbox, a label with the current animation details, and — for the primary
+/// mate only — guides marking the furthest positions the sprite can reach
+/// before hitting the right and bottom edges of the screen.
+///
+/// Everything lives inside the mate's shadow root and is styled inline, so
+/// none of this has any effect on regular builds. The elements are removed
+/// when the overlay is dropped.
+pub(crate) struct Overlay {
+	/// # Hitbox.
+	hitbox: HtmlElement,
+
+	/// # Label.
+	label: HtmlElement,
+
+	/// # Edge Guides.
+	guides: Option<HtmlElement>,
+
+	/// # Last Placement.
+	///
+	/// The position, max position, and screen offset last written to the
+	/// DOM, used to skip redundant updates.
+	last: Option<(Position, Position, Position)>,
+
+	/// # Last Label.
+	text: String,
+}
+
+impl Drop for Overlay {
+	fn drop(&mut self) {
+		self.hitbox.remove();
+		self.label.remove();
+		if let Some(g) = &self.guides { g.remove(); }
+	}
+}
+
+impl Overlay {
+	/// # Hitbox Style.
+	const CSS_HITBOX: &str = "position:fixed;top:0;left:0;box-sizing:border-box;width:40px;height:40px;outline:1px solid #ff1493;pointer-events:none;z-index:2147483647;";
+
+	/// # Label Style.
+	const CSS_LABEL: &str = "position:fixed;top:0;left:0;padding:2px 4px;background:#000c;color:#0f0;font:10px/1.3 monospace;white-space:pre;pointer-events:none;z-index:2147483647;";
+
+	/// # Guide Style.
+	const CSS_GUIDES: &str = "position:fixed;top:0;left:0;box-sizing:border-box;border:0 dashed #00abc0;border-width:0 1px 1px 0;pointer-events:none;z-index:2147483646;";
+
+	/// # Label Gap.
+	///
+	/// The label sits just beneath the hitbox.
+	const LABEL_GAP: i32 = 42;
+}
+
+impl Overlay {
+	/// # New.
+	///
+	/// Create the overlay elements and append them to the mate's shadow root.
+	pub(super) fn new(el: &Element, primary: bool) -> Self {
+		let root = el.shadow_root().expect_throw("!");
+
+		let hitbox = make_div(Self::CSS_HITBOX);
+		let label = make_div(Self::CSS_LABEL);
+		root.append_with_node_2(&hitbox, &label).expect_throw("!");
+
+		let guides = primary.then(|| {
+			let g = make_div(Self::CSS_GUIDES);
+			root.append_child(&g).expect_throw("!");
+			g
+		});
+
+		Self { hitbox, label, guides, last: None, text: String::new() }
+	}
+
+	/// # Update.
+	///
+	/// Sync the hitbox and label with the sprite's current position — `pos`
+	/// and `max` are relative to the usable screen area, `offset` the insets
+	/// separating it from the viewport — and refresh the label text.
+	pub(super) fn update(&mut self, pos: Position, max: Position, offset: Position, text: String) {
+		if self.last != Some((pos, max, offset)) {
+			self.last = Some((pos, max, offset));
+
+			let (hitbox, label, guides) = placement(pos, max, offset);
+			let _res = self.hitbox.style().set_property("transform", &translate(hitbox));
+			let _res = self.label.style().set_property("transform", &translate(label));
+
+			if let Some(g) = &self.guides {
+				let style = g.style();
+				let _res = style.set_property("transform", &translate(offset));
+				let _res = style.set_property("width", &format!("{}px", guides.x));
+				let _res = style.set_property("height", &format!("{}px", guides.y));
+			}
+		}
+
+		if self.text != text {
+			self.label.set_text_content(Some(&text));
+			self.text = text;
+		}
+	}
+}
+
+
+
+/// # Placement.
+///
+/// Return the viewport positions of the hitbox and label, and the size of
+/// the edge guides, for the given relative position, max position, and
+/// screen offset. (See `Overlay::update`.)
+const fn placement(pos: Position, max: Position, offset: Position)
+-> (Position, Position, Position) {
+	let hitbox = Position::new(pos.x + offset.x, pos.y + offset.y);
+	(
+		hitbox,
+		Position::new(hitbox.x, hitbox.y + Overlay::LABEL_GAP),
+		Position::new(if max.x < 0 { 0 } else { max.x }, if max.y < 0 { 0 } else { max.y }),
+	)
+}
+
+/// # Translate.
+///
+/// Format a CSS translation to the given position.
+fn translate(pos: Position) -> String { format!("translate({}px,{}px)", pos.x, pos.y) }
+
+/// # Make Div.
+///
+/// Create a `<div>` with the given inline style.
+fn make_div(css: &str) -> HtmlElement {
+	let el: HtmlElement = dom::document()
+		.and_then(|d| d.create_element("div").ok())
+		.and_then(|e| e.dyn_into().ok())
+		.expect_throw("!");
+	let _res = el.set_attribute("style", css);
+	el
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use wasm_bindgen_test::*;
+
+	#[wasm_bindgen_test]
+	fn t_placement() {
+		let (hitbox, label, guides) = placement(
+			Position::new(100, 200),
+			Position::new(1880, 1040),
+			Position::new(10, 20),
+		);
+		assert_eq!(hitbox, Position::new(110, 220));
+		assert_eq!(label, Position::new(110, 220 + Overlay::LABEL_GAP));
+		assert_eq!(guides, Position::new(1880, 1040));
+		assert_eq!(translate(hitbox), "translate(110px,220px)");
+
+		// Guides can't have negative sizes, even on absurdly small screens.
+		let (_, _, guides) = placement(
+			Position::new(-50, 0),
+			Position::new(-40, 5),
+			Position::default(),
+		);
+		assert_eq!(guides, Position::new(0, 5));
+	}
+}
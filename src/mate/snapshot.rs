@@ -0,0 +1,247 @@
+/*!
+# RS Mate Poe: Mate Snapshot
+*/
+
+#[cfg(feature = "director")] use crate::Direction;
+use crate::{
+	Animation,
+	dom,
+	Frame,
+	Position,
+	SceneList,
+};
+use js_sys::Object;
+use super::MateFlags;
+use wasm_bindgen::JsValue;
+
+
+
+#[derive(Debug, Clone, Copy)]
+/// # Mate Snapshot.
+///
+/// The details reported for a single mate by `Poe.state` (and, in director
+/// builds, the debug overlay), pulled together without touching the DOM.
+pub(super) struct Snapshot {
+	/// # Flags.
+	flags: MateFlags,
+
+	/// # Current Animation.
+	animation: Option<Animation>,
+
+	/// # Current Frame.
+	frame: Frame,
+
+	/// # Position.
+	///
+	/// This is relative to the viewport, i.e. with any insets applied.
+	pos: Position,
+
+	/// # Step Index.
+	step: Option<usize>,
+
+	/// # Queued Animation.
+	next: Option<Animation>,
+
+	/// # Milliseconds Until Next Tick.
+	next_tick: f64,
+
+	/// # Playing Custom Scenes?
+	custom: bool,
+}
+
+impl Snapshot {
+	/// # New.
+	///
+	/// Capture the current animation details. The `pos` should include any
+	/// screen offset.
+	pub(super) fn new(
+		flags: MateFlags,
+		animation: Option<Animation>,
+		scenes: Option<&SceneList>,
+		frame: Frame,
+		pos: Position,
+	) -> Self {
+		Self {
+			flags,
+			animation,
+			frame,
+			pos,
+			step: scenes.and_then(SceneList::position),
+			next: None,
+			next_tick: 0.0,
+			custom: false,
+		}
+	}
+
+	#[must_use]
+	/// # With Next.
+	///
+	/// Add the queued animation, if any, and the time remaining until the
+	/// `next_tick` timestamp, relative to `now`. (Inactive mates aren't
+	/// ticking, so always report zero.)
+	pub(super) fn with_next(self, next: Option<Animation>, next_tick: u32, now: f64) -> Self {
+		let next_tick =
+			if self.animation.is_some() { (f64::from(next_tick) - now).max(0.0) }
+			else { 0.0 };
+		Self { next, next_tick, ..self }
+	}
+
+	#[cfg(feature = "director")]
+	#[must_use]
+	/// # With Custom.
+	///
+	/// Note whether `Poe.inject` scenes are playing in place of the
+	/// animation's own.
+	pub(super) const fn with_custom(self, custom: bool) -> Self {
+		Self { custom, ..self }
+	}
+
+	/// # Dragging?
+	const fn dragging(&self) -> bool { matches!(self.animation, Some(Animation::Drag)) }
+
+	/// # Frame ID.
+	///
+	/// Return the sprite frame ID, if any.
+	const fn frame_id(&self) -> Option<u8> {
+		match self.frame {
+			Frame::None => None,
+			f => Some(f as u8),
+		}
+	}
+
+	/// # Into Object.
+	///
+	/// Return the snapshot as a plain Javascript object, for `Poe.state`.
+	pub(super) fn into_object(self) -> Object {
+		/// # Animation ID/Name.
+		fn animation(a: Option<Animation>) -> (JsValue, JsValue) {
+			a.map_or(
+				(JsValue::NULL, JsValue::NULL),
+				|a| (JsValue::from(a.id()), JsValue::from_str(a.name())),
+			)
+		}
+
+		let (id, name) = animation(self.animation);
+		let (next_id, next_name) = animation(self.next);
+
+		dom::object([
+			("primary", JsValue::from_bool(self.flags.primary())),
+			("animation", id),
+			("name", name),
+			("frame", self.frame_id().map_or(JsValue::NULL, JsValue::from)),
+			("x", JsValue::from(self.pos.x)),
+			("y", JsValue::from(self.pos.y)),
+			("flipped", JsValue::from_bool(self.flags.flipped_x())),
+			("gravity", JsValue::from_bool(self.flags.gravity())),
+			("ignoreEdges", JsValue::from_bool(self.flags.ignore_edges())),
+			("dragging", JsValue::from_bool(self.dragging())),
+			("step", self.step.map_or(JsValue::NULL, JsValue::from)),
+			("nextTick", JsValue::from(self.next_tick)),
+			("next", next_id),
+			("nextName", next_name),
+			("custom", JsValue::from_bool(self.custom)),
+		])
+	}
+
+	#[cfg(feature = "director")]
+	/// # Overlay Label.
+	///
+	/// Return the text for the debug overlay, given the direction of the most
+	/// recent step.
+	pub(super) fn label(&self, dir: Direction) -> String {
+		format!(
+			"{} #{}{}\nframe {}  ({}, {})  {:?}\ngravity {}  ignoreEdges {}  mayExit {}\nnext {}ms",
+			self.animation.map_or("", Animation::name),
+			self.animation.map_or(0, Animation::id),
+			if self.custom { " (custom)" } else { "" },
+			self.frame_id().unwrap_or(0),
+			self.pos.x,
+			self.pos.y,
+			dir,
+			u8::from(self.flags.gravity()),
+			u8::from(self.flags.ignore_edges()),
+			u8::from(self.flags.may_exit()),
+			self.next_tick,
+		)
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use wasm_bindgen_test::*;
+
+	#[wasm_bindgen_test]
+	fn t_snapshot() {
+		// Find a primary with a child to pair up.
+		let primary = Animation::ALL.into_iter()
+			.find(|a| a.playable() && a.child().is_some())
+			.expect("No animations have children.");
+		let child = primary.child().expect("Missing child.");
+
+		// Primary, a few steps in.
+		let mut flags = MateFlags::new(true);
+		flags.flip_x(Some(true));
+		let mut scenes = primary.scenes(1920, 1080);
+		let frame = scenes.nth(2).expect("Missing step.").frame();
+		let snap = Snapshot::new(flags, Some(primary), Some(&scenes), frame, Position::new(100, 200))
+			.with_next(Some(Animation::Walk), 1_050, 1_000.0);
+		assert!(snap.flags.primary(), "Primary should be primary.");
+		assert!(snap.flags.flipped_x(), "Primary should be flipped.");
+		assert_eq!(snap.animation, Some(primary));
+		assert_eq!(snap.frame_id(), Some(frame as u8));
+		assert_eq!(snap.step, Some(2));
+		assert_eq!(snap.next, Some(Animation::Walk));
+		assert!((snap.next_tick - 50.0).abs() < f64::EPSILON, "Wrong next tick: {}", snap.next_tick);
+		assert!(! snap.dragging(), "Primary should not be dragging.");
+		assert!(! snap.custom, "Primary should not be custom.");
+
+		// Its child, just getting started, with a tick already overdue.
+		let scenes = child.scenes(1920, 1080);
+		let pos = Position::new(110, 220);
+		let snap = Snapshot::new(MateFlags::new(false), primary.child(), Some(&scenes), Frame::None, pos)
+			.with_next(None, 900, 1_000.0);
+		assert!(! snap.flags.primary(), "Child should not be primary.");
+		assert!(! snap.flags.flipped_x(), "Child should not be flipped.");
+		assert_eq!(snap.animation, Some(child));
+		assert_eq!(snap.frame_id(), None);
+		assert_eq!(snap.pos, pos);
+		assert_eq!(snap.step, None);
+		assert_eq!(snap.next, None);
+		assert!(snap.next_tick.abs() < f64::EPSILON, "Next tick should be zero.");
+
+		// Inactive mates have nothing to report.
+		let snap = Snapshot::new(MateFlags::new(false), None, None, Frame::None, Position::default())
+			.with_next(None, 5_000, 1_000.0);
+		assert_eq!(snap.animation, None);
+		assert_eq!(snap.step, None);
+		assert!(snap.next_tick.abs() < f64::EPSILON, "Next tick should be zero.");
+
+		// Dragging is just a matter of animation.
+		let snap = Snapshot::new(MateFlags::new(true), Some(Animation::Drag), None, Frame::None, Position::default());
+		assert!(snap.dragging(), "Drag should be dragging.");
+	}
+
+	#[cfg(feature = "director")]
+	#[wasm_bindgen_test]
+	fn t_label() {
+		let mut flags = MateFlags::new(true);
+		flags.set_may_exit(true);
+		let mut scenes = Animation::Walk.scenes(1920, 1080);
+		let frame = scenes.next().expect("Missing step.").frame();
+		let snap = Snapshot::new(flags, Some(Animation::Walk), Some(&scenes), frame, Position::new(-5, 30))
+			.with_next(None, 1_200, 1_000.0);
+		let expected = format!(
+			"Walk #{}\nframe {}  (-5, 30)  Left\ngravity 0  ignoreEdges 0  mayExit 1\nnext 200ms",
+			Animation::Walk.id(),
+			frame as u8,
+		);
+		assert_eq!(snap.label(Direction::Left), expected);
+
+		// Injected scenes keep the stand-in's name and ID, but say so.
+		let snap = snap.with_custom(true);
+		assert!(snap.label(Direction::Left).starts_with(&format!("Walk #{} (custom)\n", Animation::Walk.id())));
+	}
+}
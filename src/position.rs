@@ -53,6 +53,28 @@ impl Position {
 		Self::new(self.x, self.y.saturating_neg())
 	}
 
+	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+	#[must_use]
+	/// # Linear Interpolation.
+	///
+	/// Return the point `done / span` of the way from `self` to `to`,
+	/// rounding toward `self`. If `span` is zero or `done` is out of range,
+	/// `to` is returned.
+	pub(crate) const fn lerp(self, to: Self, done: u32, span: u32) -> Self {
+		/// # Lerp One.
+		const fn lerp1(a: i32, b: i32, done: i64, span: i64) -> i32 {
+			// The result lies between a and b, so can't truncate.
+			(a as i64 + (b as i64 - a as i64) * done / span) as i32
+		}
+
+		if span == 0 || span <= done { to }
+		else {
+			let done = done as i64;
+			let span = span as i64;
+			Self::new(lerp1(self.x, to.x, done, span), lerp1(self.y, to.y, done, span))
+		}
+	}
+
 	/// # Move To.
 	///
 	/// Add `pos` to `self`, returning `true` if the coordinates changed as a
@@ -182,6 +204,24 @@ mod tests {
 		);
 	}
 
+	#[wasm_bindgen_test]
+	fn t_lerp() {
+		let a = Position::new(0, 100);
+		let b = Position::new(10, -100);
+		assert_eq!(a.lerp(b, 0, 4), a);
+		assert_eq!(a.lerp(b, 1, 4), Position::new(2, 50));
+		assert_eq!(a.lerp(b, 2, 4), Position::new(5, 0));
+		assert_eq!(a.lerp(b, 3, 4), Position::new(7, -50));
+		assert_eq!(a.lerp(b, 4, 4), b);
+		assert_eq!(a.lerp(b, 5, 4), b);
+		assert_eq!(a.lerp(b, 0, 0), b);
+
+		// Extremes shouldn't overflow.
+		let a = Position::new(i32::MIN, i32::MAX);
+		let b = Position::new(i32::MAX, i32::MIN);
+		assert_eq!(a.lerp(b, 1, 2), Position::new(-1, 0));
+	}
+
 	#[wasm_bindgen_test]
 	fn t_direction() {
 		// No direction.
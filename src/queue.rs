@@ -0,0 +1,248 @@
+/*!
+# RS Mate Poe: Queue
+*/
+
+use crate::{
+	Animation,
+	dom,
+	Universe,
+};
+use js_sys::{
+	Array,
+	Function,
+	Promise,
+};
+use std::cell::RefCell;
+use wasm_bindgen::{
+	JsCast,
+	prelude::*,
+};
+
+
+
+thread_local! {
+	/// # Current Queue.
+	///
+	/// Unlike the [`Universe`] settings, the queue holds Javascript callbacks,
+	/// so has to live here instead.
+	static QUEUE: RefCell<Option<Queue>> = const { RefCell::new(None) };
+}
+
+
+
+#[derive(Debug)]
+/// # Animation Queue.
+///
+/// This holds a userland playlist — set via `Poe.queue` — that takes the
+/// place of the primary mate's usual (random) animation choices until it runs
+/// out.
+///
+/// Each entry plays until it would normally transition to another playable
+/// animation; internal follow-ups (like `TornadoExit` after `Tornado`) and
+/// edge-triggered transitions are still honored.
+pub(crate) struct Queue {
+	/// # Animations.
+	list: Vec<Animation>,
+
+	/// # Next Index.
+	idx: usize,
+
+	/// # Flags.
+	flags: u8,
+
+	/// # Promise Resolver.
+	resolve: Function,
+}
+
+impl Drop for Queue {
+	fn drop(&mut self) {
+		// Resolve the promise, noting whether or not we made it to the end.
+		let done = Self::DONE == self.flags & Self::DONE;
+		let _res = self.resolve.call1(&JsValue::NULL, &JsValue::from_bool(done));
+	}
+}
+
+impl Queue {
+	/// # Flag: Loop.
+	const LOOP: u8 =    0b0001;
+
+	/// # Flag: Deactivate When Done.
+	const STOP: u8 =    0b0010;
+
+	/// # Flag: Started.
+	const STARTED: u8 = 0b0100;
+
+	/// # Flag: Finished.
+	const DONE: u8 =    0b1000;
+}
+
+impl Queue {
+	/// # New.
+	///
+	/// Parse and validate the userland animation list and options, install
+	/// the result as the current queue — replacing any previous one — and
+	/// return a `Promise` that resolves once it finishes.
+	///
+	/// The promise resolves to `true` if the queue played through to the
+	/// end, or `false` if it was replaced or Poe was deactivated first.
+	/// (Looping queues can only end the latter way.)
+	///
+	/// ## Errors
+	///
+	/// An error is returned if the list is empty or contains anything other
+	/// than directly-playable animation IDs or names, or if the options are
+	/// invalid.
+	pub(crate) fn set(list: &JsValue, opts: &JsValue) -> Result<Promise, String> {
+		let list = parse_list(list)?;
+		let flags = parse_opts(opts)?;
+
+		let mut resolve = None;
+		let promise = Promise::new(&mut |res, _| { resolve.replace(res); });
+		let Some(resolve) = resolve else { return Err("Unable to create promise.".to_owned()); };
+
+		let new = Self { list, idx: 0, flags, resolve };
+
+		// Swap it in, dropping (and resolving) the old one outside the
+		// borrow, just in case.
+		let old = QUEUE.with_borrow_mut(|q| q.replace(new));
+		drop(old);
+
+		Ok(promise)
+	}
+
+	/// # Active?
+	///
+	/// Returns `true` if there is a queue in progress.
+	pub(crate) fn active() -> bool { QUEUE.with_borrow(Option::is_some) }
+
+	/// # Clear.
+	///
+	/// Remove the current queue, if any.
+	pub(crate) fn clear() {
+		let old = QUEUE.with_borrow_mut(Option::take);
+		drop(old);
+	}
+
+	/// # Start.
+	///
+	/// Return the first animation from a newly-set queue, if any. This only
+	/// returns a value once per queue.
+	pub(crate) fn start() -> Option<Animation> {
+		QUEUE.with_borrow_mut(|q| {
+			let q = q.as_mut()?;
+			if Self::STARTED == q.flags & Self::STARTED { None }
+			else {
+				q.flags |= Self::STARTED;
+				q.idx = 1;
+				q.list.first().copied()
+			}
+		})
+	}
+
+	/// # Next.
+	///
+	/// Advance the queue, returning the next animation, if any. When the end
+	/// is reached, non-looping queues are removed — possibly deactivating Poe
+	/// — and `None` is returned so normal behaviors can resume.
+	pub(crate) fn next() -> Option<Animation> {
+		let (next, stop) = QUEUE.with_borrow_mut(|q| {
+			let q = q.as_mut()?;
+			if Self::LOOP == q.flags & Self::LOOP && q.list.len() <= q.idx { q.idx = 0; }
+			let next = q.list.get(q.idx).copied();
+			if next.is_some() { q.idx += 1; }
+			else { q.flags |= Self::DONE; }
+			Some((next, Self::STOP == q.flags & Self::STOP))
+		})?;
+
+		if next.is_none() {
+			Self::clear();
+			if stop { Universe::set_active(false); }
+		}
+
+		next
+	}
+}
+
+
+
+/// # Parse List.
+///
+/// Convert an array of animation IDs and/or names into a list of playable
+/// animations.
+fn parse_list(src: &JsValue) -> Result<Vec<Animation>, String> {
+	if ! Array::is_array(src) { return Err("The queue must be an array.".to_owned()); }
+
+	let out = src.unchecked_ref::<Array>()
+		.iter()
+		.map(|v| {
+			let a = v.as_f64()
+				.and_then(animation_id)
+				.or_else(|| v.as_string().and_then(|n| Animation::from_name(&n)))
+				.filter(|a| a.playable());
+			a.ok_or_else(|| format!("Invalid or unplayable animation: {v:?}."))
+		})
+		.collect::<Result<Vec<_>, _>>()?;
+
+	if out.is_empty() { Err("The queue must not be empty.".to_owned()) }
+	else { Ok(out) }
+}
+
+#[expect(
+	clippy::cast_possible_truncation,
+	clippy::cast_sign_loss,
+	reason = "False positive.",
+)]
+/// # Animation By ID.
+///
+/// Return the animation matching the (whole) number, if any.
+fn animation_id(n: f64) -> Option<Animation> {
+	if n.fract() == 0.0 && (1.0..256.0).contains(&n) { Animation::from_u8(n as u8) }
+	else { None }
+}
+
+/// # Parse Options.
+///
+/// Return the `Queue::LOOP` and `Queue::STOP` flags corresponding to the
+/// options object, if any.
+fn parse_opts(src: &JsValue) -> Result<u8, String> {
+	let mut flags = 0;
+
+	if src.is_undefined() || src.is_null() { return Ok(flags); }
+
+	for (k, v) in dom::entries(src).ok_or("Queue options must be an object.")? {
+		match k.as_str() {
+			"loop" => match v.as_bool() {
+				Some(true) => { flags |= Queue::LOOP; },
+				Some(false) => { flags &= ! Queue::LOOP; },
+				None => return Err("loop must be a boolean.".to_owned()),
+			},
+			"then" => match v.as_string().as_deref() {
+				Some("resume") => { flags &= ! Queue::STOP; },
+				Some("stop") => { flags |= Queue::STOP; },
+				_ => return Err("then must be \"resume\" or \"stop\".".to_owned()),
+			},
+			_ => return Err(format!("Unknown option: {k}.")),
+		}
+	}
+
+	Ok(flags)
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use wasm_bindgen_test::*;
+
+	#[wasm_bindgen_test]
+	fn t_animation_id() {
+		for a in Animation::ALL {
+			assert_eq!(animation_id(f64::from(a.id())), Some(a));
+		}
+
+		for n in [0.0, 1.5, -1.0, 256.0, f64::NAN, f64::INFINITY] {
+			assert!(animation_id(n).is_none(), "Animation ID {n} should be invalid.");
+		}
+	}
+}
@@ -0,0 +1,219 @@
+/*!
+# RS Mate Poe: Skins
+*/
+
+use crate::{
+	dom,
+	IMAGE_HEIGHT,
+	IMAGE_WIDTH,
+	State,
+};
+use std::cell::RefCell;
+use wasm_bindgen::{
+	JsCast,
+	prelude::*,
+};
+use web_sys::{
+	Blob,
+	HtmlImageElement,
+	Url,
+};
+
+
+
+thread_local! {
+	/// # Current Skin.
+	///
+	/// Like the queue, skins hold Javascript objects, so have to live here
+	/// rather than the [`Universe`](crate::Universe).
+	static SKIN: RefCell<Option<Skin>> = const { RefCell::new(None) };
+}
+
+
+
+/// # Tile Size.
+///
+/// Sprite sheets are a single row of square tiles.
+const TILE_SIZE: u32 = IMAGE_HEIGHT;
+
+const _: () = assert!(
+	IMAGE_WIDTH.is_multiple_of(TILE_SIZE),
+	"BUG: the sprite width is not a multiple of its tile size.",
+);
+
+
+
+/// # Custom Sprite Sheet.
+///
+/// This holds an alternative sprite sheet set via `Poe.skin`, along with the
+/// detached image element and callbacks used to load and validate it.
+///
+/// Skins must have the exact same dimensions — and so tile layout — as the
+/// built-in art. Anything else, or anything that fails to load, is discarded
+/// in favor of the original.
+pub(crate) struct Skin {
+	/// # URL.
+	url: String,
+
+	/// # Blob?
+	///
+	/// If `true`, the URL was created by us and needs to be revoked.
+	blob: bool,
+
+	/// # Ready?
+	ready: bool,
+
+	/// # Loader.
+	img: HtmlImageElement,
+
+	#[expect(dead_code, reason = "Kept alive for the loader.")]
+	/// # Load Callback.
+	onload: Closure<dyn FnMut()>,
+
+	#[expect(dead_code, reason = "Kept alive for the loader.")]
+	/// # Error Callback.
+	onerror: Closure<dyn FnMut()>,
+}
+
+impl Drop for Skin {
+	fn drop(&mut self) {
+		// Make sure the callbacks can't fire after we're gone.
+		self.img.set_onload(None);
+		self.img.set_onerror(None);
+		self.img.set_src("");
+
+		if self.blob { let _res = Url::revoke_object_url(&self.url); }
+	}
+}
+
+impl Skin {
+	/// # Set.
+	///
+	/// Parse the userland source — a URL string or `Blob` — and begin loading
+	/// it, replacing any previous skin. `null`, `undefined`, or an empty
+	/// string restore the built-in sprite.
+	///
+	/// ## Errors
+	///
+	/// An error is returned if the source is some other type, or a blob URL
+	/// cannot be created for it.
+	pub(crate) fn set(src: &JsValue) -> Result<(), String> {
+		let (url, blob) =
+			if src.is_undefined() || src.is_null() { (String::new(), false) }
+			else if let Some(s) = src.as_string() { (s.trim().to_owned(), false) }
+			else if let Some(b) = src.dyn_ref::<Blob>() {
+				let url = Url::create_object_url_with_blob(b)
+					.map_err(|_| "Unable to read skin blob.".to_owned())?;
+				(url, true)
+			}
+			else { return Err("The skin must be a URL or Blob.".to_owned()); };
+
+		// Restore the default.
+		if url.is_empty() {
+			Self::clear();
+			return Ok(());
+		}
+
+		let img = HtmlImageElement::new().map_err(|_| "Unable to load skin.".to_owned())?;
+		let onload: Closure<dyn FnMut()> = Closure::wrap(Box::new(Self::loaded));
+		let onerror: Closure<dyn FnMut()> = Closure::wrap(Box::new(Self::failed));
+		img.set_onload(Some(onload.as_ref().unchecked_ref()));
+		img.set_onerror(Some(onerror.as_ref().unchecked_ref()));
+		img.set_src(&url);
+
+		// Swap it in, restoring the default art in the meantime.
+		let new = Self { url, blob, ready: false, img, onload, onerror };
+		let old = SKIN.with_borrow_mut(|s| s.replace(new));
+		if old.as_ref().is_some_and(|s| s.ready) { State::set_image(None); }
+		drop(old);
+
+		Ok(())
+	}
+
+	/// # Clear.
+	///
+	/// Remove the current skin, if any, restoring the built-in sprite.
+	fn clear() {
+		let old = SKIN.with_borrow_mut(Option::take);
+		if old.as_ref().is_some_and(|s| s.ready) { State::set_image(None); }
+		drop(old);
+	}
+
+	/// # URL.
+	///
+	/// Return the URL of the current skin, if loaded and valid.
+	pub(crate) fn url() -> Option<String> {
+		SKIN.with_borrow(|s| s.as_ref().filter(|s| s.ready).map(|s| s.url.clone()))
+	}
+}
+
+impl Skin {
+	/// # Load Callback.
+	///
+	/// Validate the freshly-loaded image, applying it to any running mates if
+	/// it checks out, or discarding it if not.
+	fn loaded() {
+		let url = SKIN.with_borrow_mut(|s| {
+			let s = s.as_mut()?;
+			if valid_size(s.img.natural_width(), s.img.natural_height()) {
+				s.ready = true;
+				Some(s.url.clone())
+			}
+			else { None }
+		});
+
+		if let Some(url) = url { State::set_image(Some(&url)); }
+		else {
+			#[cfg(feature = "director")]
+			dom::console_warn(&format!(
+				"The skin must be {IMAGE_WIDTH}x{IMAGE_HEIGHT}; using the built-in sprite instead.",
+			));
+			Self::discard();
+		}
+	}
+
+	/// # Error Callback.
+	fn failed() {
+		#[cfg(feature = "director")]
+		dom::console_warn("The skin failed to load; using the built-in sprite instead.");
+		Self::discard();
+	}
+
+	/// # Discard.
+	///
+	/// Remove the current skin without dropping it immediately, since this is
+	/// called from inside its own callbacks. The actual cleanup is deferred to
+	/// a microtask.
+	fn discard() {
+		if let Some(old) = SKIN.with_borrow_mut(Option::take) && let Some(w) = dom::window() {
+			let cb = Closure::once_into_js(move || drop(old));
+			w.queue_microtask(cb.unchecked_ref());
+		}
+	}
+}
+
+
+
+/// # Valid Size?
+///
+/// Returns `true` if the dimensions match the built-in sprite — a single row
+/// of square tiles.
+const fn valid_size(width: u32, height: u32) -> bool {
+	width == IMAGE_WIDTH && height == IMAGE_HEIGHT
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use wasm_bindgen_test::*;
+
+	#[wasm_bindgen_test]
+	fn t_valid_size() {
+		assert!(valid_size(IMAGE_WIDTH, IMAGE_HEIGHT));
+		assert!(! valid_size(IMAGE_WIDTH - TILE_SIZE, IMAGE_HEIGHT));
+		assert!(! valid_size(IMAGE_WIDTH, IMAGE_HEIGHT * 2));
+		assert!(! valid_size(0, 0));
+	}
+}
@@ -3,17 +3,27 @@
 */
 
 use crate::{
+	Bubble,
 	dom,
 	Mate,
+	Queue,
+	Skin,
 	Sound,
 	Universe,
 };
 use std::{
-	cell::RefCell,
-	rc::Rc,
+	cell::{
+		OnceCell,
+		RefCell,
+	},
+	rc::{
+		Rc,
+		Weak,
+	},
 };
 use js_sys::{
 	Array,
+	ArrayBuffer,
 	Uint8Array,
 };
 use wasm_bindgen::{
@@ -22,14 +32,22 @@ use wasm_bindgen::{
 };
 use web_sys::{
 	AddEventListenerOptions,
+	AudioBuffer,
+	AudioContext,
+	AudioContextState,
 	Blob,
 	BlobPropertyBag,
 	Element,
 	Event,
-	HtmlAudioElement,
+	GainNode,
 	MouseEvent,
 	Url,
 };
+#[cfg(feature = "firefox")]
+use web_sys::{
+	XmlHttpRequest,
+	XmlHttpRequestResponseType,
+};
 
 
 
@@ -43,6 +61,16 @@ extern "C" {
 
 
 
+thread_local! {
+	/// # Current State.
+	///
+	/// A weak reference to the running `State`, if any, so that userland
+	/// getters like `Poe.state` can peek inside without keeping it alive.
+	static CURRENT: RefCell<Weak<State>> = const { RefCell::new(Weak::new()) };
+}
+
+
+
 /// # Raw Sprite Image.
 static IMAGE: &[u8] = include_bytes!("../skel/img/poe.png");
 
@@ -102,8 +130,10 @@ impl Default for State {
 		let sound = StateAudio::default();
 
 		// Initialize the mates and add them to the document body.
-		let mut m1 = Mate::new(true, &image);
-		let mut m2 = Mate::new(false, &image);
+		let skin = Skin::url();
+		let src = skin.as_deref().unwrap_or(&image);
+		let mut m1 = Mate::new(true, src);
+		let mut m2 = Mate::new(false, src);
 		dom::body()
 			.expect_throw("Missing body.")
 			.append_with_node_2(m1.el(), m2.el())
@@ -114,8 +144,8 @@ impl Default for State {
 		if Universe::assign_child() { m1.set_child_animation(&mut m2); }
 
 		// Set up the event bindings.
-		let events = StateEvents::new(quirks);
-		events.bind(m1.el(), &sound.el);
+		let events = StateEvents::new(quirks, &sound);
+		events.bind(m1.el());
 
 		Self {
 			image,
@@ -131,7 +161,7 @@ impl Drop for State {
 	fn drop(&mut self) {
 		// Unbind events.
 		let m = self.mates.borrow();
-		self.events.unbind(m[0].el(), &self.sound.el);
+		self.events.unbind(m[0].el());
 
 		// Detach the mate elements.
 		if let Some(body) = dom::body() {
@@ -142,6 +172,9 @@ impl Drop for State {
 		// Revoke the image URL.
 		let _res = Url::revoke_object_url(&self.image);
 
+		// Abandon the queue, if any.
+		Queue::clear();
+
 		// Let the Universe know we're dead.
 		Universe::set_state(false);
 		#[cfg(feature = "director")] dom::console_warn("Poe deactivated.");
@@ -161,6 +194,7 @@ impl State {
 	pub(crate) fn init() {
 		// Shove what we've got so far into the state.
 		let state1 = Rc::new(Self::default());
+		CURRENT.with_borrow_mut(|c| *c = Rc::downgrade(&state1));
 
 		// Set up the recursive requestAnimationFrame callback, using a clone
 		// for the setup and initial call. (It will be dropped when the
@@ -170,6 +204,9 @@ impl State {
 			if Universe::active() {
 				// Unless we're paused, go ahead and (maybe) repaint.
 				if ! Universe::paused() { state1.paint(e as u32); }
+				// Otherwise play any manually-requested steps.
+				#[cfg(feature = "director")]
+				if Universe::paused() { state1.step(e as u32); }
 				state1.raf();
 			}
 			else { state1.raf.borrow_mut().take(); }
@@ -202,6 +239,9 @@ impl State {
 			}
 		}
 
+		#[cfg(feature = "director")]
+		if let Some(idx) = Universe::take_seek() { m1.seek(idx); }
+
 		m1.paint(now, &self.sound);
 
 		if Universe::no_child() { m2.stop(); }
@@ -209,6 +249,76 @@ impl State {
 		m2.paint(now, &self.sound);
 	}
 
+	#[cfg(feature = "director")]
+	/// # Step.
+	///
+	/// Tick both mates once for each step requested via `Poe.step`, and once
+	/// more to show the result of a `Poe.seek`, regardless of timing. This
+	/// is used while paused.
+	fn step(&self, now: u32) {
+		let mut steps = Universe::take_steps();
+		if let Some(idx) = Universe::take_seek() {
+			self.mates.borrow_mut()[0].seek(idx);
+			steps = steps.saturating_add(1);
+		}
+
+		for _ in 0..steps {
+			{
+				let [m1, m2] = &mut *self.mates.borrow_mut();
+				m1.hurry();
+				m2.hurry();
+			}
+			self.paint(now);
+		}
+	}
+
+	/// # Snapshot.
+	///
+	/// Return an array containing the current state of each mate — primary
+	/// then child — or `null` if Poe isn't running.
+	pub(crate) fn snapshot() -> JsValue {
+		let Some(state) = CURRENT.with_borrow(Weak::upgrade) else {
+			return JsValue::NULL;
+		};
+		let Ok(mates) = state.mates.try_borrow() else { return JsValue::NULL; };
+		let now = dom::now().unwrap_or_default();
+		Array::of2(&mates[0].snapshot(now), &mates[1].snapshot(now)).into()
+	}
+
+	/// # Set Image.
+	///
+	/// Point the running mates, if any, to a different sprite sheet, or back
+	/// to the built-in one if `None`.
+	pub(crate) fn set_image(src: Option<&str>) {
+		let Some(state) = CURRENT.with_borrow(Weak::upgrade) else { return; };
+		let src = src.unwrap_or(&state.image);
+		if let Ok(mates) = state.mates.try_borrow() {
+			for m in mates.iter() { m.set_image(src); }
+		}
+	}
+
+	#[expect(
+		clippy::cast_possible_truncation,
+		clippy::cast_sign_loss,
+		reason = "False positive.",
+	)]
+	/// # Say.
+	///
+	/// Show text in the primary mate's speech bubble, returning `false` if
+	/// Poe isn't running.
+	///
+	/// ## Errors
+	///
+	/// An error is returned if the options are invalid.
+	pub(crate) fn say(text: &str, opts: &JsValue) -> Result<bool, String> {
+		let duration = Bubble::parse_opts(opts)?;
+		let Some(state) = CURRENT.with_borrow(Weak::upgrade) else { return Ok(false); };
+		let Ok(mut mates) = state.mates.try_borrow_mut() else { return Ok(false); };
+		let now = dom::now().unwrap_or_default() as u32;
+		mates[0].say(text, duration, now);
+		Ok(true)
+	}
+
 	#[inline]
 	/// # Request Animation Frame.
 	///
@@ -224,81 +334,102 @@ impl State {
 
 /// # State Audio.
 ///
-/// This holds a reference to a (detached) audio element and URLs that can be
-/// used to point to each of the three audio sources at runtime, either actual
-/// `URL` objects in the case of the library, or normal strings for the Firefox
-/// extension.
+/// This holds a Web Audio context — routed through a master volume node —
+/// and the decoded buffers for each of the three sounds.
+///
+/// The context is only created the first time a sound would actually play,
+/// so pages with audio disabled (or every sound muted) never get one. At that
+/// point the raw sources are decoded in the background, either straight from
+/// the embedded bytes in the case of the library, or fetched from the
+/// extension for the Firefox version. Each call to [`StateAudio::play`] then
+/// spins up its own (cheap, disposable) source node, so overlapping sounds no
+/// longer cut each other off.
 ///
-/// Its [`StateAudio::play`] method is used by [`Mate`] to initiate playback if
-/// and when sound is required.
+/// If the browser doesn't support Web Audio, or a sound has yet to finish
+/// decoding — as will be the case for the very first one — playback is
+/// silently skipped.
 pub(crate) struct StateAudio {
-	/// # Element.
-	el: HtmlAudioElement,
+	/// # Context and Master Volume.
+	///
+	/// This is shared with the event handlers so they can unlock playback,
+	/// but only initialized by [`StateAudio::play`].
+	ctx: Rc<OnceCell<Option<(AudioContext, GainNode)>>>,
 
-	/// # Sounds.
-	sound: [String; 3],
+	/// # Decoded Sounds.
+	sound: Rc<RefCell<[Option<AudioBuffer>; 3]>>,
 }
 
 impl Default for StateAudio {
-	#[cfg(not(feature = "firefox"))]
 	fn default() -> Self {
-		let sound = [
-			url(BAA, "audio/flac"),
-			url(SNEEZE, "audio/flac"),
-			url(YAWN, "audio/flac"),
-		];
-
-		Self {
-			el: HtmlAudioElement::new().expect_throw("!"),
-			sound,
-		}
-	}
-
-	#[cfg(feature = "firefox")]
-	#[expect(clippy::allow_attributes, reason = "Buggy lint.")]
-	#[allow(unsafe_code, reason = "For FFI.")]
-	fn default() -> Self {
-		let sound = [
-			firefox_url("sound/baa.flac"),
-			firefox_url("sound/sneeze.flac"),
-			firefox_url("sound/yawn.flac"),
-		];
-
 		Self {
-			el: HtmlAudioElement::new().expect_throw("!"),
-			sound,
+			ctx: Rc::new(OnceCell::new()),
+			sound: Rc::new(RefCell::new([None, None, None])),
 		}
 	}
 }
 
-#[cfg(not(feature = "firefox"))]
 impl Drop for StateAudio {
 	fn drop(&mut self) {
-		// Revoke the URLs.
-		for i in &self.sound { let _res = Url::revoke_object_url(i); }
+		// Shut down the context, if we ever started one.
+		if let Some(Some((ctx, _))) = self.ctx.get() { let _res = ctx.close(); }
 	}
 }
 
 impl StateAudio {
-	/// # Play Sound.
+	#[cfg_attr(feature = "firefox", expect(clippy::allow_attributes, reason = "Buggy lint."))]
+	#[cfg_attr(feature = "firefox", allow(unsafe_code, reason = "For FFI."))]
+	/// # Context.
 	///
-	/// This updates the audio source and calls the element's `load` method.
-	/// (That should in turn trigger its `canplaythrough` event, which in turn
-	/// actually _plays_ the source.)
+	/// Return the audio context and master volume node, creating them — and
+	/// kicking off the decoding — on first use.
+	fn ctx(&self) -> Option<&(AudioContext, GainNode)> {
+		self.ctx.get_or_init(|| {
+			let ctx = AudioContext::new().ok()?;
+			let gain = ctx.create_gain().ok()?;
+			gain.connect_with_audio_node(&ctx.destination()).ok()?;
+
+			#[cfg(not(feature = "firefox"))]
+			for (idx, raw) in [BAA, SNEEZE, YAWN].into_iter().enumerate() {
+				decode(&ctx, &self.sound, idx, &Uint8Array::from(raw).buffer());
+			}
+
+			#[cfg(feature = "firefox")]
+			for (idx, path) in ["sound/baa.flac", "sound/sneeze.flac", "sound/yawn.flac"].into_iter().enumerate() {
+				fetch(&ctx, &self.sound, idx, &firefox_url(path));
+			}
+
+			Some((ctx, gain))
+		}).as_ref()
+	}
+
+	/// # Play Sound.
 	///
-	/// This method has no conditional logic of its own.
+	/// Play the sound at the current master volume, panned left/right per
+	/// `pan` (`-1.0..=1.0`).
 	///
-	/// The [`Mate`] calling it ensures the global audio option is enabled, and
-	/// the `oncanplaythrough` callback makes sure the page is visible/active.
-	pub(crate) fn play(&self, sound: Sound) {
-		// Update the source.
-		self.el.set_src(match sound {
-			Sound::Baa => self.sound[0].as_str(),
-			Sound::Sneeze => self.sound[1].as_str(),
-			Sound::Yawn => self.sound[2].as_str(),
-		});
-		// Force a reload, just in case.
-		self.el.load();
+	/// The [`Mate`] calling it ensures the global audio option is enabled
+	/// and the sound isn't muted; this method makes sure the page is
+	/// visible/active.
+	pub(crate) fn play(&self, sound: Sound, pan: f32) {
+		// Only proceed to play the sound if the current tab is visible.
+		if ! dom::is_visible() { return; }
+
+		let Some((ctx, gain)) = self.ctx() else { return; };
+		let Some(buf) = self.sound.borrow()[sound as usize].clone() else { return; };
+
+		// Make one last attempt to unlock playback, if needed.
+		unlock(ctx);
+
+		gain.gain().set_value(Universe::volume());
+		let _res = ctx.create_buffer_source()
+			.and_then(|src| {
+				src.set_buffer(Some(&buf));
+				let panner = ctx.create_stereo_panner()?;
+				panner.pan().set_value(pan.clamp(-1.0, 1.0));
+				src.connect_with_audio_node(&panner)?
+					.connect_with_audio_node(gain)?;
+				src.start()
+			});
 	}
 }
 
@@ -312,12 +443,13 @@ impl StateAudio {
 /// allowing for proper cleanup once the `StateEvents` object is itself
 /// dropped.
 struct StateEvents {
-	canplaythrough: Closure<dyn FnMut(Event)>,
 	contextmenu: Closure<dyn FnMut(Event)>,
 	#[cfg(not(feature = "firefox"))] dblclick: Closure<dyn FnMut()>,
+	keydown: Closure<dyn FnMut()>,
 	mousedown: Closure<dyn FnMut(MouseEvent)>,
 	mousemove: Closure<dyn FnMut(MouseEvent)>,
 	mouseup: Closure<dyn FnMut()>,
+	pointerdown: Closure<dyn FnMut()>,
 	resize: Closure<dyn FnMut()>,
 }
 
@@ -327,19 +459,17 @@ impl StateEvents {
 	/// This returns a new, ready-to-bind `StateEvents` instance. The `quirks`
 	/// variable determines which of the two approaches should be used to
 	/// obtain the window dimensions after a resize event.
-	fn new(quirks: bool) -> Self {
+	///
+	/// The audio context, if one has been created by then, is used to unlock
+	/// playback on user gestures, as most browsers require.
+	fn new(quirks: bool, audio: &StateAudio) -> Self {
+		let ctx1 = Rc::clone(&audio.ctx);
+		let ctx2 = Rc::clone(&audio.ctx);
 		Self {
-			canplaythrough: Closure::wrap(Box::new(|e: Event|
-				// Only proceed to play the sound if the current tab is visible.
-				if dom::is_visible() {
-					let _res = e.target()
-						.and_then(|t| t.dyn_into::<HtmlAudioElement>().ok())
-						.and_then(|t| t.play().ok());
-				}
-			)),
 			contextmenu: Closure::wrap(Box::new(|e: Event| { e.prevent_default(); })),
 			#[cfg(not(feature = "firefox"))]
 			dblclick: Closure::wrap(Box::new(|| { Universe::set_active(false); })),
+			keydown: Closure::wrap(Box::new(move || if let Some(Some((c, _))) = ctx1.get() { unlock(c); })),
 			mousedown: Closure::wrap(Box::new(|e: MouseEvent|
 				if 1 == e.buttons() && 0 == e.button() {
 					Universe::set_dragging(true);
@@ -352,12 +482,13 @@ impl StateEvents {
 				}
 			)),
 			mouseup: Closure::wrap(Box::new(|| { Universe::set_dragging(false); })),
+			pointerdown: Closure::wrap(Box::new(move || if let Some(Some((c, _))) = ctx2.get() { unlock(c); })),
 			resize: Closure::wrap(Box::new(if quirks { size_quirks } else { size_standards })),
 		}
 	}
 
 	/// # Bind Event Listeners.
-	fn bind(&self, mate: &Element, audio: &Element) {
+	fn bind(&self, mate: &Element) {
 		let document_element = dom::document_element().expect_throw("Missing documentElement.");
 		let window = dom::window().expect_throw("Missing window.");
 
@@ -374,12 +505,13 @@ impl StateEvents {
 			);
 		}
 
-		bind!(audio, canplaythrough, true);
 		bind!(mate, contextmenu, false);
 		#[cfg(not(feature = "firefox"))] bind!(mate, dblclick, true);
+		bind!(document_element, keydown, true);
 		bind!(mate, mousedown, true);
 		bind!(document_element, mousemove, true);
 		bind!(document_element, mouseup, true);
+		bind!(document_element, pointerdown, true);
 		bind!(window, resize, true);
 	}
 
@@ -388,7 +520,7 @@ impl StateEvents {
 	/// Note: this must be called before the object is dropped, otherwise
 	/// active references to the callbacks may persist, preventing their memory
 	/// from being properly freed.
-	fn unbind(&self, mate: &Element, audio: &Element) {
+	fn unbind(&self, mate: &Element) {
 		/// # Helper: Unbind.
 		macro_rules! unbind {
 			($el:expr, $event:ident) => (
@@ -399,13 +531,14 @@ impl StateEvents {
 			);
 		}
 
-		unbind!(audio, canplaythrough);
 		unbind!(mate, contextmenu);
 		#[cfg(not(feature = "firefox"))] unbind!(mate, dblclick);
 		unbind!(mate, mousedown);
 		if let Some(document_element) = dom::document_element() {
+			unbind!(document_element, keydown);
 			unbind!(document_element, mousemove);
 			unbind!(document_element, mouseup);
+			unbind!(document_element, pointerdown);
 		}
 		if let Some(window) = dom::window() { unbind!(window, resize); }
 	}
@@ -460,6 +593,58 @@ const fn normalize_size(size: i32) -> u16 {
 	else { u16::MAX }
 }
 
+/// # Decode Sound.
+///
+/// Decode the raw audio data in the background, storing the result in the
+/// corresponding slot once finished.
+fn decode(
+	ctx: &AudioContext,
+	sound: &Rc<RefCell<[Option<AudioBuffer>; 3]>>,
+	idx: usize,
+	raw: &ArrayBuffer,
+) {
+	let sound = Rc::clone(sound);
+	let cb = Closure::once_into_js(move |buf: AudioBuffer| {
+		if let Some(slot) = sound.borrow_mut().get_mut(idx) { slot.replace(buf); }
+	});
+	let _res = ctx.decode_audio_data_with_success_callback(raw, cb.unchecked_ref());
+}
+
+#[cfg(feature = "firefox")]
+/// # Fetch and Decode Sound.
+///
+/// Download the raw audio data from the extension, then pass it along to
+/// [`decode`].
+fn fetch(
+	ctx: &AudioContext,
+	sound: &Rc<RefCell<[Option<AudioBuffer>; 3]>>,
+	idx: usize,
+	url: &str,
+) {
+	let Ok(xhr) = XmlHttpRequest::new() else { return; };
+	if xhr.open_with_async("GET", url, true).is_err() { return; }
+	xhr.set_response_type(XmlHttpRequestResponseType::Arraybuffer);
+
+	let ctx = ctx.clone();
+	let sound = Rc::clone(sound);
+	let xhr2 = xhr.clone();
+	let cb = Closure::once_into_js(move || {
+		if let Ok(raw) = xhr2.response().and_then(JsCast::dyn_into::<ArrayBuffer>) {
+			decode(&ctx, &sound, idx, &raw);
+		}
+	});
+	xhr.set_onload(Some(cb.unchecked_ref()));
+	let _res = xhr.send();
+}
+
+/// # Unlock Audio.
+///
+/// Browsers generally start audio contexts in a suspended state until the
+/// user has interacted with the page. This tries to resume it, if needed.
+fn unlock(ctx: &AudioContext) {
+	if ctx.state() == AudioContextState::Suspended { let _res = ctx.resume(); }
+}
+
 /// # Slice to Blob to URL.
 ///
 /// This generates a Javascript `URL` object pointing to a raw binary slice.
@@ -4,17 +4,19 @@
 
 use crate::{
 	Frame,
+	Goal,
 	Position,
 	State,
 };
 #[cfg(feature = "director")] use crate::{Animation, dom};
 use std::sync::atomic::{
+	AtomicBool,
 	AtomicU8,
+	AtomicU16,
 	AtomicU32,
 	AtomicU64,
 	Ordering::SeqCst,
 };
-#[cfg(feature = "director")] use std::sync::atomic::AtomicU16;
 #[cfg(target_arch = "wasm32")] use wasm_bindgen::prelude::*;
 
 
@@ -32,12 +34,36 @@ extern "C" {
 
 
 
+/// # Entrance Delay.
+///
+/// The number of milliseconds to wait before beginning an entrance animation.
+static ENTRANCE_DELAY: AtomicU16 = AtomicU16::new(Universe::DEFAULT_ENTRANCE_DELAY);
+
+/// # Exit Odds.
+///
+/// Animations that are allowed to walk off-screen will be given permission to
+/// do so one in this many times. Zero disables exiting altogether.
+static EXIT_ODDS: AtomicU16 = AtomicU16::new(Universe::DEFAULT_EXIT_ODDS);
+
 /// # Flags.
 ///
 /// This holds a few basic bitflag runtime settings. (See the constants defined
 /// on the [`Universe`] below.)
 static FLAGS: AtomicU8 = AtomicU8::new(Universe::AUDIO);
 
+/// # Goal Coordinates.
+///
+/// This holds the (x, y) coordinates for a movement requested from
+/// Browserland, packed the same way as `POS`. (See `GOAL_KIND` for the
+/// type.)
+static GOAL: AtomicU64 = AtomicU64::new(0);
+
+/// # Goal Kind.
+///
+/// The type of movement requested from Browserland, if any: `1` for a
+/// teleport, `2` for a walk. Zero is equivalent to none.
+static GOAL_KIND: AtomicU8 = AtomicU8::new(0);
+
 #[cfg(feature = "director")]
 /// # Next Animation.
 ///
@@ -45,6 +71,45 @@ static FLAGS: AtomicU8 = AtomicU8::new(Universe::AUDIO);
 /// if any. Because those begin at `1`, zero is equivalent to none.
 static NEXT_ANIMATION: AtomicU8 = AtomicU8::new(0);
 
+#[cfg(feature = "director")]
+/// # Debug Overlay.
+///
+/// This is kept separate from `FLAGS` since it only exists in director
+/// builds.
+static DEBUG: AtomicBool = AtomicBool::new(false);
+
+#[cfg(feature = "director")]
+/// # Seek Position.
+///
+/// This holds the step index requested by `Poe.seek`, plus one, so zero is
+/// equivalent to none.
+static SEEK: AtomicU32 = AtomicU32::new(0);
+
+#[cfg(feature = "director")]
+/// # Pending Steps.
+///
+/// The number of steps requested by `Poe.step` that have yet to be played.
+static STEPS: AtomicU16 = AtomicU16::new(0);
+
+/// # Muted Sounds.
+///
+/// A bitset of individually-muted sounds. (See `Sound::bit`.)
+static MUTED: AtomicU8 = AtomicU8::new(0);
+
+/// # Screen Insets.
+///
+/// The top, right, bottom, and left margins — in that order — to keep clear
+/// of, stored as four `u16` values. (See `Universe::offset`.)
+static INSETS: AtomicU64 = AtomicU64::new(0);
+
+/// # Interpolate Movement.
+///
+/// When enabled — the default — movements are smoothed out between steps
+/// rather than jumping from one to the next. (See `Mate::drawn_pos`.)
+///
+/// This is kept separate from `FLAGS` since that's full up.
+static INTERPOLATE: AtomicBool = AtomicBool::new(true);
+
 /// # Mouse Coordinates.
 ///
 /// This holds the (x, y) mouse coordinates captured while dragging.
@@ -53,6 +118,12 @@ static NEXT_ANIMATION: AtomicU8 = AtomicU8::new(0);
 /// or updated as a pair, they're stored within a single 64-bit atomic.
 static POS: AtomicU64 = AtomicU64::new(0);
 
+/// # Fixed Seed.
+///
+/// A userland-provided seed to use in place of `Math.random` when (re)seeding
+/// the PRNG. Zero is equivalent to none.
+static SEED: AtomicU64 = AtomicU64::new(0);
+
 /// # Xoshi Seed #1.
 static SEED1: AtomicU64 = AtomicU64::new(0x8596_cc44_bef0_1aa0);
 
@@ -65,13 +136,23 @@ static SEED3: AtomicU64 = AtomicU64::new(0x49f1_3013_c503_a6aa);
 /// # Xoshi Seed #4.
 static SEED4: AtomicU64 = AtomicU64::new(0xc4d7_82ff_3c9f_7bef);
 
-#[cfg(feature = "director")]
 /// # Speed.
 ///
 /// This holds the playback speed as an integer percentage in the range of
 /// `0..=1000`, where `100` is normal.
 static SPEED: AtomicU16 = AtomicU16::new(100);
 
+/// # Volume.
+///
+/// The master volume as an integer percentage in the range of `0..=100`.
+static VOLUME: AtomicU8 = AtomicU8::new(100);
+
+/// # Walk Odds.
+///
+/// `Animation::default_choice` will select `Walk` one in this many times.
+/// Zero disables the preference altogether.
+static WALK_ODDS: AtomicU16 = AtomicU16::new(Universe::DEFAULT_WALK_ODDS);
+
 /// # Screen Width and Height.
 ///
 /// The dimensions are both `u16`, stored together because they're only ever
@@ -118,6 +199,17 @@ impl Universe {
 	const FIX_BINDINGS: u8 =  0b1000_0000;
 }
 
+impl Universe {
+	/// # Default Entrance Delay (ms).
+	pub(crate) const DEFAULT_ENTRANCE_DELAY: u16 = 1000;
+
+	/// # Default Exit Odds (1/n).
+	pub(crate) const DEFAULT_EXIT_ODDS: u16 = 25;
+
+	/// # Default Walk Odds (1/n).
+	pub(crate) const DEFAULT_WALK_ODDS: u16 = 3;
+}
+
 /// # Helper: Flag Getter.
 macro_rules! get {
 	($title:literal, $flag:ident, $fn:ident) => (
@@ -135,6 +227,10 @@ impl Universe {
 	get!("Dragging", DRAGGING, dragging);
 	get!("No Focus Allowed", NO_FOCUS, no_focus);
 
+	#[inline]
+	/// # Interpolate Movement?
+	pub(crate) fn interpolate() -> bool { INTERPOLATE.load(SeqCst) }
+
 	/// # Assign Child Animation?
 	///
 	/// Returns `true` if the previous mate requested a new child since the
@@ -163,14 +259,45 @@ impl Universe {
 		Self::NO_CHILD == old & Self::NO_CHILD
 	}
 
-	#[cfg(feature = "director")]
+	#[inline]
+	/// # Volume.
+	///
+	/// Return the master volume as a float between `0.0` and `1.0`.
+	pub(crate) fn volume() -> f32 { f32::from(VOLUME.load(SeqCst)) / 100.0 }
+
+	#[inline]
+	/// # Entrance Delay.
+	///
+	/// Return the number of milliseconds to wait before beginning an entrance
+	/// animation.
+	pub(crate) fn entrance_delay() -> u16 { ENTRANCE_DELAY.load(SeqCst) }
+
+	#[inline]
+	/// # Exit Odds.
+	///
+	/// Return the (one in n) odds that an animation capable of exiting the
+	/// screen will be allowed to do so. Zero means never.
+	pub(crate) fn exit_odds() -> u16 { EXIT_ODDS.load(SeqCst) }
+
+	#[inline]
+	/// # Muted Sounds.
+	///
+	/// Return the muted sound bitset. (This is separate from the global audio
+	/// setting.)
+	pub(crate) fn muted_set() -> u8 { MUTED.load(SeqCst) }
+
 	#[inline]
 	/// # Are We Paused?
 	pub(crate) fn paused() -> bool { SPEED.load(SeqCst) == 0 }
 
-	#[cfg(not(feature = "director"))]
-	/// # We Aren't Paused.
-	pub(crate) const fn paused() -> bool { false }
+	/// # Speed.
+	///
+	/// Returns the current playback speed if other than "normal" or paused.
+	pub(crate) fn speed() -> Option<f32> {
+		let speed = SPEED.load(SeqCst);
+		if speed == 0 || speed == 100 { None }
+		else { Some(f32::from(speed) / 100.0) }
+	}
 
 	/// # Position.
 	///
@@ -186,17 +313,48 @@ impl Universe {
 		Position::new(x, y)
 	}
 
+	/// # Browserland Goal.
+	///
+	/// This returns (and clears) the movement requested by `Poe.moveTo` or
+	/// `Poe.walkTo`, if any.
+	pub(crate) fn take_goal() -> Option<Goal> {
+		let kind = GOAL_KIND.swap(0, SeqCst);
+		let pos = GOAL.load(SeqCst).to_le_bytes();
+		let x = i32::from_le_bytes([pos[0], pos[1], pos[2], pos[3]]);
+		let y = i32::from_le_bytes([pos[4], pos[5], pos[6], pos[7]]);
+		match kind {
+			1 => Some(Goal::MoveTo(Position::new(x, y))),
+			2 => Some(Goal::WalkTo(x)),
+			_ => None,
+		}
+	}
+
+	/// # Offset.
+	///
+	/// Returns the left and top insets as a position, i.e. the screen
+	/// coordinates of Poe's `(0, 0)`.
+	pub(crate) fn offset() -> Position {
+		let [top, _, _, left] = insets();
+		Position::new(i32::from(left), i32::from(top))
+	}
+
 	/// # Width/Height.
 	///
-	/// Returns the current — or last recorded — dimensions of the screen.
+	/// Returns the current — or last recorded — dimensions of the screen,
+	/// less any insets.
 	///
 	/// These are captured when the universe is first initialized and refreshed
 	/// whenever the window is resized, but will grow stale when Poe has been
 	/// de-activated.
 	pub(crate) fn size() -> (u16, u16) {
 		let size = SIZE.load(SeqCst).to_le_bytes();
-		let width = u16::from_le_bytes([size[0], size[1]]);
-		let height = u16::from_le_bytes([size[2], size[3]]);
+		let [top, right, bottom, left] = insets();
+		let width = u16::from_le_bytes([size[0], size[1]])
+			.saturating_sub(left)
+			.saturating_sub(right);
+		let height = u16::from_le_bytes([size[2], size[3]])
+			.saturating_sub(top)
+			.saturating_sub(bottom);
 		match (width, height) {
 			(0, 0) => (1, 1),
 			(0, h) => (1, h),
@@ -204,6 +362,13 @@ impl Universe {
 			(w, h) => (w, h),
 		}
 	}
+
+	#[inline]
+	/// # Walk Odds.
+	///
+	/// Return the (one in n) odds that `Animation::default_choice` will
+	/// simply return `Walk`. Zero means never.
+	pub(crate) fn walk_odds() -> u16 { WALK_ODDS.load(SeqCst) }
 }
 
 impl Universe {
@@ -213,31 +378,16 @@ impl Universe {
 	/// Return a random `u64` (xoshiro256).
 	pub(crate) fn rand() -> u64 {
 		let mut seeds = get_seeds();
-		let out = seeds[1].overflowing_mul(5).0.rotate_left(7).overflowing_mul(9).0;
-		update_seeds(&mut seeds);
+		let out = next_rand(&mut seeds);
 		set_seeds(&seeds);
 		out
 	}
 
-	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
 	/// # Random (Capped) U16.
 	///
 	/// Return a random number between `0..max`, mitigating bias the same way
 	/// as `fastrand` (i.e. <https://lemire.me/blog/2016/06/30/fast-random-shuffling/>).
-	pub(crate) fn rand_mod(n: u16) -> u16 {
-		let mut r = Self::rand() as u16;
-		let mut hi = mul_high_u16(r, n);
-		let mut lo = r.wrapping_mul(n);
-		if lo < n {
-			let t = n.wrapping_neg() % n;
-			while lo < t {
-				r = Self::rand() as u16;
-				hi = mul_high_u16(r, n);
-				lo = r.wrapping_mul(n);
-			}
-		}
-		hi
-	}
+	pub(crate) fn rand_mod(n: u16) -> u16 { rand_mod_with(n, Self::rand) }
 }
 
 /// # Helper: Flag Setter.
@@ -283,6 +433,36 @@ impl Universe {
 		}
 	}
 
+	/// # Set Muted Sounds.
+	///
+	/// Replace the muted sound bitset.
+	pub(crate) fn set_muted(set: u8) { MUTED.store(set, SeqCst); }
+
+	#[expect(
+		clippy::cast_possible_truncation,
+		clippy::cast_sign_loss,
+		reason = "False positive.",
+	)]
+	/// # Set Volume.
+	///
+	/// Change the master volume, given as a float between `0.0` and `1.0`.
+	/// Out-of-range values are clamped; `NaN` is ignored.
+	pub(crate) fn set_volume(volume: f32) {
+		if ! volume.is_nan() {
+			VOLUME.store((volume * 100.0).round().clamp(0.0, 100.0) as u8, SeqCst);
+		}
+	}
+
+	/// # Set Odds and Delays.
+	///
+	/// Update the runtime-configurable entrance delay, exit odds, and walk
+	/// odds. (See [`Config`](crate::Config) for details.)
+	pub(crate) fn set_tunables(entrance_delay: u16, exit_odds: u16, walk_odds: u16) {
+		ENTRANCE_DELAY.store(entrance_delay, SeqCst);
+		EXIT_ODDS.store(exit_odds, SeqCst);
+		WALK_ODDS.store(walk_odds, SeqCst);
+	}
+
 	/// # Set Assign Child Flag.
 	///
 	/// This will also remove the incompatible no-child flag.
@@ -320,14 +500,21 @@ impl Universe {
 		else { FLAGS.fetch_and(! Self::NO_FOCUS, SeqCst); }
 	}
 
+	/// # Set Interpolate Movement.
+	///
+	/// Disabling this gives the authentic, step-by-step movement of the
+	/// original.
+	pub(crate) fn set_interpolate(v: bool) { INTERPOLATE.store(v, SeqCst); }
+
 	/// # Set Position.
 	///
 	/// Update the cached X/Y mouse coordinates, only used when dragging a
 	/// Poe around the screen.
 	pub(crate) fn set_pos(x: i32, y: i32) {
 		let half_tile = Frame::SIZE_I.saturating_div(2);
-		let x = x.saturating_sub(half_tile).to_le_bytes();
-		let y = y.saturating_sub(half_tile).to_le_bytes();
+		let offset = Self::offset();
+		let x = x.saturating_sub(half_tile).saturating_sub(offset.x).to_le_bytes();
+		let y = y.saturating_sub(half_tile).saturating_sub(offset.y).to_le_bytes();
 		let pos = u64::from_le_bytes([
 			x[0], x[1], x[2], x[3],
 			y[0], y[1], y[2], y[3],
@@ -335,25 +522,48 @@ impl Universe {
 		POS.store(pos, SeqCst);
 	}
 
-	/// # Set Width/Height.
+	/// # Set Browserland Goal.
 	///
-	/// This updates the cached window dimensions.
-	pub(crate) fn set_size(width: u16, height: u16) {
-		let width = width.to_le_bytes();
-		let height = height.to_le_bytes();
-		SIZE.store(u32::from_le_bytes([width[0], width[1], height[0], height[1]]), SeqCst);
+	/// `Poe.moveTo` and `Poe.walkTo` use this to send the primary mate
+	/// somewhere specific. Requests made while Poe is inactive are ignored.
+	///
+	/// Note: the coordinates are relative to the screen, so are adjusted for
+	/// any insets here.
+	pub(crate) fn set_goal(goal: Goal) {
+		if ! Self::active() { return; }
+
+		let offset = Self::offset();
+		let (kind, x, y) = match goal {
+			Goal::MoveTo(pos) => (1, pos.x - offset.x, pos.y - offset.y),
+			Goal::WalkTo(x) => (2, x - offset.x, 0),
+		};
+		let x = x.to_le_bytes();
+		let y = y.to_le_bytes();
+		GOAL.store(u64::from_le_bytes([
+			x[0], x[1], x[2], x[3],
+			y[0], y[1], y[2], y[3],
+		]), SeqCst);
+		GOAL_KIND.store(kind, SeqCst);
 	}
-}
 
-#[cfg(feature = "director")]
-impl Universe {
-	/// # Speed.
+	/// # Set Insets.
 	///
-	/// Returns the current playback speed if other than "normal" or paused.
-	pub(crate) fn speed() -> Option<f32> {
-		let speed = SPEED.load(SeqCst);
-		if speed == 0 || speed == 100 { None }
-		else { Some(f32::from(speed) / 100.0) }
+	/// Reserve space along the top, right, bottom, and left edges of the
+	/// screen — in that order — that Poe should keep clear of.
+	pub(crate) fn set_insets(insets: [u16; 4]) {
+		let [t, r, b, l] = insets.map(u16::to_le_bytes);
+		INSETS.store(u64::from_le_bytes([t[0], t[1], r[0], r[1], b[0], b[1], l[0], l[1]]), SeqCst);
+	}
+
+	/// # Set Seed.
+	///
+	/// Use a fixed seed — rather than `Math.random` — for the PRNG from now
+	/// on, or go back to random seeding with zero. This takes effect
+	/// immediately, and again each time Poe is (re)activated, so sessions
+	/// play out the same way every time.
+	pub(crate) fn set_seed(seed: u64) {
+		SEED.store(seed, SeqCst);
+		if seed != 0 { seed_from(seed); }
 	}
 
 	#[expect(
@@ -363,7 +573,8 @@ impl Universe {
 	)]
 	/// # Set Speed.
 	///
-	/// Change the animation playback speed.
+	/// Change the animation playback speed, given as a float multiplier. The
+	/// result is clamped to `0.0..=10.0`; zero pauses playback.
 	pub(crate) fn set_speed(speed: f32) {
 		// Clamp the range to something sane.
 		let speed =
@@ -373,11 +584,35 @@ impl Universe {
 		// Store as an integer.
 		SPEED.store(speed as u16, SeqCst);
 
+		// Any unplayed manual steps are moot now.
+		#[cfg(feature = "director")] STEPS.store(0, SeqCst);
+
 		#[cfg(feature = "director")] dom::console_debug(&format!(
 			"Playback Speed: {speed:.2}%"
 		));
 	}
 
+	/// # Set Width/Height.
+	///
+	/// This updates the cached window dimensions.
+	pub(crate) fn set_size(width: u16, height: u16) {
+		let width = width.to_le_bytes();
+		let height = height.to_le_bytes();
+		SIZE.store(u32::from_le_bytes([width[0], width[1], height[0], height[1]]), SeqCst);
+	}
+}
+
+#[cfg(feature = "director")]
+impl Universe {
+	#[inline]
+	/// # Debug Overlay?
+	pub(crate) fn debug() -> bool { DEBUG.load(SeqCst) }
+
+	/// # Set Debug Overlay.
+	///
+	/// `Poe.debug` uses this to show or hide the debug overlay.
+	pub(crate) fn set_debug(v: bool) { DEBUG.store(v, SeqCst); }
+
 	/// # Browserland Next Animation.
 	///
 	/// This returns (and clears) the animation set by `Poe.play`, if any.
@@ -392,6 +627,35 @@ impl Universe {
 	pub(crate) fn set_next_animation(next: u8) {
 		NEXT_ANIMATION.store(next, SeqCst);
 	}
+
+	/// # Browserland Seek.
+	///
+	/// This returns (and clears) the step index requested by `Poe.seek`, if
+	/// any.
+	pub(crate) fn take_seek() -> Option<u16> {
+		SEEK.swap(0, SeqCst).checked_sub(1).and_then(|n| u16::try_from(n).ok())
+	}
+
+	/// # Set Browserland Seek.
+	///
+	/// `Poe.seek` uses this to jump the primary mate to a specific step of
+	/// its current animation.
+	pub(crate) fn set_seek(idx: u16) {
+		SEEK.store(u32::from(idx) + 1, SeqCst);
+	}
+
+	/// # Browserland Steps.
+	///
+	/// This returns (and clears) the number of steps requested by `Poe.step`.
+	pub(crate) fn take_steps() -> u16 { STEPS.swap(0, SeqCst) }
+
+	/// # Add Browserland Steps.
+	///
+	/// `Poe.step` uses this to advance playback manually while paused.
+	/// Requests made in quick succession — before the next frame — add up.
+	pub(crate) fn add_steps(n: u16) {
+		let _res = STEPS.fetch_update(SeqCst, SeqCst, |old| Some(old.saturating_add(n)));
+	}
 }
 
 
@@ -407,18 +671,72 @@ fn get_seeds() -> [u64; 4] {
 	]
 }
 
+#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+/// # Random (Capped) U16 (With Source).
+///
+/// This is the meat of `Universe::rand_mod`, broken out so the source of
+/// randomness can be specified.
+pub(crate) fn rand_mod_with<F: FnMut() -> u64>(n: u16, mut rand: F) -> u16 {
+	let mut r = rand() as u16;
+	let mut hi = mul_high_u16(r, n);
+	let mut lo = r.wrapping_mul(n);
+	if lo < n {
+		let t = n.wrapping_neg() % n;
+		while lo < t {
+			r = rand() as u16;
+			hi = mul_high_u16(r, n);
+			lo = r.wrapping_mul(n);
+		}
+	}
+	hi
+}
+
+#[cfg(test)]
+/// # Seeded Randomness.
+///
+/// Return a standalone xoshiro256 source seeded the same way as
+/// `Universe::set_seed`, for tests that need a reproducible sequence without
+/// racing the global state.
+pub(crate) fn seeded_rand(mut seed: u64) -> impl FnMut() -> u64 {
+	let mut seeds = [0_u64; 4];
+	for i in &mut seeds { *i = splitmix(&mut seed); }
+	move || next_rand(&mut seeds)
+}
+
 #[inline]
 /// # High 16 Product.
 const fn mul_high_u16(a: u16, b: u16) -> u16 {
 	(((a as u32) * (b as u32)) >> 16) as u16
 }
 
+/// # Insets.
+///
+/// Return the top, right, bottom, and left insets.
+fn insets() -> [u16; 4] {
+	let i = INSETS.load(SeqCst).to_le_bytes();
+	[
+		u16::from_le_bytes([i[0], i[1]]),
+		u16::from_le_bytes([i[2], i[3]]),
+		u16::from_le_bytes([i[4], i[5]]),
+		u16::from_le_bytes([i[6], i[7]]),
+	]
+}
+
 #[cfg(target_arch = "wasm32")]
 /// # Reseed Randomness.
+///
+/// Use the fixed seed if there is one, otherwise `Math.random`.
 fn reseed() {
-	// Splitmix Math.random to give us a reasonable starting point for the
+	match SEED.load(SeqCst) {
+		0 => seed_from(js_random().to_bits()),
+		seed => seed_from(seed),
+	}
+}
+
+/// # Seed From.
+fn seed_from(mut seed: u64) {
+	// Splitmix the seed to give us a reasonable starting point for the
 	// subsequent Xoshi randomness.
-	let mut seed: u64 = js_random().to_bits();
 	let mut seeds = [0_u64; 4];
 	for i in &mut seeds { *i = splitmix(&mut seed); }
 	set_seeds(&seeds);
@@ -451,6 +769,15 @@ fn set_seeds(seeds: &[u64; 4]) {
 	}
 }
 
+/// # Next Random Value.
+///
+/// Return the next xoshiro256 value and advance the seeds.
+const fn next_rand(seeds: &mut [u64; 4]) -> u64 {
+	let out = seeds[1].overflowing_mul(5).0.rotate_left(7).overflowing_mul(9).0;
+	update_seeds(seeds);
+	out
+}
+
 /// # Update Seeds.
 const fn update_seeds(seeds: &mut[u64; 4]) {
 	let t = seeds[1] << 17;
@@ -462,7 +789,6 @@ const fn update_seeds(seeds: &mut[u64; 4]) {
 	seeds[3] =  seeds[3].rotate_left(45);
 }
 
-#[cfg(target_arch = "wasm32")]
 /// # Split/Mix.
 ///
 /// This is used to generate our Xoshi256 seeds from a single source `u64`.